@@ -3,7 +3,7 @@
 use std::fs::File;
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
-use vrp_pragmatic::checker::CheckerContext;
+use vrp_pragmatic::checker::{CheckerContext, CheckerError};
 use vrp_pragmatic::core::models::{Problem as CoreProblem, Solution as CoreSolution};
 use vrp_pragmatic::core::solver::Builder;
 use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, Matrix, PragmaticProblem, Problem};
@@ -66,7 +66,7 @@ fn run_examples(base_path: &str) {
         let solution = get_pragmatic_solution(&core_problem, &solution);
 
         if let Err(err) = CheckerContext::new(core_problem, problem, matrices, solution).check() {
-            panic!("unfeasible solution in '{}': '{}'", name, err);
+            panic!("unfeasible solution in '{}': '{}'", name, CheckerError::format_many(&err, ", "));
         }
     }
 }
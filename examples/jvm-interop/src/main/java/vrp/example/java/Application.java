@@ -2,7 +2,9 @@ package vrp.example.java;
 
 import com.sun.jna.Callback;
 import com.sun.jna.Library;
+import com.sun.jna.Memory;
 import com.sun.jna.Native;
+import com.sun.jna.Pointer;
 
 import java.io.IOException;
 import java.nio.file.Files;
@@ -19,6 +21,17 @@ interface Solver extends Library {
                          int matricesSize,
                          String config,
                          OnSuccess onSuccess, OnError onError);
+    /** Solves pragmatic problem reporting progress. Search is stopped when value of isCancelled is set to non-zero. **/
+    void solve_pragmatic_with_progress(String problem, String[] matrices,
+                                       int matricesSize,
+                                       String config,
+                                       OnProgress onProgress,
+                                       Pointer isCancelled,
+                                       OnSuccess onSuccess, OnError onError);
+}
+
+interface OnProgress extends Callback {
+    void progress(int generation, double cost, int unassigned);
 }
 
 interface OnSuccess extends Callback {
@@ -56,7 +69,21 @@ class Application {
                     }
                 });
 
-        solver.solve_pragmatic(problem, matrices, matrices.length, "{}",
+        Memory isCancelled = new Memory(4);
+        isCancelled.setInt(0, 0);
+
+        solver.solve_pragmatic_with_progress(problem, matrices, matrices.length, "{}",
+                new OnProgress() {
+                    @Override
+                    public void progress(int generation, double cost, int unassigned) {
+                        System.out.println("generation: " + generation + ", cost: " + cost + ", unassigned: " + unassigned);
+                        // NOTE stop search earlier and get the best known solution
+                        if (generation == 100) {
+                            isCancelled.setInt(0, 1);
+                        }
+                    }
+                },
+                isCancelled,
                 new OnSuccess() {
                     @Override
                     public void result(String json) {
@@ -2,7 +2,9 @@ package vrp.example.kotlin
 
 import com.sun.jna.Callback
 import com.sun.jna.Library
+import com.sun.jna.Memory
 import com.sun.jna.Native
+import com.sun.jna.Pointer
 import java.nio.file.Files
 import java.nio.file.Paths
 
@@ -18,6 +20,18 @@ private interface Solver : Library {
                         matricesLen: Int,
                         config: String,
                         onSuccess: OnSuccess, onError: OnError)
+    /** Solves pragmatic problem reporting progress. Search is stopped when value of isCancelled is set to non-zero. **/
+    fun solve_pragmatic_with_progress(problem: String,
+                                      matrices: Array<String>,
+                                      matricesLen: Int,
+                                      config: String,
+                                      onProgress: OnProgress,
+                                      isCancelled: Pointer,
+                                      onSuccess: OnSuccess, onError: OnError)
+}
+
+private interface OnProgress : Callback {
+    fun progress(generation: Int, cost: Double, unassigned: Int)
 }
 
 private interface OnSuccess : Callback {
@@ -51,7 +65,17 @@ fun main(args: Array<String>) {
             }
     )
 
-    solver.solve_pragmatic(problem, matrices, matrices.size, "{}",
+    val isCancelled = Memory(4).apply { setInt(0, 0) }
+
+    solver.solve_pragmatic_with_progress(problem, matrices, matrices.size, "{}",
+            onProgress = object : OnProgress {
+                override fun progress(generation: Int, cost: Double, unassigned: Int) {
+                    println("generation: $generation, cost: $cost, unassigned: $unassigned")
+                    // NOTE stop search earlier and get the best known solution
+                    if (generation == 100) isCancelled.setInt(0, 1)
+                }
+            },
+            isCancelled = isCancelled,
             onSuccess = object : OnSuccess {
                 override fun result(json: String) {
                     println("solution: $json")
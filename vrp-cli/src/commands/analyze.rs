@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/analyze_test.rs"]
+mod analyze_test;
+
+use super::*;
+use std::io::BufReader;
+use vrp_cli::extensions::analyze::analyze_pragmatic_problem;
+use vrp_cli::get_errors_serialized;
+use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+use vrp_pragmatic::format::FormatError;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const MATRIX_ARG_NAME: &str = "matrix";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_analyze_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("analyze")
+        .about("Provides the way to get problem statistics and detect feasibility issues before solving")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short("m")
+                .long(MATRIX_ARG_NAME)
+                .multiple(true)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for analysis output in json format")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_analyze(matches: &ArgMatches) {
+    let problem_file = matches.value_of(PROBLEM_ARG_NAME).map(|path| BufReader::new(open_file(path, "problem")));
+    let matrices = matches.values_of(MATRIX_ARG_NAME).map(|paths: Values| {
+        paths
+            .map(|path| {
+                deserialize_matrix(BufReader::new(open_file(path, "routing matrix"))).unwrap_or_else(|errs| {
+                    eprintln!("cannot read matrix: '{}'", FormatError::format_many(&errs, ","));
+                    process::exit(1);
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+
+    let problem = deserialize_problem(problem_file.unwrap()).unwrap_or_else(|errs| {
+        eprintln!("cannot read problem: '{}'", FormatError::format_many(&errs, ","));
+        process::exit(1);
+    });
+
+    match analyze_pragmatic_problem(problem, matrices) {
+        Ok(analysis) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_buffer = create_write_buffer(out_result);
+            if let Err(err) = serde_json::to_writer_pretty(&mut out_buffer, &analysis)
+                .map_err(|err| err.to_string())
+                .and_then(|_| out_buffer.flush().map_err(|err| err.to_string()))
+            {
+                eprintln!("cannot write analysis: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(errors) => {
+            eprintln!("cannot analyze problem:\n{}", get_errors_serialized(&errors));
+            process::exit(1);
+        }
+    }
+}
@@ -3,14 +3,17 @@
 mod check_test;
 
 use super::*;
-use std::io::BufReader;
+use std::io::{BufReader, Write};
 use std::process;
 use vrp_cli::extensions::check::check_pragmatic_solution;
+use vrp_pragmatic::checker::CheckerError;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "problem-file";
 const SOLUTION_ARG_NAME: &str = "solution-file";
 const MATRIX_ARG_NAME: &str = "matrix";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
 
 pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
     App::new("check")
@@ -48,6 +51,22 @@ pub fn get_check_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies format of found violations: json lists all of them with details")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["text", "json"])
+                .default_value("text"),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for violations output in json format")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 pub fn run_check(matches: &ArgMatches) {
@@ -60,18 +79,38 @@ pub fn run_check(matches: &ArgMatches) {
         .values_of(MATRIX_ARG_NAME)
         .map(|paths: Values| paths.map(|path| BufReader::new(open_file(path, "routing matrix"))).collect());
 
+    let is_json_output = matches.value_of(OUT_FORMAT_ARG_NAME) == Some("json");
+
     let result = match (input_format, problem_files, solution_file) {
         ("pragmatic", Some(mut problem_files), Some(solution_file)) if problem_files.len() == 1 => {
             check_pragmatic_solution(problem_files.swap_remove(0), solution_file, matrix_files)
         }
         ("pragmatic", _, _) => {
-            Err("pragmatic format expects one problem, one solution file, and optionally matrices".to_string())
+            eprintln!("pragmatic format expects one problem, one solution file, and optionally matrices");
+            process::exit(1);
+        }
+        _ => {
+            eprintln!("unknown format: '{}'", input_format);
+            process::exit(1);
         }
-        _ => Err(format!("unknown format: '{}'", input_format)),
     };
 
-    if let Err(err) = result {
-        eprintln!("{}", err);
+    let errors = result.err().unwrap_or_default();
+
+    if is_json_output {
+        let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+        let mut out_buffer = create_write_buffer(out_result);
+        if let Err(err) = CheckerError::to_json(&errors).and_then(|errors| {
+            out_buffer.write_all(errors.as_bytes()).and_then(|_| out_buffer.flush()).map_err(|err| err.to_string())
+        }) {
+            eprintln!("cannot write violations: '{}'", err);
+            process::exit(1);
+        }
+    } else if !errors.is_empty() {
+        eprintln!("{}", CheckerError::format_many(&errors, "\n"));
+    }
+
+    if !errors.is_empty() {
         process::exit(1);
     }
 }
@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/diff_test.rs"]
+mod diff_test;
+
+use super::*;
+use std::io::BufReader;
+use vrp_cli::extensions::diff::diff_solutions;
+use vrp_pragmatic::format::solution::{deserialize_solution, Solution};
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const BASE_SOLUTION_ARG_NAME: &str = "base-solution";
+const SOLUTION_ARG_NAME: &str = "solution";
+const TIME_THRESHOLD_ARG_NAME: &str = "time-threshold";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_diff_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("diff")
+        .about("Provides the way to compare two solutions of the same problem")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(BASE_SOLUTION_ARG_NAME)
+                .help("Sets base solution file")
+                .short("b")
+                .long(BASE_SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets new solution file which is compared with the base one")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(TIME_THRESHOLD_ARG_NAME)
+                .help("Specifies time shift (in seconds) which activity time change should exceed to be reported. Default is 0")
+                .short("t")
+                .long(TIME_THRESHOLD_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for diff output in json format")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_diff(matches: &ArgMatches) {
+    let base = read_solution(matches.value_of(BASE_SOLUTION_ARG_NAME).unwrap(), "base solution");
+    let solution = read_solution(matches.value_of(SOLUTION_ARG_NAME).unwrap(), "solution");
+    let time_threshold = parse_int_value::<i64>(matches, TIME_THRESHOLD_ARG_NAME, "time threshold").unwrap_or(0);
+
+    match diff_solutions(&base, &solution, time_threshold) {
+        Ok(diff) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let mut out_buffer = create_write_buffer(out_result);
+            if let Err(err) = serde_json::to_writer_pretty(&mut out_buffer, &diff)
+                .map_err(|err| err.to_string())
+                .and_then(|_| out_buffer.flush().map_err(|err| err.to_string()))
+            {
+                eprintln!("cannot write diff: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("cannot compare solutions: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
+
+fn read_solution(path: &str, description: &str) -> Solution {
+    deserialize_solution(BufReader::new(open_file(path, description))).unwrap_or_else(|err| {
+        eprintln!("cannot read {}: '{}'", description, err);
+        process::exit(1);
+    })
+}
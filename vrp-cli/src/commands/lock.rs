@@ -0,0 +1,128 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/lock_test.rs"]
+mod lock_test;
+
+use super::*;
+use std::io::BufReader;
+use std::process;
+use vrp_cli::extensions::lock::{create_locked_problem, LockOptions};
+use vrp_pragmatic::format::problem::{deserialize_problem, serialize_problem, RelationType};
+use vrp_pragmatic::format::solution::deserialize_solution;
+use vrp_pragmatic::format::FormatError;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "problem-file";
+const SOLUTION_ARG_NAME: &str = "solution-file";
+const RELATION_TYPE_ARG_NAME: &str = "relation-type";
+const VEHICLE_IDS_ARG_NAME: &str = "vehicle-ids";
+const TIME_RANGE_ARG_NAME: &str = "time-range";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+
+pub fn get_lock_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("lock")
+        .about("Provides the way to create a new problem with relations which lock jobs assigned in the solution")
+        .arg(
+            Arg::with_name(FORMAT_ARG_NAME)
+                .help("Specifies input type")
+                .required(true)
+                .possible_values(&["pragmatic"])
+                .index(1),
+        )
+        .arg(
+            Arg::with_name(PROBLEM_ARG_NAME)
+                .help("Sets problem file")
+                .short("p")
+                .long(PROBLEM_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(SOLUTION_ARG_NAME)
+                .help("Sets solution file")
+                .short("s")
+                .long(SOLUTION_ARG_NAME)
+                .required(true)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(RELATION_TYPE_ARG_NAME)
+                .help("Specifies type of relations to be created")
+                .short("r")
+                .long(RELATION_TYPE_ARG_NAME)
+                .required(false)
+                .possible_values(&["any", "sequence", "strict"])
+                .default_value("any"),
+        )
+        .arg(
+            Arg::with_name(VEHICLE_IDS_ARG_NAME)
+                .help("Specifies ids of vehicles which tours should be locked, all tours are locked if omitted")
+                .long(VEHICLE_IDS_ARG_NAME)
+                .required(false)
+                .takes_value(true)
+                .multiple(true),
+        )
+        .arg(
+            Arg::with_name(TIME_RANGE_ARG_NAME)
+                .help(
+                    "Specifies time range as two comma separated dates in RFC3339 format: \
+                     only activities started within it are locked",
+                )
+                .long(TIME_RANGE_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to file for result output")
+                .short("o")
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+}
+
+pub fn run_lock(matches: &ArgMatches) {
+    let problem_file = matches.value_of(PROBLEM_ARG_NAME).map(|path| BufReader::new(open_file(path, "problem")));
+    let solution_file = matches.value_of(SOLUTION_ARG_NAME).map(|path| BufReader::new(open_file(path, "solution")));
+
+    let relation_type = match matches.value_of(RELATION_TYPE_ARG_NAME) {
+        Some("sequence") => RelationType::Sequence,
+        Some("strict") => RelationType::Strict,
+        _ => RelationType::Any,
+    };
+    let vehicle_ids = matches.values_of(VEHICLE_IDS_ARG_NAME).map(|ids: Values| ids.map(String::from).collect());
+    let time_range = matches.value_of(TIME_RANGE_ARG_NAME).map(|arg| {
+        if let [start, end] = arg.split(',').collect::<Vec<_>>().as_slice() {
+            (start.trim().to_string(), end.trim().to_string())
+        } else {
+            eprintln!("cannot parse time range");
+            process::exit(1);
+        }
+    });
+
+    let problem = deserialize_problem(problem_file.unwrap()).unwrap_or_else(|errs| {
+        eprintln!("cannot read problem: '{}'", FormatError::format_many(&errs, ","));
+        process::exit(1);
+    });
+    let solution = deserialize_solution(solution_file.unwrap()).unwrap_or_else(|err| {
+        eprintln!("cannot read solution: '{}'", err);
+        process::exit(1);
+    });
+
+    let options = LockOptions { relation_type, vehicle_ids, time_range };
+
+    match create_locked_problem(problem, &solution, &options) {
+        Ok(problem) => {
+            let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out result"));
+            let out_buffer = create_write_buffer(out_result);
+            if let Err(err) = serialize_problem(out_buffer, &problem) {
+                eprintln!("cannot serialize result problem: '{}'", err);
+                process::exit(1);
+            }
+        }
+        Err(err) => {
+            eprintln!("cannot lock solution: '{}'", err);
+            process::exit(1);
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use clap::{App, Arg, ArgMatches, Values};
 
+pub mod analyze;
 pub mod check;
+pub mod diff;
 pub mod generate;
 pub mod import;
+pub mod lock;
+pub mod serve;
 pub mod solve;
 
 use std::fs::File;
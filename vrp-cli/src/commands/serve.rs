@@ -0,0 +1,104 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/serve_test.rs"]
+mod serve_test;
+
+use super::*;
+use std::time::Duration;
+use vrp_cli::extensions::serve::{run_server, ServerConfig};
+
+const ADDRESS_ARG_NAME: &str = "address";
+const WORKERS_ARG_NAME: &str = "workers";
+const QUEUE_SIZE_ARG_NAME: &str = "queue-size";
+const CONNECTIONS_ARG_NAME: &str = "connections";
+const TIMEOUT_ARG_NAME: &str = "timeout";
+const JOB_TTL_ARG_NAME: &str = "job-ttl";
+
+pub fn get_serve_app<'a, 'b>() -> App<'a, 'b> {
+    App::new("serve")
+        .about("Runs http server which provides the way to solve, check and import problems, and get their locations")
+        .arg(
+            Arg::with_name(ADDRESS_ARG_NAME)
+                .help("Specifies address to listen on")
+                .short("a")
+                .long(ADDRESS_ARG_NAME)
+                .required(false)
+                .default_value("127.0.0.1:8000"),
+        )
+        .arg(
+            Arg::with_name(WORKERS_ARG_NAME)
+                .help("Specifies amount of workers which solve problems in parallel")
+                .short("w")
+                .long(WORKERS_ARG_NAME)
+                .required(false)
+                .default_value("1"),
+        )
+        .arg(
+            Arg::with_name(QUEUE_SIZE_ARG_NAME)
+                .help("Specifies max amount of solve jobs waiting in the queue")
+                .long(QUEUE_SIZE_ARG_NAME)
+                .required(false)
+                .default_value("16"),
+        )
+        .arg(
+            Arg::with_name(CONNECTIONS_ARG_NAME)
+                .help("Specifies max amount of connections handled in parallel")
+                .long(CONNECTIONS_ARG_NAME)
+                .required(false)
+                .default_value("32"),
+        )
+        .arg(
+            Arg::with_name(TIMEOUT_ARG_NAME)
+                .help("Specifies connection read and write timeout in seconds")
+                .long(TIMEOUT_ARG_NAME)
+                .required(false)
+                .default_value("30"),
+        )
+        .arg(
+            Arg::with_name(JOB_TTL_ARG_NAME)
+                .help("Specifies how long processed or cancelled solve jobs are kept, in seconds")
+                .long(JOB_TTL_ARG_NAME)
+                .required(false)
+                .default_value("3600"),
+        )
+}
+
+pub fn run_serve(matches: &ArgMatches) {
+    let address = matches.value_of(ADDRESS_ARG_NAME).unwrap();
+    let config = get_server_config(matches).unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    });
+
+    if let Err(err) = run_server(address, &config) {
+        eprintln!("cannot run server: {}", err);
+        process::exit(1);
+    }
+}
+
+fn get_server_config(matches: &ArgMatches) -> Result<ServerConfig, String> {
+    let workers = parse_int_value::<usize>(matches, WORKERS_ARG_NAME, "workers").unwrap();
+    let queue_size = parse_int_value::<usize>(matches, QUEUE_SIZE_ARG_NAME, "queue size").unwrap();
+    let connections = parse_int_value::<usize>(matches, CONNECTIONS_ARG_NAME, "connections").unwrap();
+    let timeout = parse_int_value::<u64>(matches, TIMEOUT_ARG_NAME, "timeout").unwrap();
+    let job_ttl = parse_int_value::<u64>(matches, JOB_TTL_ARG_NAME, "job ttl").unwrap();
+
+    if workers == 0 {
+        return Err("amount of workers should be greater than zero".to_string());
+    }
+
+    if connections == 0 {
+        return Err("amount of connections should be greater than zero".to_string());
+    }
+
+    if timeout == 0 {
+        return Err("timeout should be greater than zero".to_string());
+    }
+
+    Ok(ServerConfig {
+        workers,
+        queue_size,
+        connections,
+        timeout: Duration::from_secs(timeout),
+        job_ttl: Duration::from_secs(job_ttl),
+    })
+}
@@ -8,15 +8,23 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
 use std::process;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Once};
 use vrp_cli::core::solver::population::Population;
 use vrp_cli::extensions::check::check_pragmatic_solution;
-use vrp_cli::extensions::solve::config::create_builder_from_config_file;
+use vrp_cli::extensions::solve::config::{
+    create_builder_from_config, get_config_preset, read_config, TerminationConfig,
+};
+use vrp_cli::extensions::solve::reoptimization::{apply_change_set, read_change_set};
 use vrp_cli::{get_errors_serialized, get_locations_serialized};
+use vrp_core::construction::Quota;
+use vrp_core::models::common::Cost;
 use vrp_core::models::{Problem, Solution};
 use vrp_core::solver::population::{Elitism, Rosomaxa, RosomaxaConfig};
-use vrp_core::solver::{Builder, Metrics, Telemetry, TelemetryMode};
+use vrp_core::solver::{Builder, CheckpointCallback, CheckpointInterval, Metrics, Progress, Telemetry, TelemetryMode};
 use vrp_core::utils::{DefaultRandom, Random};
+use vrp_pragmatic::checker::CheckerError;
+use vrp_pragmatic::format::FormatError;
 
 const FORMAT_ARG_NAME: &str = "FORMAT";
 const PROBLEM_ARG_NAME: &str = "PROBLEM";
@@ -30,9 +38,30 @@ const INIT_SOLUTION_ARG_NAME: &str = "init-solution";
 const OUT_RESULT_ARG_NAME: &str = "out-result";
 const GET_LOCATIONS_ARG_NAME: &str = "get-locations";
 const CONFIG_ARG_NAME: &str = "config";
+const CONFIG_PRESET_ARG_NAME: &str = "config-preset";
 const LOG_ARG_NAME: &str = "log";
 const CHECK_ARG_NAME: &str = "check";
 const SEARCH_MODE_ARG_NAME: &str = "search-mode";
+const CHANGE_SET_ARG_NAME: &str = "change-set";
+const KEEP_ROUTES_ARG_NAME: &str = "keep-routes";
+const BEST_KNOWN_ARG_NAME: &str = "best-known";
+const OUT_FORMAT_ARG_NAME: &str = "out-format";
+const OUT_UNASSIGNED_ARG_NAME: &str = "out-unassigned";
+const EXPLAIN_UNASSIGNED_ARG_NAME: &str = "explain-unassigned";
+const CHECKPOINT_ARG_NAME: &str = "checkpoint";
+const CHECKPOINT_GENERATIONS_ARG_NAME: &str = "checkpoint-generations";
+const CHECKPOINT_TIME_ARG_NAME: &str = "checkpoint-time";
+const PARETO_FRONT_ARG_NAME: &str = "pareto-front";
+
+const CHECKPOINT_GENERATION_PLACEHOLDER: &str = "{generation}";
+const DEFAULT_CHECKPOINT_GENERATIONS: usize = 100;
+const PARETO_FRONT_INDEX_PLACEHOLDER: &str = "{index}";
+
+static IS_INTERRUPTED: AtomicBool = AtomicBool::new(false);
+static SIGNAL_HANDLER: Once = Once::new();
+
+/// A default time limit in seconds used when solution is re-optimized using change set.
+const REOPTIMIZATION_MAX_TIME: usize = 10;
 
 #[allow(clippy::type_complexity)]
 struct ProblemReader(pub Box<dyn Fn(File, Option<Vec<File>>) -> Result<Problem, String>>);
@@ -43,15 +72,23 @@ struct InitSolutionReader(pub Box<dyn Fn(File, Arc<Problem>) -> Result<Solution,
 struct SolutionWriter(
     pub  Box<
         dyn Fn(
-            &Problem,
+            &Arc<Problem>,
             Solution,
             Option<Metrics>,
             BufWriter<Box<dyn Write>>,
             Option<BufWriter<Box<dyn Write>>>,
+            SolutionOutput,
         ) -> Result<(), String>,
     >,
 );
 
+/// Specifies solution output format and its additional writers.
+struct SolutionOutput {
+    pub is_csv: bool,
+    pub is_detailed_unassigned: bool,
+    pub unassigned_writer: Option<BufWriter<Box<dyn Write>>>,
+}
+
 #[allow(clippy::type_complexity)]
 struct LocationWriter(pub Box<dyn Fn(File, BufWriter<Box<dyn Write>>) -> Result<(), String>>);
 
@@ -60,9 +97,13 @@ type FormatMap<'a> = HashMap<&'a str, (ProblemReader, InitSolutionReader, Soluti
 
 fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>) {
     if cfg!(feature = "scientific-format") {
+        use vrp_scientific::cordeau::{CordeauProblem, CordeauSolution};
+        use vrp_scientific::lilim::read_init_solution as read_init_lilim;
         use vrp_scientific::lilim::{LilimProblem, LilimSolution};
         use vrp_scientific::solomon::read_init_solution as read_init_solomon;
         use vrp_scientific::solomon::{SolomonProblem, SolomonSolution};
+        use vrp_scientific::tsplib::read_init_solution as read_init_tsplib;
+        use vrp_scientific::tsplib::{TsplibProblem, TsplibSolution};
 
         formats.insert(
             "solomon",
@@ -71,10 +112,11 @@ fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_solomon()
                 })),
-                InitSolutionReader(Box::new(move |file, problem| {
-                    read_init_solomon(BufReader::new(file), problem, random.clone())
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem| read_init_solomon(BufReader::new(file), problem, random.clone())
                 })),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_solomon(writer))),
+                SolutionWriter(Box::new(|_, solution, _, writer, _, _| solution.write_solomon(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
@@ -85,11 +127,58 @@ fn add_scientific(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>
                     assert!(matrices.is_none());
                     BufReader::new(problem).read_lilim()
                 })),
-                InitSolutionReader(Box::new(|_file, _problem| unimplemented!())),
-                SolutionWriter(Box::new(|_, solution, _, writer, _| solution.write_lilim(writer))),
+                InitSolutionReader(Box::new({
+                    let random = random.clone();
+                    move |file, problem| read_init_lilim(BufReader::new(file), problem, random.clone())
+                })),
+                SolutionWriter(Box::new(|_, solution, _, writer, _, _| solution.write_lilim(writer))),
                 LocationWriter(Box::new(|_, _| unimplemented!())),
             ),
         );
+        formats.insert(
+            "cordeau",
+            (
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_cordeau()
+                })),
+                InitSolutionReader(Box::new(|_file, _problem| {
+                    Err("initial solution is not supported for cordeau format".to_string())
+                })),
+                SolutionWriter(Box::new(|problem, solution, _, writer, geojson_writer, _| {
+                    if geojson_writer.is_some() {
+                        return Err("geojson output is not supported for cordeau format".to_string());
+                    }
+
+                    solution.write_cordeau(problem, writer)
+                })),
+                LocationWriter(Box::new(|_, _| {
+                    Err("getting locations is not supported for cordeau format".to_string())
+                })),
+            ),
+        );
+        formats.insert(
+            "tsplib",
+            (
+                ProblemReader(Box::new(|problem: File, matrices: Option<Vec<File>>| {
+                    assert!(matrices.is_none());
+                    BufReader::new(problem).read_tsplib()
+                })),
+                InitSolutionReader(Box::new(move |file, problem| {
+                    read_init_tsplib(BufReader::new(file), problem, random.clone())
+                })),
+                SolutionWriter(Box::new(|problem, solution, _, writer, geojson_writer, _| {
+                    if geojson_writer.is_some() {
+                        return Err("geojson output is not supported for tsplib format".to_string());
+                    }
+
+                    solution.write_tsplib(problem, writer)
+                })),
+                LocationWriter(Box::new(
+                    |_, _| Err("getting locations is not supported for tsplib format".to_string()),
+                )),
+            ),
+        );
     }
 }
 
@@ -97,6 +186,9 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
     use vrp_pragmatic::format::problem::{deserialize_problem, PragmaticProblem};
     use vrp_pragmatic::format::solution::read_init_solution as read_init_pragmatic;
     use vrp_pragmatic::format::solution::PragmaticSolution;
+    use vrp_pragmatic::format::solution::{
+        create_solution_with_unassigned_details, serialize_solution, write_csv_solution,
+    };
 
     formats.insert(
         "pragmatic",
@@ -113,11 +205,17 @@ fn add_pragmatic(formats: &mut FormatMap, random: Arc<dyn Random + Send + Sync>)
             InitSolutionReader(Box::new(move |file, problem| {
                 read_init_pragmatic(BufReader::new(file), problem, random.clone())
             })),
-            SolutionWriter(Box::new(|problem, solution, metrics, default_writer, geojson_writer| {
+            SolutionWriter(Box::new(|problem, solution, metrics, default_writer, geojson_writer, output| {
                 geojson_writer
                     .map_or(Ok(()), |geojson_writer| solution.write_geo_json(problem, geojson_writer))
                     .and_then(|_| {
-                        if let Some(metrics) = metrics {
+                        if output.is_csv {
+                            write_csv_solution(problem, &solution, default_writer, output.unassigned_writer)
+                        } else if output.is_detailed_unassigned {
+                            let solution =
+                                create_solution_with_unassigned_details(problem.clone(), &solution, metrics.as_ref());
+                            serialize_solution(default_writer, &solution).map_err(|err| err.to_string())
+                        } else if let Some(metrics) = metrics {
                             (solution, metrics).write_pragmatic_json(problem, default_writer)
                         } else {
                             solution.write_pragmatic_json(problem, default_writer)
@@ -157,7 +255,7 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
             Arg::with_name(FORMAT_ARG_NAME)
                 .help("Specifies the problem type")
                 .required(true)
-                .possible_values(&["solomon", "lilim", "pragmatic"])
+                .possible_values(&["solomon", "lilim", "tsplib", "cordeau", "pragmatic"])
                 .index(1),
         )
         .arg(Arg::with_name(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
@@ -225,6 +323,28 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(OUT_FORMAT_ARG_NAME)
+                .help("Specifies solution output format, csv is supported only for pragmatic format")
+                .long(OUT_FORMAT_ARG_NAME)
+                .required(false)
+                .possible_values(&["json", "csv"])
+                .default_value("json"),
+        )
+        .arg(
+            Arg::with_name(OUT_UNASSIGNED_ARG_NAME)
+                .help("Specifies path to unassigned jobs output in csv format")
+                .long(OUT_UNASSIGNED_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(EXPLAIN_UNASSIGNED_ARG_NAME)
+                .help("Specifies whether unassigned jobs should be explained by constraints violated per vehicle shift")
+                .long(EXPLAIN_UNASSIGNED_ARG_NAME)
+                .required(false)
+                .takes_value(false),
+        )
         .arg(
             Arg::with_name(CONFIG_ARG_NAME)
                 .help("Specifies path to algorithm configuration file")
@@ -233,6 +353,18 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .required(false)
                 .takes_value(true),
         )
+        .arg(
+            Arg::with_name(CONFIG_PRESET_ARG_NAME)
+                .help(
+                    "Specifies algorithm configuration preset. Termination settings of the preset are overridden \
+                     by termination options when they are specified",
+                )
+                .long(CONFIG_PRESET_ARG_NAME)
+                .required(false)
+                .possible_values(&["fast", "balanced", "thorough"])
+                .conflicts_with(CONFIG_ARG_NAME)
+                .takes_value(true),
+        )
         .arg(
             Arg::with_name(LOG_ARG_NAME)
                 .help("Specifies whether default logging is enabled")
@@ -256,6 +388,76 @@ pub fn get_solve_app<'a, 'b>() -> App<'a, 'b> {
                 .possible_values(&["broad", "deep"])
                 .default_value("broad"),
         )
+        .arg(
+            Arg::with_name(CHANGE_SET_ARG_NAME)
+                .help(
+                    "Specifies path to file with changes (new jobs, cancelled jobs, unavailable vehicles) \
+                     to be applied to the problem and its initial solution (pragmatic format only)",
+                )
+                .long(CHANGE_SET_ARG_NAME)
+                .required(false)
+                .requires(INIT_SOLUTION_ARG_NAME)
+                .conflicts_with(CHECK_ARG_NAME)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(KEEP_ROUTES_ARG_NAME)
+                .help("Specifies how routes which are not affected by change set are kept")
+                .long(KEEP_ROUTES_ARG_NAME)
+                .required(false)
+                .possible_values(&["none", "any", "sequence", "strict"])
+                .default_value("any"),
+        )
+        .arg(
+            Arg::with_name(BEST_KNOWN_ARG_NAME)
+                .help(
+                    "Specifies path to file with best known solution: gap in tour count and distance \
+                     is reported after solving (scientific formats only)",
+                )
+                .long(BEST_KNOWN_ARG_NAME)
+                .short("b")
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(CHECKPOINT_ARG_NAME)
+                .help(
+                    "Specifies path to file where the best known solution is written periodically, so it can be \
+                     used later as initial solution (pragmatic format only). If path contains '{generation}', \
+                     it is replaced with generation number to keep a series of files",
+                )
+                .long(CHECKPOINT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(CHECKPOINT_GENERATIONS_ARG_NAME)
+                .help("Specifies how often (in generations) checkpoint is written. Default is 100")
+                .long(CHECKPOINT_GENERATIONS_ARG_NAME)
+                .requires(CHECKPOINT_ARG_NAME)
+                .conflicts_with(CHECKPOINT_TIME_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(CHECKPOINT_TIME_ARG_NAME)
+                .help("Specifies how often (in seconds) checkpoint is written")
+                .long(CHECKPOINT_TIME_ARG_NAME)
+                .requires(CHECKPOINT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
+        .arg(
+            Arg::with_name(PARETO_FRONT_ARG_NAME)
+                .help(
+                    "Specifies path to files where all non-dominated solutions (pareto front) are written with \
+                     their objective values (pragmatic format only). Path should contain '{index}' which is \
+                     replaced with solution index, the first solution is the same as the best known one",
+                )
+                .long(PARETO_FRONT_ARG_NAME)
+                .required(false)
+                .takes_value(true),
+        )
 }
 
 /// Runs solver commands.
@@ -294,11 +496,84 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
     });
     let init_solution = matches.value_of(INIT_SOLUTION_ARG_NAME).map(|path| open_file(path, "init solution"));
     let config = matches.value_of(CONFIG_ARG_NAME).map(|path| open_file(path, "config"));
+    let config_preset = matches.value_of(CONFIG_PRESET_ARG_NAME);
     let matrix_files = get_matrix_files(matches);
     let out_result = matches.value_of(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "out solution"));
     let out_geojson = matches.value_of(GEO_JSON_ARG_NAME).map(|path| create_file(path, "out geojson"));
     let is_get_locations_set = matches.is_present(GET_LOCATIONS_ARG_NAME);
     let mode = matches.value_of(SEARCH_MODE_ARG_NAME);
+    let change_set = matches.value_of(CHANGE_SET_ARG_NAME).map(|path| open_file(path, "change set"));
+    let keep_routes = matches.value_of(KEEP_ROUTES_ARG_NAME);
+    let best_known = matches.value_of(BEST_KNOWN_ARG_NAME).map(|path| open_file(path, "best known solution"));
+    let is_csv_output = matches.value_of(OUT_FORMAT_ARG_NAME) == Some("csv");
+    let out_unassigned = matches.value_of(OUT_UNASSIGNED_ARG_NAME).map(|path| create_file(path, "out unassigned"));
+    let is_detailed_unassigned = matches.is_present(EXPLAIN_UNASSIGNED_ARG_NAME);
+    let checkpoint_path = matches.value_of(CHECKPOINT_ARG_NAME);
+    let pareto_front_path = matches.value_of(PARETO_FRONT_ARG_NAME);
+    let checkpoint_interval = match parse_int_value::<usize>(matches, CHECKPOINT_TIME_ARG_NAME, "checkpoint time") {
+        Some(seconds) => CheckpointInterval::Seconds(seconds),
+        None => CheckpointInterval::Generations(
+            parse_int_value::<usize>(matches, CHECKPOINT_GENERATIONS_ARG_NAME, "checkpoint generations")
+                .unwrap_or(DEFAULT_CHECKPOINT_GENERATIONS),
+        ),
+    };
+
+    if change_set.is_some() && problem_format != "pragmatic" {
+        eprintln!("change set is supported only for pragmatic format");
+        process::exit(1);
+    }
+
+    if is_csv_output && problem_format != "pragmatic" {
+        eprintln!("csv output format is supported only for pragmatic format");
+        process::exit(1);
+    }
+
+    if out_unassigned.is_some() && !is_csv_output {
+        eprintln!("unassigned jobs output is supported only for csv output format");
+        process::exit(1);
+    }
+
+    if is_detailed_unassigned && (is_csv_output || problem_format != "pragmatic") {
+        eprintln!("unassigned jobs explanation is supported only for pragmatic json output format");
+        process::exit(1);
+    }
+
+    if is_check_requested && is_csv_output {
+        eprintln!("solution check is not supported for csv output format");
+        process::exit(1);
+    }
+
+    if checkpoint_path.is_some() && problem_format != "pragmatic" {
+        eprintln!("checkpoint is supported only for pragmatic format");
+        process::exit(1);
+    }
+
+    if let CheckpointInterval::Generations(0) = checkpoint_interval {
+        eprintln!("checkpoint generations should be greater than zero");
+        process::exit(1);
+    }
+
+    if pareto_front_path.is_some() && problem_format != "pragmatic" {
+        eprintln!("pareto front is supported only for pragmatic format");
+        process::exit(1);
+    }
+
+    if let Some(path) = pareto_front_path.filter(|path| !path.contains(PARETO_FRONT_INDEX_PLACEHOLDER)) {
+        eprintln!("pareto front path '{}' should contain '{}' placeholder", path, PARETO_FRONT_INDEX_PLACEHOLDER);
+        process::exit(1);
+    }
+
+    if best_known.is_some() && problem_format == "pragmatic" {
+        eprintln!("best known solution is supported only for scientific formats");
+        process::exit(1);
+    }
+
+    let is_reoptimization = change_set.is_some();
+    let max_time = if is_reoptimization && max_time.is_none() && max_generations.is_none() {
+        Some(REOPTIMIZATION_MAX_TIME)
+    } else {
+        max_time
+    };
 
     match formats.get(problem_format) {
         Some((problem_reader, init_reader, solution_writer, locations_writer)) => {
@@ -311,8 +586,10 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
                     process::exit(1);
                 });
             } else {
-                match problem_reader.0(problem_file, matrix_files) {
-                    Ok(problem) => {
+                let problem_and_solutions = if let Some(change_set) = change_set {
+                    read_with_change_set(problem_file, matrix_files, init_solution, change_set, keep_routes)
+                } else {
+                    problem_reader.0(problem_file, matrix_files).map(|problem| {
                         let problem = Arc::new(problem);
                         let solutions = init_solution.map_or_else(Vec::new, |file| {
                             init_reader.0(file, problem.clone())
@@ -324,13 +601,47 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
                                 .unwrap()
                         });
 
+                        (problem, solutions)
+                    })
+                };
+
+                match problem_and_solutions {
+                    Ok((problem, solutions)) => {
+                        let best_known = best_known.map(|file| {
+                            init_reader.0(file, problem.clone()).unwrap_or_else(|err| {
+                                eprintln!("cannot read best known solution '{}'", err);
+                                process::exit(1);
+                            })
+                        });
+
                         let builder = if let Some(config) = config {
-                            create_builder_from_config_file(problem.clone(), BufReader::new(config)).unwrap_or_else(
-                                |err| {
+                            read_config(BufReader::new(config))
+                                .and_then(|mut config| {
+                                    // NOTE termination from config takes precedence over re-optimization default
+                                    if is_reoptimization && config.termination.is_none() {
+                                        config.termination = Some(TerminationConfig {
+                                            max_time: Some(REOPTIMIZATION_MAX_TIME),
+                                            max_generations: None,
+                                            variation: None,
+                                        });
+                                    }
+
+                                    create_builder_from_config(problem.clone(), &config)
+                                })
+                                .unwrap_or_else(|err| {
                                     eprintln!("cannot read config: '{}'", err);
                                     process::exit(1);
-                                },
-                            )
+                                })
+                        } else if let Some(preset) = config_preset {
+                            let builder = get_config_preset(preset)
+                                .and_then(|config| create_builder_from_config(problem.clone(), &config))
+                                .unwrap_or_else(|err| {
+                                    eprintln!("cannot create config from preset: '{}'", err);
+                                    process::exit(1);
+                                })
+                                .with_telemetry(telemetry);
+
+                            override_termination(builder, max_generations, max_time, cost_variation)
                         } else {
                             Builder::new(problem.clone())
                                 .with_max_generations(max_generations)
@@ -340,16 +651,53 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
                                 .with_telemetry(telemetry)
                         };
 
-                        let (solution, _, metrics) = builder
+                        let builder = if let Some(path) = checkpoint_path {
+                            builder.with_checkpoint(
+                                checkpoint_interval,
+                                create_checkpoint_callback(problem.clone(), path.to_string()),
+                            )
+                        } else {
+                            builder
+                        };
+
+                        let solver = builder
                             .with_init_solutions(solutions)
+                            .with_quota(create_interruption_quota())
                             .build()
-                            .and_then(|solver| solver.solve())
                             .unwrap_or_else(|err| {
                                 eprintln!("cannot find any solution: '{}'", err);
                                 process::exit(1);
                             });
 
-                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer).unwrap();
+                        let (solution, metrics) = if let Some(path) = pareto_front_path {
+                            solver.solve_pareto_front().map(|(front, metrics)| {
+                                write_pareto_front(&problem, &front, path);
+                                (front.into_iter().next().map(|(solution, _, _)| solution).unwrap(), metrics)
+                            })
+                        } else {
+                            solver.solve().map(|(solution, _, metrics)| (solution, metrics))
+                        }
+                        .unwrap_or_else(|err| {
+                            eprintln!("cannot find any solution: '{}'", err);
+                            process::exit(1);
+                        });
+
+                        if let Some(best_known) = best_known {
+                            print_best_known_gap(&problem, &solution, &best_known);
+                        }
+
+                        let output = SolutionOutput {
+                            is_csv: is_csv_output,
+                            is_detailed_unassigned,
+                            unassigned_writer: out_unassigned.map(|file| create_write_buffer(Some(file))),
+                        };
+
+                        solution_writer.0(&problem, solution, metrics, out_buffer, geo_buffer, output).unwrap_or_else(
+                            |err| {
+                                eprintln!("cannot write solution: '{}'", err);
+                                process::exit(1);
+                            },
+                        );
 
                         if is_check_requested {
                             check_solution(matches);
@@ -369,6 +717,137 @@ pub fn run_solve(matches: &ArgMatches, out_writer_func: fn(Option<File>) -> BufW
     }
 }
 
+/// Overrides termination criteria of the builder only with those which are specified.
+fn override_termination(
+    builder: Builder,
+    max_generations: Option<usize>,
+    max_time: Option<usize>,
+    cost_variation: Option<(usize, f64)>,
+) -> Builder {
+    let builder = if max_generations.is_some() { builder.with_max_generations(max_generations) } else { builder };
+    let builder = if max_time.is_some() { builder.with_max_time(max_time) } else { builder };
+
+    if cost_variation.is_some() {
+        builder.with_cost_variation(cost_variation)
+    } else {
+        builder
+    }
+}
+
+fn print_best_known_gap(problem: &Problem, solution: &Solution, best_known: &Solution) {
+    if cfg!(feature = "scientific-format") {
+        use vrp_scientific::common::BestKnownGap;
+
+        eprintln!("best known gap: {}", BestKnownGap::new(problem, solution, best_known));
+    }
+}
+
+/// Creates a callback which writes the best known solution in pragmatic format to the checkpoint file.
+/// A temporary file is renamed to the target one, so an interrupted write never corrupts a checkpoint.
+fn create_checkpoint_callback(problem: Arc<Problem>, path: String) -> CheckpointCallback {
+    use vrp_pragmatic::format::solution::PragmaticSolution;
+
+    Arc::new(move |progress: &Progress, solution: &Solution| {
+        let path = path.replace(CHECKPOINT_GENERATION_PLACEHOLDER, progress.generation.to_string().as_str());
+        let tmp_path = format!("{}.tmp", path);
+
+        File::create(&tmp_path)
+            .map_err(|err| err.to_string())
+            .and_then(|file| solution.write_pragmatic_json(&problem, BufWriter::new(file)))
+            .and_then(|_| std::fs::rename(&tmp_path, &path).map_err(|err| err.to_string()))
+            .unwrap_or_else(|err| eprintln!("cannot write checkpoint to '{}': '{}'", path, err));
+    })
+}
+
+/// Writes each solution of the pareto front with its objective values in pragmatic format into
+/// a separate file.
+fn write_pareto_front(problem: &Problem, front: &[(Solution, Cost, Vec<f64>)], path: &str) {
+    use vrp_pragmatic::format::solution::{create_solution_with_objectives, serialize_solution};
+
+    front.iter().enumerate().for_each(|(idx, (solution, _, fitness))| {
+        let path = path.replace(PARETO_FRONT_INDEX_PLACEHOLDER, idx.to_string().as_str());
+        let solution = create_solution_with_objectives(problem, solution, fitness.as_slice());
+
+        serialize_solution(create_write_buffer(Some(create_file(path.as_str(), "pareto front solution"))), &solution)
+            .unwrap_or_else(|err| {
+                eprintln!("cannot write pareto front solution to '{}': '{}'", path, err);
+                process::exit(1);
+            });
+    });
+}
+
+/// A quota which is reached when the interruption flag is set, e.g. when the process receives SIGINT or SIGTERM.
+struct InterruptionQuota {
+    is_interrupted: &'static AtomicBool,
+}
+
+impl Quota for InterruptionQuota {
+    fn is_reached(&self) -> bool {
+        self.is_interrupted.load(Ordering::Relaxed)
+    }
+}
+
+/// Creates a quota which stops the search on SIGINT or SIGTERM, so the best known solution is written
+/// to the configured output as usual. A second signal terminates the process immediately.
+fn create_interruption_quota() -> Arc<dyn Quota + Send + Sync> {
+    SIGNAL_HANDLER.call_once(|| {
+        ctrlc::set_handler(|| {
+            if IS_INTERRUPTED.swap(true, Ordering::Relaxed) {
+                process::exit(130);
+            }
+
+            eprintln!("interrupted, stopping search and writing the best known solution");
+        })
+        .unwrap_or_else(|err| eprintln!("cannot set signal handler: '{}'", err));
+    });
+
+    Arc::new(InterruptionQuota { is_interrupted: &IS_INTERRUPTED })
+}
+
+/// Reads pragmatic problem and its initial solution, then applies change set to both of them.
+fn read_with_change_set(
+    problem: File,
+    matrices: Option<Vec<File>>,
+    init_solution: Option<File>,
+    change_set: File,
+    keep_routes: Option<&str>,
+) -> Result<(Arc<Problem>, Vec<Solution>), String> {
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem, RelationType};
+    use vrp_pragmatic::format::solution::{deserialize_solution, read_init_solution, serialize_solution};
+
+    let to_string =
+        |errors: Vec<FormatError>| errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join("\t\n");
+
+    let problem = deserialize_problem(BufReader::new(problem)).map_err(to_string)?;
+    let matrices = matrices
+        .map(|matrices| {
+            matrices.into_iter().map(|matrix| deserialize_matrix(BufReader::new(matrix))).collect::<Result<Vec<_>, _>>()
+        })
+        .transpose()
+        .map_err(to_string)?;
+    let solution = init_solution
+        .ok_or_else(|| "change set requires initial solution".to_string())
+        .and_then(|file| deserialize_solution(BufReader::new(file)).map_err(|err| err.to_string()))?;
+    let change_set = read_change_set(BufReader::new(change_set))?;
+
+    let relation_type = match keep_routes {
+        Some("any") => Some(RelationType::Any),
+        Some("sequence") => Some(RelationType::Sequence),
+        Some("strict") => Some(RelationType::Strict),
+        _ => None,
+    };
+
+    let (problem, solution) = apply_change_set(problem, solution, &change_set, relation_type)?;
+
+    let problem = Arc::new((problem, matrices).read_pragmatic().map_err(to_string)?);
+
+    let mut buffer = Vec::new();
+    serialize_solution(BufWriter::new(&mut buffer), &solution).map_err(|err| err.to_string())?;
+    let solution = read_init_solution(BufReader::new(buffer.as_slice()), problem.clone(), get_random())?;
+
+    Ok((problem, vec![solution]))
+}
+
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
     matches
         .values_of(MATRIX_ARG_NAME)
@@ -402,8 +881,8 @@ fn check_solution(matches: &ArgMatches) {
 
     let result = check_pragmatic_solution(problem_file, solution_file, matrix_files);
 
-    if let Err(err) = result {
-        eprintln!("{}", err);
+    if let Err(errors) = result {
+        eprintln!("{}", CheckerError::format_many(&errors, "\n"));
         process::exit(1);
     } else {
         println!("solution feasibility check is completed successfully");
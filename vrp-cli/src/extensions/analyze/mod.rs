@@ -0,0 +1,498 @@
+//! Analyze command helpers: collects problem statistics and runs feasibility pre-analysis.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/analyze/analyze_test.rs"]
+mod analyze_test;
+
+use serde::Serialize;
+use std::collections::HashSet;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::common::{IdDimension, TimeWindow};
+use vrp_core::models::problem::{Actor, Job as CoreJob, Single, TransportCost};
+use vrp_core::models::Problem as CoreProblem;
+use vrp_core::utils::DefaultRandom;
+use vrp_pragmatic::format::problem::*;
+use vrp_pragmatic::format::solution::map_code_reason;
+use vrp_pragmatic::format::FormatError;
+use vrp_pragmatic::parse_time_safe;
+use vrp_pragmatic::validation::ValidationContext;
+
+/// Contains problem statistics and results of feasibility pre-analysis.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProblemAnalysis {
+    /// Job statistics.
+    pub jobs: JobStatistic,
+    /// Fleet statistics.
+    pub fleet: FleetStatistic,
+    /// Total demand vs total fleet capacity per dimension.
+    pub capacity: Vec<CapacityStatistic>,
+    /// Time window tightness statistics.
+    pub time_windows: TimeWindowStatistic,
+    /// Jobs which cannot be served by any vehicle due to hard route constraints, e.g. skills or areas.
+    pub incompatible_jobs: Vec<JobIssue>,
+    /// Jobs which time windows cannot be reached from any vehicle shift.
+    pub unreachable_jobs: Vec<String>,
+    /// An estimated lower bound of tours amount.
+    pub min_tours: usize,
+}
+
+/// Contains job statistics.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobStatistic {
+    /// Total amount of jobs.
+    pub total: usize,
+    /// Amount of pickup tasks.
+    pub pickups: usize,
+    /// Amount of delivery tasks.
+    pub deliveries: usize,
+    /// Amount of replacement tasks.
+    pub replacements: usize,
+    /// Amount of service tasks.
+    pub services: usize,
+    /// Amount of jobs with more than one task.
+    pub multi: usize,
+}
+
+/// Contains fleet statistics.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetStatistic {
+    /// Amount of vehicle types.
+    pub vehicle_types: usize,
+    /// Amount of vehicles.
+    pub vehicles: usize,
+    /// Amount of vehicle shifts.
+    pub shifts: usize,
+    /// Amount of routing profiles.
+    pub profiles: usize,
+}
+
+/// Contains total demand and total fleet capacity for one capacity dimension.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapacityStatistic {
+    /// Capacity dimension index.
+    pub dimension: usize,
+    /// Total demand of all jobs.
+    pub demand: i64,
+    /// Total capacity of all vehicle shifts (reloads are not considered).
+    pub capacity: i64,
+    /// A ratio of demand to capacity, omitted if capacity is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f64>,
+}
+
+/// Contains time window tightness statistics.
+#[derive(Clone, Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeWindowStatistic {
+    /// Amount of job places with time windows.
+    pub places: usize,
+    /// Amount of job places without time windows.
+    pub unrestricted_places: usize,
+    /// Minimal time window width in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_width: Option<f64>,
+    /// Average time window width in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_width: Option<f64>,
+    /// Average ratio of time window width to the planning horizon: the smaller, the tighter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avg_ratio: Option<f64>,
+}
+
+/// Describes why a job cannot be served by any vehicle.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobIssue {
+    /// Job id.
+    pub job_id: String,
+    /// Issue code.
+    pub code: String,
+    /// Issue description.
+    pub description: String,
+}
+
+/// Analyzes pragmatic problem: validates it and collects statistics which help to understand
+/// its structure and detect obvious feasibility issues before solving.
+pub fn analyze_pragmatic_problem(
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+) -> Result<ProblemAnalysis, Vec<FormatError>> {
+    ValidationContext::new(&problem, matrices.as_ref()).validate()?;
+
+    // NOTE time parse errors have to be reported before reading core problem
+    let time_windows = get_time_window_statistic(&problem)?;
+    let core_problem = Arc::new((problem.clone(), matrices).read_pragmatic()?);
+
+    let capacity = get_capacity_statistic(&problem);
+    let min_tours = get_min_tours(&problem, capacity.as_slice());
+
+    Ok(ProblemAnalysis {
+        jobs: get_job_statistic(&problem),
+        fleet: get_fleet_statistic(&problem),
+        capacity,
+        time_windows,
+        incompatible_jobs: get_incompatible_jobs(&problem, core_problem.clone()),
+        unreachable_jobs: get_unreachable_jobs(&problem, core_problem.as_ref()),
+        min_tours,
+    })
+}
+
+fn get_job_statistic(problem: &Problem) -> JobStatistic {
+    let count = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().map_or(0, |tasks| tasks.len());
+
+    problem.plan.jobs.iter().fold(JobStatistic::default(), |mut acc, job| {
+        let pickups = count(&job.pickups);
+        let deliveries = count(&job.deliveries);
+        let replacements = count(&job.replacements);
+        let services = count(&job.services);
+
+        acc.total += 1;
+        acc.pickups += pickups;
+        acc.deliveries += deliveries;
+        acc.replacements += replacements;
+        acc.services += services;
+        acc.multi += if pickups + deliveries + replacements + services > 1 { 1 } else { 0 };
+
+        acc
+    })
+}
+
+fn get_fleet_statistic(problem: &Problem) -> FleetStatistic {
+    let vehicles = &problem.fleet.vehicles;
+
+    FleetStatistic {
+        vehicle_types: vehicles.len(),
+        vehicles: vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len()).sum(),
+        shifts: vehicles.iter().map(|vehicle| vehicle.vehicle_ids.len() * vehicle.shifts.len()).sum(),
+        profiles: vehicles.iter().map(|vehicle| vehicle.profile.as_str()).collect::<HashSet<_>>().len(),
+    }
+}
+
+fn get_capacity_statistic(problem: &Problem) -> Vec<CapacityStatistic> {
+    let dimensions = problem.fleet.vehicles.iter().map(|vehicle| vehicle.capacity.len()).max().unwrap_or(0);
+
+    let mut demand = vec![0_i64; dimensions];
+    problem.plan.jobs.iter().for_each(|job| add_values(&mut demand, get_job_demand(job).as_slice()));
+
+    let mut capacity = vec![0_i64; dimensions];
+    problem.fleet.vehicles.iter().for_each(|vehicle| {
+        let amount = (vehicle.vehicle_ids.len() * vehicle.shifts.len()) as i64;
+        let vehicle_capacity = vehicle.capacity.iter().map(|value| *value as i64 * amount).collect::<Vec<_>>();
+        add_values(&mut capacity, vehicle_capacity.as_slice());
+    });
+
+    demand
+        .into_iter()
+        .zip(capacity)
+        .enumerate()
+        .map(|(dimension, (demand, capacity))| CapacityStatistic {
+            dimension,
+            demand,
+            capacity,
+            ratio: if capacity > 0 { Some(demand as f64 / capacity as f64) } else { None },
+        })
+        .collect()
+}
+
+/// Returns job's demand which occupies vehicle capacity: for pickup and delivery job, only pickups
+/// are considered as the same goods are delivered later.
+fn get_job_demand(job: &Job) -> Vec<i64> {
+    let sum = |tasks_list: &[&Option<Vec<JobTask>>]| {
+        tasks_list.iter().filter_map(|tasks| tasks.as_ref()).flatten().filter_map(|task| task.demand.as_ref()).fold(
+            vec![],
+            |mut acc, demand| {
+                add_values(&mut acc, demand.iter().map(|value| *value as i64).collect::<Vec<_>>().as_slice());
+                acc
+            },
+        )
+    };
+
+    match (&job.pickups, &job.deliveries) {
+        (Some(_), Some(_)) => sum(&[&job.pickups]),
+        _ => sum(&[&job.pickups, &job.deliveries, &job.replacements]),
+    }
+}
+
+fn add_values(acc: &mut Vec<i64>, values: &[i64]) {
+    if acc.len() < values.len() {
+        acc.resize(values.len(), 0);
+    }
+
+    acc.iter_mut().zip(values.iter()).for_each(|(acc, value)| *acc += value);
+}
+
+fn get_time_window_statistic(problem: &Problem) -> Result<TimeWindowStatistic, Vec<FormatError>> {
+    let mut invalid_type_ids = Vec::new();
+    let horizon = problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter().map(move |shift| (vehicle, shift)))
+        .filter_map(|(vehicle, shift)| {
+            let start = parse_time_safe(shift.start.earliest.as_str());
+            let end = shift.end.as_ref().map_or(Ok(f64::MAX), |end| parse_time_safe(end.latest.as_str()));
+
+            match (start, end) {
+                (Ok(start), Ok(end)) => Some((start, end)),
+                _ => {
+                    invalid_type_ids.push(vehicle.type_id.clone());
+                    None
+                }
+            }
+        })
+        .fold(None, |acc: Option<(f64, f64)>, (start, end)| match acc {
+            Some((acc_start, acc_end)) => Some((acc_start.min(start), acc_end.max(end))),
+            None => Some((start, end)),
+        })
+        .map(|(start, end)| end - start);
+
+    let mut invalid_job_ids = Vec::new();
+    let places = problem
+        .plan
+        .jobs
+        .iter()
+        .flat_map(|job| get_job_tasks(job).flat_map(|task| task.places.iter()).map(move |place| (job, place)))
+        .collect::<Vec<_>>();
+    let widths = places
+        .iter()
+        .filter_map(|(job, place)| place.times.as_ref().map(|times| (job, times)))
+        .filter_map(|(job, times)| {
+            let width = times.iter().try_fold(0., |acc, tw| match tw.as_slice() {
+                [start, end] => Some(acc + parse_time_safe(end).ok()? - parse_time_safe(start).ok()?),
+                _ => None,
+            });
+
+            if width.is_none() && !invalid_job_ids.contains(&job.id) {
+                invalid_job_ids.push(job.id.clone());
+            }
+
+            width
+        })
+        .collect::<Vec<_>>();
+
+    let mut errors = vec![];
+    if !invalid_type_ids.is_empty() {
+        invalid_type_ids.dedup();
+        errors.push(FormatError::new(
+            "E1302".to_string(),
+            "invalid start or end times in vehicle shift".to_string(),
+            format!(
+                "ensure that start and end times have RFC3339 format, vehicle type ids: {}",
+                invalid_type_ids.join(", ")
+            ),
+        ));
+    }
+    if !invalid_job_ids.is_empty() {
+        errors.push(FormatError::new(
+            "E1103".to_string(),
+            "invalid time windows in jobs".to_string(),
+            format!("ensure that time windows have RFC3339 format, jobs: '{}'", invalid_job_ids.join(", ")),
+        ));
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    if widths.is_empty() {
+        return Ok(TimeWindowStatistic { unrestricted_places: places.len(), ..TimeWindowStatistic::default() });
+    }
+
+    let count = widths.len() as f64;
+
+    Ok(TimeWindowStatistic {
+        places: widths.len(),
+        unrestricted_places: places.len() - widths.len(),
+        min_width: widths
+            .iter()
+            .cloned()
+            .fold(None, |acc: Option<f64>, width| Some(acc.map_or(width, |acc| acc.min(width)))),
+        avg_width: Some(widths.iter().sum::<f64>() / count),
+        avg_ratio: horizon
+            .filter(|horizon| *horizon > 0. && *horizon < f64::MAX / 2.)
+            .map(|horizon| widths.iter().map(|width| (width / horizon).min(1.)).sum::<f64>() / count),
+    })
+}
+
+fn get_job_tasks(job: &Job) -> impl Iterator<Item = &JobTask> + '_ {
+    job.pickups
+        .iter()
+        .chain(job.deliveries.iter())
+        .chain(job.replacements.iter())
+        .chain(job.services.iter())
+        .flat_map(|tasks| tasks.iter())
+}
+
+/// Returns jobs which are rejected by hard route constraints, such as skills or areas, for every actor.
+fn get_incompatible_jobs(problem: &Problem, core_problem: Arc<CoreProblem>) -> Vec<JobIssue> {
+    let job_ids = problem.plan.jobs.iter().map(|job| &job.id).collect::<HashSet<_>>();
+    let ctx = InsertionContext::new(core_problem.clone(), Arc::new(DefaultRandom::default()));
+    let routes = ctx.solution.registry.available().collect::<Vec<_>>();
+    let constraint = &core_problem.constraint;
+
+    if routes.is_empty() {
+        return vec![];
+    }
+
+    let mut issues = core_problem
+        .jobs
+        .all()
+        .filter_map(|job| {
+            let id = job.dimens().get_id()?;
+
+            // NOTE skip conditional jobs created for breaks, reloads, etc.
+            if !job_ids.contains(id) {
+                return None;
+            }
+
+            let reasons = routes
+                .iter()
+                .map(|route_ctx| {
+                    constraint
+                        .evaluate_hard_route(&ctx.solution, route_ctx, &job)
+                        .map(|violation| map_code_reason(violation.code))
+                })
+                .collect::<Option<Vec<_>>>()?;
+
+            // NOTE report skill constraint only when no actor has required skills
+            let (code, description) =
+                reasons.iter().find(|(code, _)| *code != "SKILL_CONSTRAINT").or_else(|| reasons.first()).cloned()?;
+
+            Some(JobIssue { job_id: id.clone(), code: code.to_string(), description: description.to_string() })
+        })
+        .collect::<Vec<_>>();
+
+    issues.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    issues
+}
+
+fn get_unreachable_jobs(problem: &Problem, core_problem: &CoreProblem) -> Vec<String> {
+    let job_ids = problem.plan.jobs.iter().map(|job| &job.id).collect::<HashSet<_>>();
+    let transport = core_problem.transport.as_ref();
+    let actors = &core_problem.fleet.actors;
+
+    let mut unreachable = core_problem
+        .jobs
+        .all()
+        .filter_map(|job| {
+            let (id, singles) = match &job {
+                CoreJob::Single(single) => (single.dimens.get_id()?, vec![single.clone()]),
+                CoreJob::Multi(multi) => (multi.dimens.get_id()?, multi.jobs.clone()),
+            };
+
+            // NOTE skip conditional jobs created for breaks, reloads, etc.
+            if !job_ids.contains(id) {
+                return None;
+            }
+
+            let is_reachable = actors
+                .iter()
+                .any(|actor| singles.iter().all(|single| is_reachable_from_actor(actor, single, transport)));
+
+            if is_reachable {
+                None
+            } else {
+                Some(id.clone())
+            }
+        })
+        .collect::<Vec<_>>();
+
+    unreachable.sort();
+
+    unreachable
+}
+
+/// Checks whether at least one job's place can be served within its time windows when the vehicle
+/// departs as early as possible and has to return back before its shift end.
+fn is_reachable_from_actor(actor: &Actor, single: &Single, transport: &(dyn TransportCost + Send + Sync)) -> bool {
+    let profile = actor.vehicle.profile;
+    let (start_location, departure) = match actor.detail.start.as_ref() {
+        Some(start) => (start.location, start.time.earliest.unwrap_or(actor.detail.time.start)),
+        None => return true,
+    };
+
+    single.places.iter().any(|place| {
+        let location = match place.location {
+            Some(location) => location,
+            None => return true,
+        };
+
+        let arrival = departure + transport.duration(profile, start_location, location, departure);
+
+        // NOTE offset time windows are relative to the tour start, so they are considered as reachable
+        let time_windows = if place.times.iter().any(|time| time.as_time_window().is_none()) || place.times.is_empty() {
+            vec![TimeWindow::max()]
+        } else {
+            place.times.iter().filter_map(|time| time.as_time_window()).collect()
+        };
+
+        time_windows.iter().any(|tw| {
+            let service_start = arrival.max(tw.start);
+            if service_start > tw.end {
+                return false;
+            }
+
+            let service_end = service_start + place.duration;
+
+            match actor.detail.end.as_ref() {
+                Some(end) => {
+                    let latest = end.time.latest.unwrap_or(actor.detail.time.end);
+                    service_end + transport.duration(profile, location, end.location, service_end) <= latest
+                }
+                None => service_end <= actor.detail.time.end,
+            }
+        })
+    })
+}
+
+fn get_min_tours(problem: &Problem, capacity: &[CapacityStatistic]) -> usize {
+    if problem.plan.jobs.is_empty() {
+        return 0;
+    }
+
+    let vehicles = &problem.fleet.vehicles;
+    let has_reloads = vehicles.iter().flat_map(|vehicle| vehicle.shifts.iter()).any(|shift| shift.reloads.is_some());
+
+    let by_capacity = if has_reloads {
+        1
+    } else {
+        capacity
+            .iter()
+            .map(|statistic| {
+                let max_capacity = vehicles
+                    .iter()
+                    .filter_map(|vehicle| vehicle.capacity.get(statistic.dimension))
+                    .max()
+                    .cloned()
+                    .unwrap_or(0) as i64;
+
+                if max_capacity > 0 {
+                    (statistic.demand as f64 / max_capacity as f64).ceil() as usize
+                } else {
+                    1
+                }
+            })
+            .max()
+            .unwrap_or(1)
+    };
+
+    let tour_sizes = vehicles
+        .iter()
+        .map(|vehicle| vehicle.limits.as_ref().and_then(|limits| limits.tour_size))
+        .collect::<Option<Vec<_>>>();
+
+    let by_tour_size = match tour_sizes.and_then(|sizes| sizes.into_iter().max()) {
+        Some(max_size) if max_size > 0 => {
+            let activities = problem.plan.jobs.iter().flat_map(get_job_tasks).count();
+            (activities as f64 / max_size as f64).ceil() as usize
+        }
+        _ => 1,
+    };
+
+    by_capacity.max(by_tour_size).max(1)
+}
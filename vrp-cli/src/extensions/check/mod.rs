@@ -1,6 +1,6 @@
 //! A helper module which contains functionality to run feasibility checks on solution.
 
-use vrp_pragmatic::checker::CheckerContext;
+use vrp_pragmatic::checker::{CheckerContext, CheckerError};
 use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem, PragmaticProblem};
 use vrp_pragmatic::format::solution::deserialize_solution;
 
@@ -9,12 +9,12 @@ use std::process;
 use std::sync::Arc;
 use vrp_pragmatic::format::FormatError;
 
-/// Checks pragmatic solution feasibility.
+/// Checks pragmatic solution feasibility and returns all found violations.
 pub fn check_pragmatic_solution<F: Read>(
     problem_reader: BufReader<F>,
     solution_reader: BufReader<F>,
     matrices_readers: Option<Vec<BufReader<F>>>,
-) -> Result<(), String> {
+) -> Result<(), Vec<CheckerError>> {
     let problem = deserialize_problem(problem_reader).unwrap_or_else(|errs| {
         eprintln!("cannot read problem: '{}'", FormatError::format_many(&errs, ","));
         process::exit(1);
@@ -0,0 +1,312 @@
+//! Diff command helpers: compares two pragmatic solutions of the same problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/diff/diff_test.rs"]
+mod diff_test;
+
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use vrp_pragmatic::format::is_reserved_job_id;
+use vrp_pragmatic::format::solution::{Activity, Solution, Stop};
+use vrp_pragmatic::parse_time_safe;
+
+/// Contains differences between base and new solutions of the same problem.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SolutionDiff {
+    /// Total statistic deltas.
+    pub statistic: StatisticDelta,
+    /// Changes of vehicle shift tours, only tours with changes are listed.
+    pub tours: Vec<TourDiff>,
+    /// Jobs which are unassigned in new solution, but not in base one.
+    pub added_unassigned: Vec<String>,
+    /// Jobs which are unassigned in base solution, but not in new one.
+    pub removed_unassigned: Vec<String>,
+}
+
+/// Contains deltas of total statistic calculated as new value minus base one.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticDelta {
+    /// Total cost delta.
+    pub cost: f64,
+    /// Total distance delta.
+    pub distance: i64,
+    /// Total duration delta.
+    pub duration: i64,
+    /// Driving time delta.
+    pub driving: i64,
+    /// Serving time delta.
+    pub serving: i64,
+    /// Waiting time delta.
+    pub waiting: i64,
+    /// Break time delta.
+    #[serde(rename = "break")]
+    pub break_time: i64,
+    /// Tours amount delta.
+    pub tours: i64,
+    /// Unassigned jobs amount delta.
+    pub unassigned: i64,
+}
+
+/// Contains changes of a tour served by specific vehicle shift.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TourDiff {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
+    /// Jobs added to the tour: moved from other tour or previously unassigned.
+    pub added_jobs: Vec<JobMove>,
+    /// Jobs removed from the tour: moved to other tour or unassigned.
+    pub removed_jobs: Vec<JobMove>,
+    /// True if jobs kept in the tour are served in a different order.
+    pub order_changed: bool,
+    /// Activities which scheduled time is shifted more than threshold.
+    pub time_shifts: Vec<TimeShift>,
+}
+
+/// Specifies a job moved between the tour and other tour or unassigned jobs list.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobMove {
+    /// Job id.
+    pub job_id: String,
+    /// Vehicle id of other tour, omitted if job is unassigned there.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_id: Option<String>,
+    /// Vehicle shift index of other tour, omitted if job is unassigned there.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+}
+
+/// Specifies a time shift of the activity.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimeShift {
+    /// Job id.
+    pub job_id: String,
+    /// Activity type.
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    /// Job tag.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_tag: Option<String>,
+    /// Activity time in base solution in RFC3339 format.
+    pub before: String,
+    /// Activity time in new solution in RFC3339 format.
+    pub after: String,
+    /// Time shift in seconds, positive value means that activity happens later.
+    pub shift: i64,
+}
+
+type TourKey = (String, usize);
+type ActivityKey = (String, String, Option<String>);
+type TourActivities = BTreeMap<TourKey, Vec<(ActivityKey, String)>>;
+
+/// Compares base and new solutions of the same problem. Time shifts of activities are reported
+/// only when they exceed given threshold in seconds.
+pub fn diff_solutions(base: &Solution, actual: &Solution, time_threshold: i64) -> Result<SolutionDiff, String> {
+    let base_tours = get_tour_activities(base);
+    let actual_tours = get_tour_activities(actual);
+    let base_jobs = get_job_tours(&base_tours);
+    let actual_jobs = get_job_tours(&actual_tours);
+
+    let no_activities = vec![];
+    let tours = base_tours
+        .keys()
+        .chain(actual_tours.keys())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|key| {
+            let base_activities = base_tours.get(key).unwrap_or(&no_activities);
+            let actual_activities = actual_tours.get(key).unwrap_or(&no_activities);
+
+            let added_jobs = get_moved_jobs(actual_activities, base_activities, &base_jobs);
+            let removed_jobs = get_moved_jobs(base_activities, actual_activities, &actual_jobs);
+            let order_changed = is_order_changed(base_activities, actual_activities);
+            let time_shifts = get_time_shifts(base_activities, actual_activities, time_threshold)?;
+
+            Ok(TourDiff {
+                vehicle_id: key.0.clone(),
+                shift_index: key.1,
+                added_jobs,
+                removed_jobs,
+                order_changed,
+                time_shifts,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?
+        .into_iter()
+        .filter(|tour| {
+            !tour.added_jobs.is_empty()
+                || !tour.removed_jobs.is_empty()
+                || tour.order_changed
+                || !tour.time_shifts.is_empty()
+        })
+        .collect();
+
+    let base_unassigned = get_unassigned(base);
+    let actual_unassigned = get_unassigned(actual);
+
+    Ok(SolutionDiff {
+        statistic: get_statistic_delta(base, actual),
+        tours,
+        added_unassigned: actual_unassigned.difference(&base_unassigned).cloned().collect(),
+        removed_unassigned: base_unassigned.difference(&actual_unassigned).cloned().collect(),
+    })
+}
+
+fn get_tour_activities(solution: &Solution) -> TourActivities {
+    solution
+        .tours
+        .iter()
+        .map(|tour| {
+            let activities = tour
+                .stops
+                .iter()
+                .flat_map(|stop| {
+                    stop.activities.iter().map(move |activity| {
+                        let key = (activity.job_id.clone(), activity.activity_type.clone(), activity.job_tag.clone());
+                        (key, get_activity_time(stop, activity))
+                    })
+                })
+                .collect();
+
+            ((tour.vehicle_id.clone(), tour.shift_index), activities)
+        })
+        .collect()
+}
+
+fn get_activity_time(stop: &Stop, activity: &Activity) -> String {
+    match (&activity.time, activity.activity_type.as_str()) {
+        (Some(time), _) => time.start.clone(),
+        (None, "departure") => stop.time.departure.clone(),
+        (None, _) => stop.time.arrival.clone(),
+    }
+}
+
+fn is_job_activity(key: &ActivityKey) -> bool {
+    !is_reserved_job_id(key.0.as_str())
+}
+
+fn get_job_ids(activities: &[(ActivityKey, String)]) -> Vec<&String> {
+    let mut visited = HashSet::new();
+
+    activities
+        .iter()
+        .filter(|(key, _)| is_job_activity(key))
+        .map(|(key, _)| &key.0)
+        .filter(|id| visited.insert(*id))
+        .collect()
+}
+
+fn get_job_tours(tours: &TourActivities) -> HashMap<String, TourKey> {
+    tours
+        .iter()
+        .flat_map(|(tour_key, activities)| {
+            get_job_ids(activities).into_iter().map(move |job_id| (job_id.clone(), tour_key.clone()))
+        })
+        .collect()
+}
+
+/// Returns jobs which are present in `source` activities, but not in `other` ones with their tour
+/// in opposite solution.
+fn get_moved_jobs(
+    source: &[(ActivityKey, String)],
+    other: &[(ActivityKey, String)],
+    opposite_jobs: &HashMap<String, TourKey>,
+) -> Vec<JobMove> {
+    let other_ids = get_job_ids(other).into_iter().collect::<HashSet<_>>();
+
+    let mut moved_jobs = get_job_ids(source)
+        .into_iter()
+        .filter(|job_id| !other_ids.contains(job_id))
+        .map(|job_id| {
+            let tour_key = opposite_jobs.get(job_id);
+            JobMove {
+                job_id: job_id.clone(),
+                vehicle_id: tour_key.map(|(vehicle_id, _)| vehicle_id.clone()),
+                shift_index: tour_key.map(|(_, shift_index)| *shift_index),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    moved_jobs.sort_by(|a, b| a.job_id.cmp(&b.job_id));
+
+    moved_jobs
+}
+
+fn is_order_changed(base: &[(ActivityKey, String)], actual: &[(ActivityKey, String)]) -> bool {
+    let base_ids = get_job_ids(base).into_iter().collect::<HashSet<_>>();
+    let actual_ids = get_job_ids(actual).into_iter().collect::<HashSet<_>>();
+
+    let get_kept_sequence = |activities: &[(ActivityKey, String)]| {
+        activities
+            .iter()
+            .filter(|(key, _)| is_job_activity(key) && base_ids.contains(&key.0) && actual_ids.contains(&key.0))
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>()
+    };
+
+    get_kept_sequence(base) != get_kept_sequence(actual)
+}
+
+fn get_time_shifts(
+    base: &[(ActivityKey, String)],
+    actual: &[(ActivityKey, String)],
+    time_threshold: i64,
+) -> Result<Vec<TimeShift>, String> {
+    // NOTE activities with the same key (e.g. multiple breaks) are matched by their occurrence index
+    let base_times = base.iter().fold(HashMap::<_, Vec<_>>::new(), |mut base_times, (key, time)| {
+        base_times.entry(key).or_default().push(time);
+        base_times
+    });
+    let mut occurrences = HashMap::<&ActivityKey, usize>::new();
+    let parse_time =
+        |time: &String| parse_time_safe(time).map_err(|err| format!("cannot parse time '{}': '{}'", time, err));
+
+    actual.iter().try_fold(vec![], |mut time_shifts, (key, after)| {
+        let occurrence = occurrences.entry(key).or_insert(0);
+        let before = base_times.get(key).and_then(|times| times.get(*occurrence));
+        *occurrence += 1;
+
+        if let Some(before) = before {
+            let shift = (parse_time(after)? - parse_time(before)?) as i64;
+
+            if shift.abs() > time_threshold {
+                time_shifts.push(TimeShift {
+                    job_id: key.0.clone(),
+                    activity_type: key.1.clone(),
+                    job_tag: key.2.clone(),
+                    before: (*before).clone(),
+                    after: after.clone(),
+                    shift,
+                });
+            }
+        }
+
+        Ok(time_shifts)
+    })
+}
+
+fn get_unassigned(solution: &Solution) -> BTreeSet<String> {
+    solution.unassigned.iter().flatten().map(|job| job.job_id.clone()).collect()
+}
+
+fn get_statistic_delta(base: &Solution, actual: &Solution) -> StatisticDelta {
+    let (base_stat, actual_stat) = (&base.statistic, &actual.statistic);
+
+    StatisticDelta {
+        cost: actual_stat.cost - base_stat.cost,
+        distance: actual_stat.distance - base_stat.distance,
+        duration: actual_stat.duration - base_stat.duration,
+        driving: actual_stat.times.driving - base_stat.times.driving,
+        serving: actual_stat.times.serving - base_stat.times.serving,
+        waiting: actual_stat.times.waiting - base_stat.times.waiting,
+        break_time: actual_stat.times.break_time - base_stat.times.break_time,
+        tours: actual.tours.len() as i64 - base.tours.len() as i64,
+        unassigned: get_unassigned(actual).len() as i64 - get_unassigned(base).len() as i64,
+    }
+}
@@ -34,7 +34,7 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
         })
         .collect();
 
-    Ok(Fleet { vehicles, profiles })
+    Ok(Fleet { vehicles, profiles, states: None })
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
@@ -14,7 +14,7 @@ mod actual {
     use std::collections::{HashMap, HashSet};
     use std::error::Error;
     use std::io::{BufReader, Read};
-    use std::ops::Deref;
+    use std::str::FromStr;
     use vrp_pragmatic::format::problem::*;
     use vrp_pragmatic::format::{FormatError, Location};
 
@@ -24,10 +24,11 @@ mod actual {
         id: String,
         lat: f64,
         lng: f64,
-        demand: i32,
+        demand: Option<String>,
         duration: usize,
         tw_start: Option<String>,
         tw_end: Option<String>,
+        skills: Option<String>,
     }
 
     #[derive(Debug, Deserialize)]
@@ -36,11 +37,37 @@ mod actual {
         id: String,
         lat: f64,
         lng: f64,
-        capacity: i32,
+        capacity: String,
         tw_start: String,
         tw_end: String,
         amount: usize,
         profile: String,
+        skills: Option<String>,
+        fixed_cost: Option<f64>,
+        distance_cost: Option<f64>,
+        time_cost: Option<f64>,
+        max_distance: Option<f64>,
+        shift_time: Option<f64>,
+        tour_size: Option<usize>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    struct CsvBreak {
+        type_id: String,
+        tw_start: String,
+        tw_end: String,
+        duration: usize,
+        lat: Option<f64>,
+        lng: Option<f64>,
+    }
+
+    /// Specifies job activity type derived from sign of its demand.
+    #[derive(Clone, Copy, PartialEq)]
+    enum TaskType {
+        Pickup,
+        Delivery,
+        Service,
     }
 
     fn read_csv_entries<T, R: Read>(reader: BufReader<R>) -> Result<Vec<T>, Box<dyn Error>>
@@ -57,26 +84,72 @@ mod actual {
         Ok(entries)
     }
 
-    fn parse_tw(start: Option<String>, end: Option<String>) -> Option<Vec<String>> {
-        match (start, end) {
-            (Some(start), Some(end)) => Some(vec![start, end]),
-            _ => None,
+    /// Parses a list of values separated by semicolon, e.g. `2;1` for multi dimensional demand.
+    fn parse_list<T: FromStr>(value: Option<&String>) -> Result<Vec<T>, Box<dyn Error>> {
+        value
+            .map(|value| value.split(';').map(|item| item.trim()).filter(|item| !item.is_empty()).collect::<Vec<_>>())
+            .unwrap_or_default()
+            .into_iter()
+            .map(|item| item.parse::<T>().map_err(|_| format!("cannot parse value: '{}'", item).into()))
+            .collect()
+    }
+
+    fn parse_tw(start: Option<&String>, end: Option<&String>) -> Result<Option<Vec<Vec<String>>>, Box<dyn Error>> {
+        let starts = parse_list::<String>(start)?;
+        let ends = parse_list::<String>(end)?;
+
+        if starts.len() != ends.len() {
+            return Err(format!("time window start and end mismatch: {} vs {}", starts.len(), ends.len()).into());
+        }
+
+        Ok(if starts.is_empty() {
+            None
+        } else {
+            Some(starts.into_iter().zip(ends).map(|(start, end)| vec![start, end]).collect())
+        })
+    }
+
+    fn parse_skills(skills: Option<&String>) -> Result<Option<Vec<String>>, Box<dyn Error>> {
+        let skills = parse_list::<String>(skills)?;
+
+        Ok(if skills.is_empty() { None } else { Some(skills) })
+    }
+
+    fn get_task_type(job: &CsvJob, demand: &[i32]) -> Result<TaskType, Box<dyn Error>> {
+        match (demand.iter().any(|value| *value > 0), demand.iter().any(|value| *value < 0)) {
+            (true, false) => Ok(TaskType::Pickup),
+            (false, true) => Ok(TaskType::Delivery),
+            (false, false) => Ok(TaskType::Service),
+            (true, true) => Err(format!("job '{}' has demand with mixed signs", job.id).into()),
         }
     }
 
     fn read_jobs<R: Read>(reader: BufReader<R>) -> Result<Vec<Job>, Box<dyn Error>> {
-        let get_task = |job: &CsvJob| JobTask {
-            places: vec![JobPlace {
-                location: Location::Coordinate { lat: job.lat, lng: job.lng },
-                duration: job.duration as f64 * 60.,
-                times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
-            }],
-            demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
-            tag: None,
+        let get_task = |job: &CsvJob| -> Result<(TaskType, JobTask), Box<dyn Error>> {
+            let demand = parse_list::<i32>(job.demand.as_ref())?;
+            let task_type = get_task_type(job, demand.as_slice())?;
+
+            Ok((
+                task_type,
+                JobTask {
+                    places: vec![JobPlace {
+                        location: Location::Coordinate { lat: job.lat, lng: job.lng },
+                        duration: job.duration as f64 * 60.,
+                        times: parse_tw(job.tw_start.as_ref(), job.tw_end.as_ref())?,
+                    }],
+                    demand: if task_type != TaskType::Service {
+                        Some(demand.iter().map(|value| value.abs()).collect())
+                    } else {
+                        None
+                    },
+                    tag: None,
+                },
+            ))
         };
 
-        let get_tasks = |jobs: &Vec<&CsvJob>, filter: Box<dyn Fn(&CsvJob) -> bool>| {
-            let tasks = jobs.iter().filter(|j| filter.deref()(j)).map(|job| get_task(job)).collect::<Vec<_>>();
+        let get_tasks = |tasks: &[(TaskType, JobTask)], task_type: TaskType| {
+            let tasks =
+                tasks.iter().filter(|(other, _)| *other == task_type).map(|(_, task)| task.clone()).collect::<Vec<_>>();
             if tasks.is_empty() {
                 None
             } else {
@@ -84,38 +157,93 @@ mod actual {
             }
         };
 
-        let jobs = read_csv_entries::<CsvJob, _>(reader)?
+        let get_job_skills = |jobs: &[&CsvJob]| -> Result<Option<JobSkills>, Box<dyn Error>> {
+            let skills = jobs.iter().try_fold(Vec::<String>::new(), |mut acc, job| {
+                parse_list::<String>(job.skills.as_ref())?.into_iter().for_each(|skill| {
+                    if !acc.contains(&skill) {
+                        acc.push(skill);
+                    }
+                });
+
+                Ok::<_, Box<dyn Error>>(acc)
+            })?;
+
+            Ok(if skills.is_empty() {
+                None
+            } else {
+                Some(JobSkills { all_of: Some(skills), one_of: None, none_of: None })
+            })
+        };
+
+        let entries = read_csv_entries::<CsvJob, _>(reader)?;
+
+        entries
             .iter()
             .fold(HashMap::new(), |mut acc, job| {
                 acc.entry(&job.id).or_insert_with(Vec::new).push(job);
                 acc
             })
             .into_iter()
-            .map(|(job_id, tasks)| Job {
-                id: job_id.clone(),
-                pickups: get_tasks(&tasks, Box::new(|j| j.demand > 0)),
-                deliveries: get_tasks(&tasks, Box::new(|j| j.demand < 0)),
-                replacements: None,
-                services: get_tasks(&tasks, Box::new(|j| j.demand == 0)),
-                priority: None,
-                skills: None,
+            .map(|(job_id, jobs)| {
+                let tasks = jobs.iter().map(|job| get_task(job)).collect::<Result<Vec<_>, _>>()?;
+
+                Ok(Job {
+                    id: job_id.clone(),
+                    pickups: get_tasks(tasks.as_slice(), TaskType::Pickup),
+                    deliveries: get_tasks(tasks.as_slice(), TaskType::Delivery),
+                    replacements: None,
+                    services: get_tasks(tasks.as_slice(), TaskType::Service),
+                    priority: None,
+                    skills: get_job_skills(jobs.as_slice())?,
+                })
             })
-            .collect();
+            .collect()
+    }
 
-        Ok(jobs)
+    fn read_breaks<R: Read>(reader: BufReader<R>) -> Result<HashMap<String, Vec<VehicleBreak>>, Box<dyn Error>> {
+        Ok(read_csv_entries::<CsvBreak, _>(reader)?.into_iter().fold(HashMap::new(), |mut acc, vehicle_break| {
+            let locations = match (vehicle_break.lat, vehicle_break.lng) {
+                (Some(lat), Some(lng)) => Some(vec![Location::Coordinate { lat, lng }]),
+                _ => None,
+            };
+
+            acc.entry(vehicle_break.type_id).or_insert_with(Vec::new).push(VehicleBreak {
+                time: VehicleBreakTime::TimeWindow(vec![vehicle_break.tw_start, vehicle_break.tw_end]),
+                duration: vehicle_break.duration as f64 * 60.,
+                locations,
+            });
+
+            acc
+        }))
     }
 
-    fn read_vehicles<R: Read>(reader: BufReader<R>) -> Result<Vec<VehicleType>, Box<dyn Error>> {
+    fn read_vehicles<R: Read>(
+        reader: BufReader<R>,
+        mut breaks: HashMap<String, Vec<VehicleBreak>>,
+    ) -> Result<Vec<VehicleType>, Box<dyn Error>> {
         let vehicles = read_csv_entries::<CsvVehicle, _>(reader)?
             .into_iter()
             .map(|vehicle| {
                 let depot_location = Location::Coordinate { lat: vehicle.lat, lng: vehicle.lng };
+                let limits = match (vehicle.max_distance, vehicle.shift_time, vehicle.tour_size) {
+                    (None, None, None) => None,
+                    (max_distance, shift_time, tour_size) => Some(VehicleLimits {
+                        max_distance,
+                        shift_time: shift_time.map(|shift_time| shift_time * 60.),
+                        tour_size,
+                        allowed_areas: None,
+                    }),
+                };
 
-                VehicleType {
+                Ok(VehicleType {
                     type_id: vehicle.id.clone(),
-                    vehicle_ids: (1..vehicle.amount).map(|seq| format!("{}_{}", vehicle.profile, seq)).collect(),
+                    vehicle_ids: (1..=vehicle.amount).map(|seq| format!("{}_{}", vehicle.id, seq)).collect(),
                     profile: vehicle.profile,
-                    costs: VehicleCosts { fixed: Some(25.), distance: 0.0002, time: 0.005 },
+                    costs: VehicleCosts {
+                        fixed: Some(vehicle.fixed_cost.unwrap_or(25.)),
+                        distance: vehicle.distance_cost.unwrap_or(0.0002),
+                        time: vehicle.time_cost.unwrap_or(0.005),
+                    },
                     shifts: vec![VehicleShift {
                         start: ShiftStart {
                             earliest: vehicle.tw_start,
@@ -124,15 +252,19 @@ mod actual {
                         },
                         end: Some(ShiftEnd { earliest: None, latest: vehicle.tw_end, location: depot_location }),
                         dispatch: None,
-                        breaks: None,
+                        breaks: breaks.remove(&vehicle.id),
                         reloads: None,
                     }],
-                    capacity: vec![vehicle.capacity],
-                    skills: None,
-                    limits: None,
-                }
+                    capacity: parse_list::<i32>(Some(&vehicle.capacity))?,
+                    skills: parse_skills(vehicle.skills.as_ref())?,
+                    limits,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+        if let Some(type_id) = breaks.keys().next() {
+            return Err(format!("unknown vehicle type id in breaks: '{}'", type_id).into());
+        }
 
         Ok(vehicles)
     }
@@ -146,13 +278,17 @@ mod actual {
         )
     }
 
-    /// Reads problem from csv format.
-    pub fn read_csv_problem<R1: Read, R2: Read>(
+    /// Reads problem from csv format. Vehicle breaks are read from optional third csv.
+    pub fn read_csv_problem<R1: Read, R2: Read, R3: Read>(
         jobs_reader: BufReader<R1>,
         vehicles_reader: BufReader<R2>,
+        breaks_reader: Option<BufReader<R3>>,
     ) -> Result<Problem, FormatError> {
         let jobs = read_jobs(jobs_reader).map_err(|err| create_format_error("jobs", err))?;
-        let vehicles = read_vehicles(vehicles_reader).map_err(|err| create_format_error("vehicles", err))?;
+        let breaks = breaks_reader
+            .map_or_else(|| Ok(HashMap::new()), read_breaks)
+            .map_err(|err| create_format_error("breaks", err))?;
+        let vehicles = read_vehicles(vehicles_reader, breaks).map_err(|err| create_format_error("vehicles", err))?;
         let profiles = vehicles.iter().map(|v| v.profile.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
@@ -163,6 +299,7 @@ mod actual {
                     .into_iter()
                     .map(|p| Profile { name: p.clone(), profile_type: p, speed: None })
                     .collect(),
+                states: None,
             },
             objectives: None,
         })
@@ -176,9 +313,10 @@ mod actual {
     use vrp_pragmatic::format::FormatError;
 
     /// A stub method for reading problem from csv format.
-    pub fn read_csv_problem<R1: Read, R2: Read>(
+    pub fn read_csv_problem<R1: Read, R2: Read, R3: Read>(
         _jobs_reader: BufReader<R1>,
         _vehicles_reader: BufReader<R2>,
+        _breaks_reader: Option<BufReader<R3>>,
     ) -> Result<Problem, FormatError> {
         unreachable!("csv-format feature is not included")
     }
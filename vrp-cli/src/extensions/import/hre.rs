@@ -362,6 +362,7 @@ mod deserialize {
                 .iter()
                 .map(|p| Profile { name: p.name.clone(), profile_type: p.profile_type.clone(), speed: None })
                 .collect(),
+            states: None,
         })
     }
 
@@ -12,12 +12,17 @@ use vrp_pragmatic::format::problem::Problem;
 /// Imports solution from specific format into pragmatic.
 pub fn import_problem<R: Read>(input_format: &str, readers: Option<Vec<BufReader<R>>>) -> Result<Problem, String> {
     match (input_format, readers) {
-        ("csv", Some(mut readers)) if readers.len() == 2 => {
-            let jobs = readers.swap_remove(0);
-            let vehicles = readers.swap_remove(0);
-            read_csv_problem(jobs, vehicles).map_err(|err| format!("cannot read csv: {}", err))
+        ("csv", Some(readers)) if readers.len() == 2 || readers.len() == 3 => {
+            let mut readers = readers.into_iter();
+            let jobs = readers.next().unwrap();
+            let vehicles = readers.next().unwrap();
+            let breaks = readers.next();
+            read_csv_problem(jobs, vehicles, breaks).map_err(|err| format!("cannot read csv: {}", err))
+        }
+        ("csv", _) => {
+            Err("csv format expects two files with jobs and vehicles and optional file with breaks as an input"
+                .to_string())
         }
-        ("csv", _) => Err("csv format expects two files with jobs and vehicles as an input".to_string()),
         ("hre", Some(mut readers)) if readers.len() == 1 => {
             let problem = readers.swap_remove(0);
             deserialize_hre_problem(problem).map_err(|err| format!("cannot read problem from hre json: '{}'", err))
@@ -0,0 +1,164 @@
+//! Lock command helpers: creates a new problem which keeps assignments of existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/lock/lock_test.rs"]
+mod lock_test;
+
+use std::collections::HashSet;
+use vrp_pragmatic::format::is_reserved_job_id;
+use vrp_pragmatic::format::problem::{Problem, Relation, RelationType};
+use vrp_pragmatic::format::solution::{Activity, Solution, Stop, Tour};
+use vrp_pragmatic::parse_time_safe;
+
+/// Specifies which part of the solution should be locked.
+#[derive(Clone, Debug)]
+pub struct LockOptions {
+    /// A type of relations to be created.
+    pub relation_type: RelationType,
+    /// Ids of vehicles which tours should be locked. If not specified, all tours are considered.
+    pub vehicle_ids: Option<Vec<String>>,
+    /// A time range in RFC3339 format: only activities which start within it are locked.
+    pub time_range: Option<(String, String)>,
+}
+
+/// Creates a new problem from the original one and its solution: jobs served in selected tours
+/// are locked to their vehicles using relations of specified type. Reserved activities (departure,
+/// break, reload, dispatch and arrival) are kept in `strict` and `sequence` relations. Jobs which
+/// are already used in relations of the original problem are not locked again.
+pub fn create_locked_problem(problem: Problem, solution: &Solution, options: &LockOptions) -> Result<Problem, String> {
+    let time_range = options
+        .time_range
+        .as_ref()
+        .map(|(start, end)| Ok::<_, String>((parse_time(start)?, parse_time(end)?)))
+        .transpose()?;
+
+    if let Some((start, end)) = time_range {
+        if start > end {
+            return Err("time range start is later than its end".to_string());
+        }
+    }
+
+    let mut problem = problem;
+    let mut relations = problem.plan.relations.take().unwrap_or_default();
+    let related_jobs = relations.iter().flat_map(|relation| relation.jobs.iter().cloned()).collect::<HashSet<_>>();
+
+    let tours = solution.tours.iter().filter(|tour| match &options.vehicle_ids {
+        Some(vehicle_ids) => vehicle_ids.contains(&tour.vehicle_id),
+        None => true,
+    });
+
+    let new_relations = tours
+        .map(|tour| {
+            validate_tour(&problem, tour)?;
+            create_relation(tour, options.relation_type.clone(), time_range, &related_jobs)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    relations.extend(new_relations.into_iter().flatten());
+
+    problem.plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    Ok(problem)
+}
+
+fn parse_time(time: &str) -> Result<f64, String> {
+    parse_time_safe(time).map_err(|err| format!("cannot parse time '{}': {}", time, err))
+}
+
+/// Checks that tour's vehicle and jobs are present in the problem.
+fn validate_tour(problem: &Problem, tour: &Tour) -> Result<(), String> {
+    let vehicle = problem
+        .fleet
+        .vehicles
+        .iter()
+        .find(|vehicle| vehicle.vehicle_ids.contains(&tour.vehicle_id))
+        .ok_or_else(|| format!("cannot find vehicle with id '{}' in the problem", tour.vehicle_id))?;
+
+    if tour.shift_index >= vehicle.shifts.len() {
+        return Err(format!("vehicle '{}' has no shift with index {}", tour.vehicle_id, tour.shift_index));
+    }
+
+    get_activities(tour).filter(|(_, activity)| is_job_activity(activity)).try_for_each(|(_, activity)| {
+        if problem.plan.jobs.iter().any(|job| job.id == activity.job_id) {
+            Ok(())
+        } else {
+            Err(format!("cannot find job with id '{}' in the problem", activity.job_id))
+        }
+    })
+}
+
+/// Creates a relation of given type for activities of the tour which start within time range.
+/// Jobs from `related_jobs` are skipped. If skipped activities leave a gap inside of `strict`
+/// relation, `sequence` relation is created instead.
+pub(crate) fn create_relation(
+    tour: &Tour,
+    relation_type: RelationType,
+    time_range: Option<(f64, f64)>,
+    related_jobs: &HashSet<String>,
+) -> Result<Option<Relation>, String> {
+    let is_in_range = |stop: &Stop, activity: &Activity| -> Result<bool, String> {
+        time_range.map_or(Ok(true), |(start, end)| {
+            let time = parse_time(activity.time.as_ref().map_or(&stop.time.arrival, |time| &time.start))?;
+            Ok(time >= start && time <= end)
+        })
+    };
+
+    let activities = get_activities(tour)
+        .map(|(stop, activity)| is_in_range(stop, activity).map(|is_locked| (activity, is_locked)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // NOTE a job with more than one activity (e.g. pickup and delivery) is locked only if all its activities are
+    let partial_jobs = activities
+        .iter()
+        .filter(|(activity, is_locked)| !is_locked && is_job_activity(activity))
+        .map(|(activity, _)| activity.job_id.clone())
+        .collect::<HashSet<_>>();
+
+    let (indices, locked): (Vec<_>, Vec<_>) = activities
+        .into_iter()
+        .enumerate()
+        .filter(|(_, (activity, is_locked))| {
+            let is_related = is_job_activity(activity) && related_jobs.contains(&activity.job_id);
+            *is_locked && !is_related && !partial_jobs.contains(&activity.job_id)
+        })
+        .map(|(idx, (activity, _))| (idx, activity))
+        .unzip();
+
+    if !locked.iter().any(|activity| is_job_activity(activity)) {
+        return Ok(None);
+    }
+
+    // NOTE strict relation cannot have gaps: downgrade it to sequence when some activity in the middle is skipped
+    let has_gaps = indices.windows(2).any(|pair| pair[1] != pair[0] + 1);
+    let relation_type = match relation_type {
+        RelationType::Strict if has_gaps => RelationType::Sequence,
+        relation_type => relation_type,
+    };
+
+    // NOTE relation should contain job id per each job activity, e.g. twice for pickup and delivery job
+    let jobs = match relation_type {
+        RelationType::Any => locked
+            .into_iter()
+            .filter(|activity| is_job_activity(activity))
+            .map(|activity| activity.job_id.clone())
+            .collect(),
+        RelationType::Sequence | RelationType::Strict => {
+            locked.into_iter().map(|activity| activity.job_id.clone()).collect()
+        }
+    };
+
+    Ok(Some(Relation {
+        type_field: relation_type,
+        jobs,
+        vehicle_id: tour.vehicle_id.clone(),
+        shift_index: Some(tour.shift_index),
+    }))
+}
+
+fn get_activities<'a>(tour: &'a Tour) -> impl Iterator<Item = (&'a Stop, &'a Activity)> + 'a {
+    tour.stops.iter().flat_map(|stop| stop.activities.iter().map(move |activity| (stop, activity)))
+}
+
+fn is_job_activity(activity: &Activity) -> bool {
+    !is_reserved_job_id(activity.job_id.as_str())
+}
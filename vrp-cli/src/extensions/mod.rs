@@ -1,9 +1,16 @@
 //! Module provides various helper functionality.
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod analyze;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod diff;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod serve;
 
 pub mod import;
+pub mod lock;
 pub mod solve;
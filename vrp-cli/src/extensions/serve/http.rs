@@ -0,0 +1,138 @@
+//! A minimalistic HTTP/1.1 protocol implementation sufficient to serve json requests.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/http_test.rs"]
+mod http_test;
+
+use std::io::{BufRead, Read, Write};
+
+/// A max size of request body in bytes.
+const MAX_BODY_SIZE: usize = 32 * 1024 * 1024;
+
+/// A max size of memory allocated for request body in advance: the rest is allocated while
+/// the body is read, so a client cannot reserve memory by sending big content length only.
+const BODY_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A max size of request line or header line in bytes.
+const MAX_LINE_SIZE: u64 = 8 * 1024;
+
+/// A max amount of header lines in request.
+const MAX_HEADERS: usize = 64;
+
+/// Represents HTTP request.
+pub struct Request {
+    /// Request method, e.g. `GET`.
+    pub method: String,
+    /// Request path without query string.
+    pub path: String,
+    /// Request body.
+    pub body: Vec<u8>,
+}
+
+/// Represents HTTP response with json body.
+pub struct Response {
+    /// Status code.
+    pub status: u16,
+    /// Response body.
+    pub body: String,
+}
+
+impl Response {
+    /// Creates a new response.
+    pub fn new(status: u16, body: String) -> Self {
+        Self { status, body }
+    }
+
+    /// Creates a new response with error message.
+    pub fn error(status: u16, message: &str) -> Self {
+        Self::new(status, serde_json::json!({ "error": message }).to_string())
+    }
+}
+
+/// Reads HTTP request from the reader.
+pub fn read_request<R: BufRead>(reader: &mut R) -> Result<Request, String> {
+    let request_line = read_line(reader)?;
+    let mut parts = request_line.split_whitespace();
+
+    let (method, target) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(method), Some(target), Some(version)) if version.starts_with("HTTP/") => (method, target),
+        _ => return Err(format!("invalid request line: '{}'", request_line)),
+    };
+
+    let mut content_length = 0;
+    let mut headers = 0;
+    loop {
+        let line = read_line(reader)?;
+        if line.is_empty() {
+            break;
+        }
+
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Err(format!("too many request headers, max is {}", MAX_HEADERS));
+        }
+
+        if let Some(idx) = line.find(':') {
+            let (name, value) = (&line[..idx], &line[idx + 1..]);
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length =
+                    value.trim().parse::<usize>().map_err(|err| format!("invalid content length: {}", err))?;
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_SIZE {
+        return Err(format!("request body is too large: {} bytes", content_length));
+    }
+
+    let mut body = Vec::with_capacity(content_length.min(BODY_CHUNK_SIZE));
+    reader
+        .take(content_length as u64)
+        .read_to_end(&mut body)
+        .map_err(|err| format!("cannot read request body: {}", err))?;
+
+    if body.len() != content_length {
+        return Err(format!("unexpected end of request body: {} of {} bytes", body.len(), content_length));
+    }
+
+    Ok(Request { method: method.to_uppercase(), path: target.split('?').next().unwrap_or_default().to_string(), body })
+}
+
+/// Writes HTTP response to the writer.
+pub fn write_response<W: Write>(writer: &mut W, response: &Response) -> std::io::Result<()> {
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        get_reason_phrase(response.status),
+        response.body.len()
+    )?;
+    writer.write_all(response.body.as_bytes())?;
+    writer.flush()
+}
+
+fn read_line<R: BufRead>(reader: &mut R) -> Result<String, String> {
+    let mut line = String::new();
+    reader.take(MAX_LINE_SIZE).read_line(&mut line).map_err(|err| format!("cannot read request: {}", err))?;
+
+    if !line.ends_with('\n') && line.len() as u64 == MAX_LINE_SIZE {
+        return Err(format!("request line is too long, max size is {} bytes", MAX_LINE_SIZE));
+    }
+
+    Ok(line.trim_end_matches(&['\r', '\n'][..]).to_string())
+}
+
+fn get_reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        404 => "Not Found",
+        409 => "Conflict",
+        410 => "Gone",
+        422 => "Unprocessable Entity",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Unknown",
+    }
+}
@@ -0,0 +1,170 @@
+//! Contains a job pool which runs submitted tasks using fixed amount of worker threads.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/jobs_test.rs"]
+mod jobs_test;
+
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+use vrp_core::utils::CancellationQuota;
+
+/// A task which produces serialized result or error. A task should stop its work when
+/// the passed quota is reached.
+pub type Task = Box<dyn FnOnce(CancellationQuota) -> Result<String, String> + Send>;
+
+/// Specifies job status.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    /// Job is waiting in the queue.
+    Queued,
+    /// Job is processed by a worker.
+    Running,
+    /// Job is finished successfully.
+    Finished,
+    /// Job is failed.
+    Failed,
+    /// Job is cancelled.
+    Cancelled,
+}
+
+struct JobEntry {
+    status: JobStatus,
+    result: Option<Result<String, String>>,
+    quota: CancellationQuota,
+    /// A time when the job was processed or cancelled.
+    completed: Option<Instant>,
+}
+
+type JobStore = Arc<Mutex<HashMap<usize, JobEntry>>>;
+
+type JobMessage = (usize, Task, CancellationQuota);
+
+/// A job pool with bounded queue and fixed amount of workers. Processed and cancelled jobs are
+/// kept in the pool for a given time to live, then they are removed.
+pub struct JobPool {
+    sender: SyncSender<JobMessage>,
+    jobs: JobStore,
+    next_id: AtomicUsize,
+    ttl: Duration,
+}
+
+impl JobPool {
+    /// Creates a new instance of `JobPool` and starts its workers.
+    pub fn new(workers: usize, queue_size: usize, ttl: Duration) -> Self {
+        let (sender, receiver) = sync_channel::<JobMessage>(queue_size);
+        let receiver = Arc::new(Mutex::new(receiver));
+        let jobs = JobStore::default();
+
+        (0..workers.max(1)).for_each(|_| {
+            let receiver = receiver.clone();
+            let jobs = jobs.clone();
+            thread::spawn(move || run_worker(receiver, jobs));
+        });
+
+        Self { sender, jobs, next_id: AtomicUsize::new(1), ttl }
+    }
+
+    /// Submits a new task and returns its job id. Returns error if the queue is full.
+    pub fn submit(&self, task: Task) -> Result<usize, String> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let quota = CancellationQuota::default();
+        self.lock_jobs()
+            .insert(id, JobEntry { status: JobStatus::Queued, result: None, quota: quota.clone(), completed: None });
+
+        self.sender.try_send((id, task, quota)).map(|_| id).map_err(|err| {
+            self.lock_jobs().remove(&id);
+            match err {
+                TrySendError::Full(_) => "job queue is full".to_string(),
+                TrySendError::Disconnected(_) => "job pool is stopped".to_string(),
+            }
+        })
+    }
+
+    /// Returns job status.
+    pub fn status(&self, id: usize) -> Option<JobStatus> {
+        self.lock_jobs().get(&id).map(|entry| entry.status)
+    }
+
+    /// Returns job status and its result, if the job is processed.
+    pub fn result(&self, id: usize) -> Option<(JobStatus, Option<Result<String, String>>)> {
+        self.lock_jobs().get(&id).map(|entry| (entry.status, entry.result.clone()))
+    }
+
+    /// Cancels queued or running job: running job is requested to stop and its result is discarded.
+    /// A job which is already processed is removed from the pool. Returns job status before the call.
+    pub fn cancel(&self, id: usize) -> Option<JobStatus> {
+        let mut jobs = self.lock_jobs();
+        let entry = jobs.get_mut(&id)?;
+        let status = entry.status;
+
+        match status {
+            JobStatus::Queued | JobStatus::Running => {
+                entry.status = JobStatus::Cancelled;
+                entry.result = None;
+                entry.quota.cancel();
+                entry.completed = Some(Instant::now());
+            }
+            _ => {
+                jobs.remove(&id);
+            }
+        }
+
+        Some(status)
+    }
+
+    /// Locks job store and removes jobs which are completed longer than time to live ago.
+    fn lock_jobs(&self) -> MutexGuard<'_, HashMap<usize, JobEntry>> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let ttl = self.ttl;
+        jobs.retain(|_, entry| match entry.completed {
+            Some(completed) => completed.elapsed() < ttl,
+            None => true,
+        });
+
+        jobs
+    }
+}
+
+fn run_worker(receiver: Arc<Mutex<Receiver<JobMessage>>>, jobs: JobStore) {
+    loop {
+        let message = receiver.lock().unwrap().recv();
+        let (id, task, quota) = match message {
+            Ok(message) => message,
+            Err(_) => break,
+        };
+
+        if !try_update_status(&jobs, id, JobStatus::Queued, JobStatus::Running, None) {
+            continue;
+        }
+
+        let result = task(quota);
+        let status = if result.is_ok() { JobStatus::Finished } else { JobStatus::Failed };
+
+        try_update_status(&jobs, id, JobStatus::Running, status, Some(result));
+    }
+}
+
+/// Updates job status only if it has expected value, e.g. job was not cancelled meanwhile.
+fn try_update_status(
+    jobs: &JobStore,
+    id: usize,
+    expected: JobStatus,
+    status: JobStatus,
+    result: Option<Result<String, String>>,
+) -> bool {
+    match jobs.lock().unwrap().get_mut(&id) {
+        Some(entry) if entry.status == expected => {
+            entry.status = status;
+            entry.result = result;
+            entry.completed = if entry.result.is_some() { Some(Instant::now()) } else { None };
+            true
+        }
+        _ => false,
+    }
+}
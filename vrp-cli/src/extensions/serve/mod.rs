@@ -0,0 +1,281 @@
+//! Serve command helpers: exposes solver functionality via simple HTTP API.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/serve/serve_test.rs"]
+mod serve_test;
+
+mod http;
+pub use self::http::{read_request, write_response, Request, Response};
+
+mod jobs;
+pub use self::jobs::{JobPool, JobStatus, Task};
+
+use crate::extensions::import::import_problem;
+use crate::extensions::solve::config::Config;
+use crate::{get_locations_serialized, get_solution_serialized_with_quota};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::io::{BufReader, BufWriter};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{sync_channel, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+use vrp_pragmatic::checker::{CheckerContext, CheckerError};
+use vrp_pragmatic::format::problem::{serialize_problem, Matrix, PragmaticProblem, Problem};
+use vrp_pragmatic::format::solution::Solution;
+use vrp_pragmatic::format::FormatError;
+
+/// Specifies server settings.
+#[derive(Clone, Debug)]
+pub struct ServerConfig {
+    /// Amount of workers which solve problems in parallel.
+    pub workers: usize,
+    /// Max amount of solve jobs waiting in the queue.
+    pub queue_size: usize,
+    /// Max amount of connections handled in parallel.
+    pub connections: usize,
+    /// A read and write timeout of connection socket.
+    pub timeout: Duration,
+    /// A time to keep processed or cancelled solve jobs with their results.
+    pub job_ttl: Duration,
+}
+
+/// A solve request body.
+#[derive(Deserialize)]
+struct SolveRequest {
+    problem: Problem,
+    matrices: Option<Vec<Matrix>>,
+    config: Option<Config>,
+}
+
+/// A check request body.
+#[derive(Deserialize)]
+struct CheckRequest {
+    problem: Problem,
+    solution: Solution,
+    matrices: Option<Vec<Matrix>>,
+}
+
+/// An import request body.
+#[derive(Deserialize)]
+struct ImportRequest {
+    inputs: Vec<String>,
+}
+
+/// A service which handles HTTP requests. Solve requests are processed asynchronously by
+/// the job pool, all other requests are processed immediately.
+pub struct Service {
+    pool: JobPool,
+}
+
+impl Service {
+    /// Creates a new instance of `Service`.
+    pub fn new(config: &ServerConfig) -> Self {
+        Self { pool: JobPool::new(config.workers, config.queue_size, config.job_ttl) }
+    }
+
+    /// Handles request and returns response.
+    pub fn handle(&self, request: &Request) -> Response {
+        let segments = request.path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("POST", ["solve"]) => self.submit_solve(request.body.as_slice()),
+            ("GET", ["jobs", id]) => with_job_id(id, |id| self.get_status(id)),
+            ("GET", ["jobs", id, "result"]) => with_job_id(id, |id| self.get_result(id)),
+            ("DELETE", ["jobs", id]) => with_job_id(id, |id| self.cancel(id)),
+            ("POST", ["locations"]) => get_locations(request.body.as_slice()),
+            ("POST", ["import", format]) => import(format, request.body.as_slice()),
+            ("POST", ["check"]) => check(request.body.as_slice()),
+            _ => Response::error(404, format!("unknown route: {} {}", request.method, request.path).as_str()),
+        }
+    }
+
+    fn submit_solve(&self, body: &[u8]) -> Response {
+        let request: SolveRequest = match parse_body(body) {
+            Ok(request) => request,
+            Err(response) => return response,
+        };
+
+        let problem = match read_core_problem(request.problem, request.matrices) {
+            Ok(problem) => Arc::new(problem),
+            Err(response) => return response,
+        };
+        let config = request.config.unwrap_or_default();
+
+        match self
+            .pool
+            .submit(Box::new(move |quota| get_solution_serialized_with_quota(problem, config, Some(Arc::new(quota)))))
+        {
+            Ok(id) => create_status_response(202, id, JobStatus::Queued),
+            Err(err) => Response::error(503, err.as_str()),
+        }
+    }
+
+    fn get_status(&self, id: usize) -> Response {
+        match self.pool.status(id) {
+            Some(status) => create_status_response(200, id, status),
+            None => create_unknown_job_response(id),
+        }
+    }
+
+    fn get_result(&self, id: usize) -> Response {
+        match self.pool.result(id) {
+            Some((_, Some(Ok(solution)))) => Response::new(200, solution),
+            Some((_, Some(Err(err)))) => Response::error(422, err.as_str()),
+            Some((JobStatus::Cancelled, None)) => create_status_response(410, id, JobStatus::Cancelled),
+            Some((status, None)) => create_status_response(409, id, status),
+            None => create_unknown_job_response(id),
+        }
+    }
+
+    fn cancel(&self, id: usize) -> Response {
+        match self.pool.cancel(id) {
+            Some(JobStatus::Queued) | Some(JobStatus::Running) => create_status_response(200, id, JobStatus::Cancelled),
+            Some(status) => create_status_response(200, id, status),
+            None => create_unknown_job_response(id),
+        }
+    }
+}
+
+/// Starts the server on given address and processes incoming connections until the process is stopped.
+pub fn run_server(address: &str, config: &ServerConfig) -> Result<(), String> {
+    let listener = TcpListener::bind(address).map_err(|err| format!("cannot bind to '{}': {}", address, err))?;
+
+    serve(listener, Arc::new(Service::new(config)), config)
+}
+
+/// Processes incoming connections of the listener using fixed amount of handler threads. When all
+/// handlers are busy and their queue is full, a new connection is rejected.
+pub fn serve(listener: TcpListener, service: Arc<Service>, config: &ServerConfig) -> Result<(), String> {
+    let connections = config.connections.max(1);
+    let (sender, receiver) = sync_channel::<TcpStream>(connections);
+    let receiver = Arc::new(Mutex::new(receiver));
+
+    (0..connections).for_each(|_| {
+        let receiver = receiver.clone();
+        let service = service.clone();
+        thread::spawn(move || loop {
+            let stream = receiver.lock().unwrap().recv();
+            match stream {
+                Ok(stream) => handle_connection(stream, service.as_ref()),
+                Err(_) => break,
+            }
+        });
+    });
+
+    listener.incoming().try_for_each(|stream| {
+        let stream = stream.map_err(|err| format!("cannot accept connection: {}", err))?;
+        set_timeouts(&stream, config.timeout);
+
+        match sender.try_send(stream) {
+            Ok(_) => Ok(()),
+            Err(TrySendError::Full(stream)) => {
+                send_response(stream, &Response::error(503, "too many connections"));
+                Ok(())
+            }
+            Err(TrySendError::Disconnected(_)) => Err("connection handlers are stopped".to_string()),
+        }
+    })
+}
+
+fn set_timeouts(stream: &TcpStream, timeout: Duration) {
+    if let Err(err) = stream.set_read_timeout(Some(timeout)).and_then(|_| stream.set_write_timeout(Some(timeout))) {
+        eprintln!("cannot set connection timeout: {}", err);
+    }
+}
+
+fn handle_connection(stream: TcpStream, service: &Service) {
+    let response = match stream.try_clone() {
+        Ok(reader) => match read_request(&mut BufReader::new(reader)) {
+            Ok(request) => service.handle(&request),
+            Err(err) => Response::error(400, err.as_str()),
+        },
+        Err(err) => Response::error(500, err.to_string().as_str()),
+    };
+
+    send_response(stream, &response);
+}
+
+fn send_response(stream: TcpStream, response: &Response) {
+    let mut writer = BufWriter::new(stream);
+    if let Err(err) = write_response(&mut writer, response) {
+        eprintln!("cannot write response: {}", err);
+    }
+}
+
+fn get_locations(body: &[u8]) -> Response {
+    match parse_body::<Problem>(body) {
+        Ok(problem) => get_locations_serialized(&problem)
+            .map(|locations| Response::new(200, locations))
+            .unwrap_or_else(|err| Response::error(400, err.as_str())),
+        Err(response) => response,
+    }
+}
+
+fn import(format: &str, body: &[u8]) -> Response {
+    let request: ImportRequest = match parse_body(body) {
+        Ok(request) => request,
+        Err(response) => return response,
+    };
+
+    let readers = request.inputs.iter().map(|input| BufReader::new(input.as_bytes())).collect();
+
+    match import_problem(format, Some(readers)) {
+        Ok(problem) => {
+            let mut buffer = String::new();
+            let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+            match serialize_problem(writer, &problem) {
+                Ok(_) => Response::new(200, buffer),
+                Err(err) => Response::error(500, err.to_string().as_str()),
+            }
+        }
+        Err(err) => Response::error(400, err.as_str()),
+    }
+}
+
+fn check(body: &[u8]) -> Response {
+    let request: CheckRequest = match parse_body(body) {
+        Ok(request) => request,
+        Err(response) => return response,
+    };
+
+    let core_problem = match read_core_problem(request.problem.clone(), request.matrices.clone()) {
+        Ok(problem) => Arc::new(problem),
+        Err(response) => return response,
+    };
+
+    let result = CheckerContext::new(core_problem, request.problem, request.matrices, request.solution).check();
+
+    match CheckerError::to_json(result.err().unwrap_or_default().as_slice()) {
+        Ok(errors) => Response::new(200, errors),
+        Err(err) => Response::error(500, err.as_str()),
+    }
+}
+
+fn read_core_problem(problem: Problem, matrices: Option<Vec<Matrix>>) -> Result<vrp_core::models::Problem, Response> {
+    match matrices {
+        Some(matrices) if !matrices.is_empty() => (problem, matrices).read_pragmatic(),
+        _ => problem.read_pragmatic(),
+    }
+    .map_err(|errors| Response::new(400, FormatError::format_many_to_json(&errors)))
+}
+
+fn parse_body<T: DeserializeOwned>(body: &[u8]) -> Result<T, Response> {
+    serde_json::from_slice(body).map_err(|err| Response::error(400, format!("cannot parse request: {}", err).as_str()))
+}
+
+fn with_job_id<F: FnOnce(usize) -> Response>(id: &str, func: F) -> Response {
+    match id.parse::<usize>() {
+        Ok(id) => func(id),
+        Err(_) => Response::error(404, format!("invalid job id: '{}'", id).as_str()),
+    }
+}
+
+fn create_status_response(code: u16, id: usize, status: JobStatus) -> Response {
+    Response::new(code, serde_json::json!({ "id": id, "status": status }).to_string())
+}
+
+fn create_unknown_job_response(id: usize) -> Response {
+    Response::error(404, format!("unknown job id: {}", id).as_str())
+}
@@ -9,17 +9,21 @@ mod config_test;
 extern crate serde_json;
 
 use serde::Deserialize;
+use std::fmt::Display;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::models::common::SingleDimLoad;
 use vrp_core::models::Problem;
 use vrp_core::solver::mutation::*;
 use vrp_core::solver::population::*;
-use vrp_core::solver::{Builder, Telemetry, TelemetryMode};
-use vrp_core::utils::{get_cpus, DefaultRandom};
+use vrp_core::solver::post_processing::SetPartitioning;
+use vrp_core::solver::{Builder, PopulationFactory, RunIslands, Telemetry, TelemetryMode};
+use vrp_core::utils::{get_cpus, DefaultRandom, Random};
+use vrp_pragmatic::format::FormatError;
 
 /// An algorithm configuration.
 #[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct Config {
     /// Specifies evolution configuration.
     pub evolution: Option<EvolutionConfig>,
@@ -27,6 +31,8 @@ pub struct Config {
     pub mutation: Option<MutationType>,
     /// Specifies algorithm termination configuration.
     pub termination: Option<TerminationConfig>,
+    /// Specifies post processing configuration.
+    pub post_processing: Option<PostProcessingConfig>,
     /// Specifies telemetry configuration.
     pub telemetry: Option<TelemetryConfig>,
 }
@@ -37,6 +43,19 @@ pub struct Config {
 pub struct EvolutionConfig {
     initial: Option<InitialConfig>,
     population: Option<PopulationType>,
+    islands: Option<IslandsConfig>,
+}
+
+/// An island model configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IslandsConfig {
+    /// Populations of islands, one per island.
+    pub populations: Vec<PopulationType>,
+    /// Specifies how often migration happens. Default is 100 (generations).
+    pub migration_interval: Option<usize>,
+    /// Amount of best individuals which migrate from each island. Default is 1.
+    pub migration_size: Option<usize>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -136,6 +155,29 @@ pub enum MutationType {
         /// Recreate methods.
         recreates: Vec<RecreateMethod>,
     },
+
+    /// A crossover and recreate metaheuristic settings.
+    #[serde(rename(deserialize = "crossover"))]
+    Crossover {
+        /// Probability.
+        probability: f64,
+        /// Crossover methods.
+        crossovers: Vec<CrossoverMethod>,
+        /// Recreate methods used to repair offspring.
+        recreates: Vec<RecreateMethod>,
+    },
+}
+
+/// Specifies crossover methods with their probability weight and specific parameters.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+pub enum CrossoverMethod {
+    /// Route based crossover method.
+    #[serde(rename(deserialize = "route-based"))]
+    RouteBased { weight: usize, routes: usize },
+    /// Selective route exchange method.
+    #[serde(rename(deserialize = "srex"))]
+    SelectiveRouteExchange { weight: usize, routes: usize },
 }
 
 /// A ruin method configuration
@@ -147,28 +189,68 @@ pub struct RuinGroupConfig {
     weight: usize,
 }
 
-/// Specifies ruin methods with their probability weight and specific parameters.
+/// Specifies ruin methods with their probability weight and specific parameters. Sizes which are
+/// omitted are scaled automatically according to problem characteristics.
 #[derive(Clone, Deserialize, Debug)]
 #[serde(tag = "type")]
 pub enum RuinMethod {
     /// Adjusted string removal method.
     #[serde(rename(deserialize = "adjusted-string"))]
-    AdjustedString { probability: f64, lmax: usize, cavg: usize, alpha: f64 },
+    AdjustedString { probability: f64, lmax: Option<usize>, cavg: Option<usize>, alpha: f64 },
     /// Neighbour jobs method
     #[serde(rename(deserialize = "neighbour"))]
-    Neighbour { probability: f64, min: usize, max: usize, threshold: f64 },
+    Neighbour {
+        probability: f64,
+        #[serde(flatten)]
+        size: RuinSizeConfig,
+        threshold: f64,
+    },
     /// Random job removal method.
     #[serde(rename(deserialize = "random-job"))]
-    RandomJob { probability: f64, min: usize, max: usize, threshold: f64 },
+    RandomJob {
+        probability: f64,
+        #[serde(flatten)]
+        size: RuinSizeConfig,
+        threshold: f64,
+    },
     /// Random route removal method.
     #[serde(rename(deserialize = "random-route"))]
-    RandomRoute { probability: f64, min: usize, max: usize, threshold: f64 },
+    RandomRoute {
+        probability: f64,
+        #[serde(flatten)]
+        size: RuinSizeConfig,
+        threshold: f64,
+    },
     /// Worst job removal method.
     #[serde(rename(deserialize = "worst-job"))]
-    WorstJob { probability: f64, min: usize, max: usize, threshold: f64, skip: usize },
+    WorstJob {
+        probability: f64,
+        #[serde(flatten)]
+        size: RuinSizeConfig,
+        threshold: f64,
+        skip: usize,
+    },
     /// Clustered jobs removal method.
     #[serde(rename(deserialize = "cluster"))]
-    Cluster { probability: f64, min: usize, max: usize, threshold: f64, cmin: usize, cmax: usize },
+    Cluster {
+        probability: f64,
+        #[serde(flatten)]
+        size: RuinSizeConfig,
+        threshold: f64,
+        cmin: usize,
+        cmax: usize,
+    },
+}
+
+/// Specifies amount of removed jobs (routes for random route removal): either absolute via `min`
+/// and `max` or relative to total amount of jobs (vehicle shifts) via `minRatio` and `maxRatio`.
+#[derive(Clone, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RuinSizeConfig {
+    pub min: Option<usize>,
+    pub max: Option<usize>,
+    pub min_ratio: Option<f64>,
+    pub max_ratio: Option<f64>,
 }
 
 /// Specifies recreate methods with their probability weight and specific parameters.
@@ -234,6 +316,24 @@ pub struct VariationConfig {
     cv: f64,
 }
 
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostProcessingConfig {
+    /// Specifies set partitioning over routes discovered during search.
+    pub set_partitioning: Option<SetPartitioningConfig>,
+}
+
+#[derive(Clone, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPartitioningConfig {
+    /// A max size of route pool. Default is 1000.
+    pub max_routes: Option<usize>,
+    /// A max amount of nodes explored by branch and bound. Default is 10000.
+    pub node_limit: Option<usize>,
+    /// A max time in seconds spent by branch and bound. Default is 5.
+    pub max_time: Option<f64>,
+}
+
 #[derive(Clone, Deserialize, Debug)]
 pub struct TelemetryConfig {
     logging: Option<LoggingConfig>,
@@ -276,7 +376,7 @@ pub struct NameWeight {
 
 impl Default for Config {
     fn default() -> Self {
-        Self { evolution: None, mutation: None, termination: None, telemetry: None }
+        Self { evolution: None, mutation: None, termination: None, post_processing: None, telemetry: None }
     }
 }
 
@@ -299,69 +399,91 @@ fn configure_from_evolution(
         if let Some(variation) = &config.population {
             // TODO pass random from outside
             let random = Arc::new(DefaultRandom::default());
-
-            let population = match &variation {
-                PopulationType::Elitism { max_size, selection_size } => Box::new(Elitism::new(
-                    problem,
-                    random,
-                    max_size.unwrap_or(4),
-                    selection_size.unwrap_or_else(get_cpus),
-                )),
-                PopulationType::Rosomaxa {
-                    max_elite_size,
-                    max_node_size,
-                    spread_factor,
-                    reduction_factor,
-                    distribution_factor,
-                    learning_rate,
-                    selection_size,
-                    rebalance_memory,
-                    rebalance_count,
-                    exploration_ratio,
-                } => {
-                    let mut config = RosomaxaConfig::default();
-                    if let Some(max_elite_size) = max_elite_size {
-                        config.elite_size = *max_elite_size;
-                    }
-                    if let Some(max_node_size) = max_node_size {
-                        config.node_size = *max_node_size;
-                    }
-                    if let Some(spread_factor) = spread_factor {
-                        config.spread_factor = *spread_factor;
-                    }
-                    if let Some(reduction_factor) = reduction_factor {
-                        config.reduction_factor = *reduction_factor;
-                    }
-                    if let Some(distribution_factor) = distribution_factor {
-                        config.distribution_factor = *distribution_factor;
-                    }
-                    if let Some(learning_rate) = learning_rate {
-                        config.learning_rate = *learning_rate;
-                    }
-                    if let Some(selection_size) = selection_size {
-                        config.selection_size = *selection_size;
-                    }
-                    if let Some(rebalance_memory) = rebalance_memory {
-                        config.rebalance_memory = *rebalance_memory;
-                    }
-                    if let Some(rebalance_count) = rebalance_count {
-                        config.rebalance_count = *rebalance_count;
-                    }
-                    if let Some(exploration_ratio) = exploration_ratio {
-                        config.exploration_ratio = *exploration_ratio;
-                    }
-
-                    Rosomaxa::new_with_fallback(problem, random, config)
-                }
-            };
+            let population = create_population(problem.clone(), random, variation);
 
             builder = builder.with_population(population);
         }
+
+        if let Some(islands) = &config.islands {
+            let populations = islands
+                .populations
+                .iter()
+                .cloned()
+                .map::<PopulationFactory, _>(|variation| {
+                    let problem = problem.clone();
+                    Arc::new(move || create_population(problem.clone(), Arc::new(DefaultRandom::default()), &variation))
+                })
+                .collect();
+
+            builder = builder.with_strategy(Arc::new(RunIslands::new(
+                populations,
+                islands.migration_interval.unwrap_or(100),
+                islands.migration_size.unwrap_or(1),
+            )));
+        }
     }
 
     Ok(builder)
 }
 
+fn create_population(
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+    variation: &PopulationType,
+) -> Box<dyn Population + Send + Sync> {
+    match variation {
+        PopulationType::Elitism { max_size, selection_size } => {
+            Box::new(Elitism::new(problem, random, max_size.unwrap_or(4), selection_size.unwrap_or_else(get_cpus)))
+        }
+        PopulationType::Rosomaxa {
+            max_elite_size,
+            max_node_size,
+            spread_factor,
+            reduction_factor,
+            distribution_factor,
+            learning_rate,
+            selection_size,
+            rebalance_memory,
+            rebalance_count,
+            exploration_ratio,
+        } => {
+            let mut config = RosomaxaConfig::default();
+            if let Some(max_elite_size) = max_elite_size {
+                config.elite_size = *max_elite_size;
+            }
+            if let Some(max_node_size) = max_node_size {
+                config.node_size = *max_node_size;
+            }
+            if let Some(spread_factor) = spread_factor {
+                config.spread_factor = *spread_factor;
+            }
+            if let Some(reduction_factor) = reduction_factor {
+                config.reduction_factor = *reduction_factor;
+            }
+            if let Some(distribution_factor) = distribution_factor {
+                config.distribution_factor = *distribution_factor;
+            }
+            if let Some(learning_rate) = learning_rate {
+                config.learning_rate = *learning_rate;
+            }
+            if let Some(selection_size) = selection_size {
+                config.selection_size = *selection_size;
+            }
+            if let Some(rebalance_memory) = rebalance_memory {
+                config.rebalance_memory = *rebalance_memory;
+            }
+            if let Some(rebalance_count) = rebalance_count {
+                config.rebalance_count = *rebalance_count;
+            }
+            if let Some(exploration_ratio) = exploration_ratio {
+                config.exploration_ratio = *exploration_ratio;
+            }
+
+            Rosomaxa::new_with_fallback(problem, random, config)
+        }
+    }
+}
+
 fn configure_from_mutation(mut builder: Builder, mutation_config: &Option<MutationType>) -> Result<Builder, String> {
     if let Some(config) = mutation_config {
         let mutation = create_mutation(&builder.config.problem, config)?.0;
@@ -384,6 +506,23 @@ fn configure_from_termination(
     Ok(builder)
 }
 
+fn configure_from_post_processing(
+    mut builder: Builder,
+    post_processing_config: &Option<PostProcessingConfig>,
+) -> Result<Builder, String> {
+    if let Some(config) = post_processing_config {
+        if let Some(SetPartitioningConfig { max_routes, node_limit, max_time }) = &config.set_partitioning {
+            builder = builder.with_post_processing(Arc::new(SetPartitioning::new(
+                max_routes.unwrap_or(1000),
+                node_limit.unwrap_or(10_000),
+                max_time.unwrap_or(5.),
+            )));
+        }
+    }
+
+    Ok(builder)
+}
+
 fn create_recreate_method(method: &RecreateMethod) -> (Box<dyn Recreate + Send + Sync>, usize) {
     match method {
         RecreateMethod::Cheapest { weight } => (Box::new(RecreateWithCheapest::default()), *weight),
@@ -405,11 +544,20 @@ fn create_mutation(
 ) -> Result<(Arc<dyn Mutation + Send + Sync>, f64), String> {
     Ok(match mutation {
         MutationType::RuinRecreate { probability, ruins, recreates } => {
-            let ruin = Box::new(CompositeRuin::new(ruins.iter().map(|g| create_ruin_group(problem, g)).collect()));
+            let characteristics = ProblemCharacteristics::new(problem.as_ref());
+            let ruin = Box::new(CompositeRuin::new(
+                ruins.iter().map(|g| create_ruin_group(problem, &characteristics, g)).collect(),
+            ));
             let recreate =
                 Box::new(CompositeRecreate::new(recreates.iter().map(|r| create_recreate_method(r)).collect()));
             (Arc::new(RuinAndRecreate::new(recreate, ruin)), *probability)
         }
+        MutationType::Crossover { probability, crossovers, recreates } => {
+            let crossover = Box::new(CompositeCrossover::new(crossovers.iter().map(create_crossover_method).collect()));
+            let recreate =
+                Box::new(CompositeRecreate::new(recreates.iter().map(|r| create_recreate_method(r)).collect()));
+            (Arc::new(CrossoverAndRecreate::new(recreate, crossover)), *probability)
+        }
         MutationType::LocalSearch { probability, times, operators: inners } => {
             let operator = create_local_search(times, inners);
             (Arc::new(LocalSearch::new(operator)), *probability)
@@ -423,31 +571,80 @@ fn create_mutation(
     })
 }
 
-fn create_ruin_group(problem: &Arc<Problem>, group: &RuinGroupConfig) -> RuinGroup {
-    (group.methods.iter().map(|r| create_ruin_method(problem, r)).collect(), group.weight)
+fn create_crossover_method(method: &CrossoverMethod) -> (Arc<dyn Crossover + Send + Sync>, usize) {
+    match method {
+        CrossoverMethod::RouteBased { weight, routes } => (Arc::new(RouteBasedCrossover::new(*routes)), *weight),
+        CrossoverMethod::SelectiveRouteExchange { weight, routes } => {
+            (Arc::new(SelectiveRouteExchange::new(*routes)), *weight)
+        }
+    }
 }
 
-fn create_ruin_method(problem: &Arc<Problem>, method: &RuinMethod) -> (Arc<dyn Ruin + Send + Sync>, f64) {
+fn create_ruin_group(
+    problem: &Arc<Problem>,
+    characteristics: &ProblemCharacteristics,
+    group: &RuinGroupConfig,
+) -> RuinGroup {
+    (group.methods.iter().map(|r| create_ruin_method(problem, characteristics, r)).collect(), group.weight)
+}
+
+fn create_ruin_method(
+    problem: &Arc<Problem>,
+    characteristics: &ProblemCharacteristics,
+    method: &RuinMethod,
+) -> (Arc<dyn Ruin + Send + Sync>, f64) {
     match method {
         RuinMethod::AdjustedString { probability, lmax, cavg, alpha } => {
-            (Arc::new(AdjustedStringRemoval::new(*lmax, *cavg, *alpha)), *probability)
+            let lmax = lmax.unwrap_or_else(|| characteristics.get_max_string_length());
+            let cavg = cavg.unwrap_or_else(|| characteristics.scale_job_amount(10));
+            (Arc::new(AdjustedStringRemoval::new(lmax, cavg, *alpha)), *probability)
         }
-        RuinMethod::Neighbour { probability, min, max, threshold } => {
-            (Arc::new(NeighbourRemoval::new(JobRemovalLimit::new(*min, *max, *threshold))), *probability)
+        RuinMethod::Neighbour { probability, size, threshold } => {
+            (Arc::new(NeighbourRemoval::new(create_job_limit(characteristics, size, *threshold))), *probability)
         }
-        RuinMethod::RandomJob { probability, min, max, threshold } => {
-            (Arc::new(RandomJobRemoval::new(JobRemovalLimit::new(*min, *max, *threshold))), *probability)
+        RuinMethod::RandomJob { probability, size, threshold } => {
+            (Arc::new(RandomJobRemoval::new(create_job_limit(characteristics, size, *threshold))), *probability)
         }
-        RuinMethod::RandomRoute { probability, min, max, threshold } => {
-            (Arc::new(RandomRouteRemoval::new(*min, *max, *threshold)), *probability)
+        RuinMethod::RandomRoute { probability, size, threshold } => {
+            let (min, max) = get_ruin_size(size, characteristics.actors)
+                .unwrap_or_else(|| (1, characteristics.get_max_removed_routes()));
+            (Arc::new(RandomRouteRemoval::new(min, max, *threshold)), *probability)
         }
-        RuinMethod::WorstJob { probability, min, max, threshold, skip: worst_skip } => {
-            (Arc::new(WorstJobRemoval::new(*worst_skip, JobRemovalLimit::new(*min, *max, *threshold))), *probability)
-        }
-        RuinMethod::Cluster { probability, min, max, threshold, cmin, cmax } => (
-            Arc::new(ClusterRemoval::new(problem.clone(), *cmin..*cmax, JobRemovalLimit::new(*min, *max, *threshold))),
+        RuinMethod::WorstJob { probability, size, threshold, skip: worst_skip } => (
+            Arc::new(WorstJobRemoval::new(*worst_skip, create_job_limit(characteristics, size, *threshold))),
             *probability,
         ),
+        RuinMethod::Cluster { probability, size, threshold, cmin, cmax } => (
+            Arc::new(ClusterRemoval::new(
+                problem.clone(),
+                *cmin..*cmax,
+                create_job_limit(characteristics, size, *threshold),
+            )),
+            *probability,
+        ),
+    }
+}
+
+/// Creates job removal limit from ruin size config or scales default one if size is not specified.
+fn create_job_limit(
+    characteristics: &ProblemCharacteristics,
+    size: &RuinSizeConfig,
+    threshold: f64,
+) -> JobRemovalLimit {
+    match get_ruin_size(size, characteristics.jobs) {
+        Some((min, max)) => JobRemovalLimit::new(min, max, threshold),
+        None => characteristics.scale_job_limit(JobRemovalLimit { threshold, ..JobRemovalLimit::default() }),
+    }
+}
+
+/// Gets absolute ruin size, ratios are applied to total amount of items.
+fn get_ruin_size(size: &RuinSizeConfig, total: usize) -> Option<(usize, usize)> {
+    let from_ratio = |ratio: f64| ((total as f64 * ratio).round() as usize).max(1);
+
+    match (size.min, size.max, size.min_ratio, size.max_ratio) {
+        (Some(min), Some(max), _, _) => Some((min, max)),
+        (_, _, Some(min_ratio), Some(max_ratio)) => Some((from_ratio(min_ratio), from_ratio(max_ratio))),
+        _ => None,
     }
 }
 
@@ -516,6 +713,244 @@ fn configure_from_telemetry(builder: Builder, telemetry_config: &Option<Telemetr
     Ok(builder.with_telemetry(Telemetry::new(telemetry_mode)))
 }
 
+/// Validates config semantically: probabilities are in range, operator lists are not empty and
+/// min values do not exceed max ones. Each error points to the offending path in the config.
+pub fn validate_config(config: &Config) -> Result<(), Vec<FormatError>> {
+    let mut errors = vec![];
+
+    if let Some(evolution) = &config.evolution {
+        validate_evolution(evolution, &mut errors);
+    }
+
+    if let Some(mutation) = &config.mutation {
+        validate_mutation(mutation, "mutation", &mut errors);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn validate_evolution(evolution: &EvolutionConfig, errors: &mut Vec<FormatError>) {
+    if let Some(methods) = evolution.initial.as_ref().and_then(|initial| initial.methods.as_ref()) {
+        check_not_empty(methods.len(), "evolution.initial.methods", errors);
+        methods.iter().enumerate().for_each(|(idx, method)| {
+            validate_recreate_method(method, format!("evolution.initial.methods[{}]", idx).as_str(), errors)
+        });
+    }
+
+    if let Some(islands) = &evolution.islands {
+        check_not_empty(islands.populations.len(), "evolution.islands.populations", errors);
+        if let Some(migration_interval) = islands.migration_interval {
+            check_positive(migration_interval, "evolution.islands.migrationInterval", errors);
+        }
+    }
+}
+
+fn validate_mutation(mutation: &MutationType, path: &str, errors: &mut Vec<FormatError>) {
+    match mutation {
+        MutationType::Composite { probability, inners } => {
+            check_probability(*probability, path, errors);
+            check_not_empty(inners.len(), format!("{}.inners", path).as_str(), errors);
+            inners.iter().enumerate().for_each(|(idx, inner)| {
+                validate_mutation(inner, format!("{}.inners[{}]", path, idx).as_str(), errors)
+            });
+        }
+        MutationType::LocalSearch { probability, times, operators } => {
+            check_probability(*probability, path, errors);
+            check_range(("min", times.min), ("max", times.max), format!("{}.times", path).as_str(), errors);
+            check_not_empty(operators.len(), format!("{}.operators", path).as_str(), errors);
+            operators.iter().enumerate().for_each(|(idx, operator)| {
+                let noise = match operator {
+                    LocalOperatorType::InterRouteBest { noise, .. }
+                    | LocalOperatorType::InterRouteRandom { noise, .. }
+                    | LocalOperatorType::IntraRouteRandom { noise, .. } => noise,
+                };
+                let path = format!("{}.operators[{}].noise", path, idx);
+
+                check_probability(noise.probability, path.as_str(), errors);
+                check_range(("min", noise.min), ("max", noise.max), path.as_str(), errors);
+            });
+        }
+        MutationType::RuinRecreate { probability, ruins, recreates } => {
+            check_probability(*probability, path, errors);
+            check_not_empty(ruins.len(), format!("{}.ruins", path).as_str(), errors);
+            ruins.iter().enumerate().for_each(|(group_idx, group)| {
+                let path = format!("{}.ruins[{}].methods", path, group_idx);
+                check_not_empty(group.methods.len(), path.as_str(), errors);
+                group.methods.iter().enumerate().for_each(|(idx, method)| {
+                    validate_ruin_method(method, format!("{}[{}]", path, idx).as_str(), errors)
+                });
+            });
+            validate_recreate_methods(recreates, format!("{}.recreates", path).as_str(), errors);
+        }
+        MutationType::Crossover { probability, crossovers, recreates } => {
+            check_probability(*probability, path, errors);
+            check_not_empty(crossovers.len(), format!("{}.crossovers", path).as_str(), errors);
+            validate_recreate_methods(recreates, format!("{}.recreates", path).as_str(), errors);
+        }
+    }
+}
+
+fn validate_ruin_method(method: &RuinMethod, path: &str, errors: &mut Vec<FormatError>) {
+    match method {
+        RuinMethod::AdjustedString { probability, .. } => check_probability(*probability, path, errors),
+        RuinMethod::Neighbour { probability, size, .. }
+        | RuinMethod::RandomJob { probability, size, .. }
+        | RuinMethod::RandomRoute { probability, size, .. }
+        | RuinMethod::WorstJob { probability, size, .. } => {
+            check_probability(*probability, path, errors);
+            validate_ruin_size(size, path, errors);
+        }
+        RuinMethod::Cluster { probability, size, cmin, cmax, .. } => {
+            check_probability(*probability, path, errors);
+            validate_ruin_size(size, path, errors);
+            check_range(("cmin", *cmin), ("cmax", *cmax), path, errors);
+        }
+    }
+}
+
+fn validate_ruin_size(size: &RuinSizeConfig, path: &str, errors: &mut Vec<FormatError>) {
+    let has_absolute = size.min.is_some() || size.max.is_some();
+    let has_relative = size.min_ratio.is_some() || size.max_ratio.is_some();
+    let is_incomplete =
+        size.min.is_some() != size.max.is_some() || size.min_ratio.is_some() != size.max_ratio.is_some();
+
+    if is_incomplete || (has_absolute && has_relative) {
+        errors.push(FormatError::new(
+            "E0008".to_string(),
+            "invalid ruin size in config".to_string(),
+            format!("specify either both 'min' and 'max' or both 'minRatio' and 'maxRatio' at '{}'", path),
+        ));
+        return;
+    }
+
+    if let (Some(min), Some(max)) = (size.min, size.max) {
+        check_range(("min", min), ("max", max), path, errors);
+    }
+
+    if let (Some(min_ratio), Some(max_ratio)) = (size.min_ratio, size.max_ratio) {
+        let is_valid_ratio = |ratio: f64| ratio > 0. && ratio <= 1.;
+
+        if is_valid_ratio(min_ratio) && is_valid_ratio(max_ratio) {
+            check_range(("minRatio", min_ratio), ("maxRatio", max_ratio), path, errors);
+        } else {
+            errors.push(FormatError::new(
+                "E0008".to_string(),
+                "invalid ruin size in config".to_string(),
+                format!("set 'minRatio' and 'maxRatio' in range (0, 1] at '{}'", path),
+            ));
+        }
+    }
+}
+
+fn validate_recreate_methods(methods: &[RecreateMethod], path: &str, errors: &mut Vec<FormatError>) {
+    check_not_empty(methods.len(), path, errors);
+    methods
+        .iter()
+        .enumerate()
+        .for_each(|(idx, method)| validate_recreate_method(method, format!("{}[{}]", path, idx).as_str(), errors));
+}
+
+fn validate_recreate_method(method: &RecreateMethod, path: &str, errors: &mut Vec<FormatError>) {
+    match method {
+        RecreateMethod::SkipBest { start, end, .. } | RecreateMethod::Regret { start, end, .. } => {
+            check_range(("start", *start), ("end", *end), path, errors)
+        }
+        RecreateMethod::Perturbation { probability, min, max, .. } => {
+            check_probability(*probability, path, errors);
+            check_range(("min", *min), ("max", *max), path, errors);
+        }
+        _ => {}
+    }
+}
+
+fn check_probability(probability: f64, path: &str, errors: &mut Vec<FormatError>) {
+    if !(0. ..=1.).contains(&probability) {
+        errors.push(FormatError::new(
+            "E0005".to_string(),
+            "invalid probability in config".to_string(),
+            format!("set probability in range [0, 1] at '{}.probability', current value is {}", path, probability),
+        ));
+    }
+}
+
+fn check_not_empty(length: usize, path: &str, errors: &mut Vec<FormatError>) {
+    if length == 0 {
+        errors.push(FormatError::new(
+            "E0006".to_string(),
+            "empty list in config".to_string(),
+            format!("specify at least one item at '{}'", path),
+        ));
+    }
+}
+
+fn check_positive(value: usize, path: &str, errors: &mut Vec<FormatError>) {
+    if value == 0 {
+        errors.push(FormatError::new(
+            "E0009".to_string(),
+            "invalid value in config".to_string(),
+            format!("set value bigger than zero at '{}'", path),
+        ));
+    }
+}
+
+fn check_range<T: PartialOrd + Display>(min: (&str, T), max: (&str, T), path: &str, errors: &mut Vec<FormatError>) {
+    let ((min_name, min), (max_name, max)) = (min, max);
+
+    if min > max {
+        errors.push(FormatError::new(
+            "E0007".to_string(),
+            "invalid range in config".to_string(),
+            format!("set '{}.{}' ({}) not bigger than '{}.{}' ({})", path, min_name, min, path, max_name, max),
+        ));
+    }
+}
+
+/// Gets config of a named preset: `fast`, `balanced` or `thorough`. Presets differ in search
+/// duration and effort, all other settings are defaults.
+pub fn get_config_preset(name: &str) -> Result<Config, String> {
+    let create_termination = |max_time: usize, max_generations: usize, variation: Option<(usize, f64)>| {
+        Some(TerminationConfig {
+            max_time: Some(max_time),
+            max_generations: Some(max_generations),
+            variation: variation.map(|(sample, cv)| VariationConfig { sample, cv }),
+        })
+    };
+
+    match name {
+        "fast" => Ok(Config {
+            evolution: Some(EvolutionConfig {
+                initial: Some(InitialConfig { size: Some(1), methods: None }),
+                population: None,
+                islands: None,
+            }),
+            termination: create_termination(60, 500, Some((200, 0.1))),
+            ..Config::default()
+        }),
+        "balanced" => Ok(Config { termination: create_termination(300, 3000, None), ..Config::default() }),
+        "thorough" => Ok(Config {
+            evolution: Some(EvolutionConfig {
+                initial: Some(InitialConfig { size: Some(4), methods: None }),
+                population: None,
+                islands: None,
+            }),
+            termination: create_termination(1800, 20000, Some((3000, 0.01))),
+            post_processing: Some(PostProcessingConfig {
+                set_partitioning: Some(SetPartitioningConfig {
+                    max_routes: Some(500),
+                    node_limit: Some(5000),
+                    max_time: Some(5.),
+                }),
+            }),
+            ..Config::default()
+        }),
+        _ => Err(format!("unknown config preset: '{}', expected one of: fast, balanced, thorough", name)),
+    }
+}
+
 /// Reads config from reader.
 pub fn read_config<R: Read>(reader: BufReader<R>) -> Result<Config, String> {
     serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize config: '{}'", err))
@@ -529,14 +964,17 @@ pub fn create_builder_from_config_file<R: Read>(
     read_config(reader).and_then(|config| create_builder_from_config(problem, &config))
 }
 
-/// Creates a solver `Builder` from config.
+/// Creates a solver `Builder` from config. Config is validated before use.
 pub fn create_builder_from_config(problem: Arc<Problem>, config: &Config) -> Result<Builder, String> {
+    validate_config(config).map_err(|errors| FormatError::format_many(&errors, "\n"))?;
+
     let mut builder = Builder::new(problem.clone());
 
     builder = configure_from_telemetry(builder, &config.telemetry)?;
     builder = configure_from_evolution(builder, &config.evolution, problem)?;
     builder = configure_from_mutation(builder, &config.mutation)?;
     builder = configure_from_termination(builder, &config.termination)?;
+    builder = configure_from_post_processing(builder, &config.post_processing)?;
 
     Ok(builder)
 }
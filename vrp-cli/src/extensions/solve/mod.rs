@@ -1,3 +1,4 @@
 //! Solve command helpers
 
 pub mod config;
+pub mod reoptimization;
@@ -0,0 +1,113 @@
+//! Contains functionality to re-optimize previously calculated solution after small changes in problem.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/reoptimization_test.rs"]
+mod reoptimization_test;
+
+use crate::extensions::lock::create_relation;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::io::{BufReader, Read};
+use vrp_pragmatic::format::is_reserved_job_id;
+use vrp_pragmatic::format::problem::{Job, Problem, RelationType};
+use vrp_pragmatic::format::solution::{Solution, Tour};
+
+/// Specifies changes happened since previous solution was calculated.
+#[derive(Clone, Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeSet {
+    /// New jobs which should be added to the plan.
+    pub new_jobs: Option<Vec<Job>>,
+    /// Ids of jobs which are cancelled and should be removed from the plan.
+    pub cancelled_jobs: Option<Vec<String>>,
+    /// Ids of vehicles which are out of service.
+    pub unavailable_vehicles: Option<Vec<String>>,
+}
+
+/// Reads change set from json.
+pub fn read_change_set<R: Read>(reader: BufReader<R>) -> Result<ChangeSet, String> {
+    serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize change set: '{}'", err))
+}
+
+/// Applies change set to the problem and its previous solution. Returns a new problem and a new
+/// solution which can be used as initial one:
+/// - cancelled jobs are removed from the plan and from the tours
+/// - new jobs are added to the plan, so they are considered as unassigned in the solution
+/// - unavailable vehicles are removed from the fleet together with their tours
+/// - tours which are not affected by changes are locked to their vehicles using relation of
+///   given type, if it is specified. Relations are created the same way as by `lock` command.
+pub fn apply_change_set(
+    problem: Problem,
+    solution: Solution,
+    change_set: &ChangeSet,
+    relation_type: Option<RelationType>,
+) -> Result<(Problem, Solution), String> {
+    let cancelled_jobs = change_set.cancelled_jobs.iter().flatten().cloned().collect::<HashSet<_>>();
+    let unavailable_vehicles = change_set.unavailable_vehicles.iter().flatten().cloned().collect::<HashSet<_>>();
+    let new_jobs = change_set.new_jobs.clone().unwrap_or_default();
+
+    let mut problem = problem;
+    let mut solution = solution;
+
+    if let Some(job) = new_jobs.iter().find(|job| problem.plan.jobs.iter().any(|other| other.id == job.id)) {
+        return Err(format!("new job has the same id as existing one: '{}'", job.id));
+    }
+
+    problem.plan.jobs.retain(|job| !cancelled_jobs.contains(&job.id));
+    problem.plan.jobs.extend(new_jobs);
+
+    problem.fleet.vehicles.iter_mut().for_each(|vehicle_type| {
+        vehicle_type.vehicle_ids.retain(|vehicle_id| !unavailable_vehicles.contains(vehicle_id))
+    });
+    problem.fleet.vehicles.retain(|vehicle_type| !vehicle_type.vehicle_ids.is_empty());
+
+    let mut relations = problem.plan.relations.take().unwrap_or_default();
+    relations.retain(|relation| !unavailable_vehicles.contains(&relation.vehicle_id));
+    relations.iter_mut().for_each(|relation| relation.jobs.retain(|job_id| !cancelled_jobs.contains(job_id)));
+    relations.retain(|relation| !relation.jobs.is_empty());
+
+    solution.tours.retain(|tour| !unavailable_vehicles.contains(&tour.vehicle_id));
+
+    let related_jobs = relations.iter().flat_map(|relation| relation.jobs.iter().cloned()).collect::<HashSet<_>>();
+    let new_relations = solution
+        .tours
+        .iter_mut()
+        .map(|tour| {
+            let is_affected = remove_jobs(tour, &cancelled_jobs);
+
+            match (is_affected, &relation_type) {
+                (false, Some(relation_type)) => create_relation(tour, relation_type.clone(), None, &related_jobs),
+                _ => Ok(None),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    relations.extend(new_relations.into_iter().flatten());
+
+    solution.tours.retain(|tour| get_job_ids(tour).next().is_some());
+
+    if let Some(unassigned) = solution.unassigned.as_mut() {
+        unassigned.retain(|job| !cancelled_jobs.contains(&job.job_id));
+    }
+
+    problem.plan.relations = if relations.is_empty() { None } else { Some(relations) };
+
+    Ok((problem, solution))
+}
+
+/// Removes activities of given jobs from the tour. Returns true if tour is changed.
+fn remove_jobs(tour: &mut Tour, jobs: &HashSet<String>) -> bool {
+    let original = tour.stops.iter().map(|stop| stop.activities.len()).sum::<usize>();
+
+    tour.stops.iter_mut().for_each(|stop| stop.activities.retain(|activity| !jobs.contains(&activity.job_id)));
+    tour.stops.retain(|stop| !stop.activities.is_empty());
+
+    original != tour.stops.iter().map(|stop| stop.activities.len()).sum::<usize>()
+}
+
+fn get_job_ids<'a>(tour: &'a Tour) -> impl Iterator<Item = String> + 'a {
+    tour.stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| !is_reserved_job_id(activity.job_id.as_str()))
+        .map(|activity| activity.job_id.clone())
+}
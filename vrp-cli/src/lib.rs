@@ -33,10 +33,12 @@ pub use vrp_scientific as scientific;
 pub mod extensions;
 
 use crate::extensions::import::import_problem;
-use crate::extensions::solve::config::{create_builder_from_config, Config};
+use crate::extensions::solve::config::{create_builder_from_config, validate_config, Config};
 use std::io::{BufReader, BufWriter};
 use std::sync::Arc;
+use vrp_core::construction::Quota;
 use vrp_core::models::Problem as CoreProblem;
+use vrp_core::solver::ProgressCallback;
 use vrp_pragmatic::format::problem::{serialize_problem, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::PragmaticSolution;
 use vrp_pragmatic::format::FormatError;
@@ -51,9 +53,28 @@ mod interop {
     use std::panic;
     use std::panic::UnwindSafe;
     use std::slice;
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use vrp_core::solver::{Progress, ProgressCallback as CoreProgressCallback};
     use vrp_pragmatic::format::problem::deserialize_problem;
 
     type Callback = extern "C" fn(*const c_char);
+    type ProgressCallback = extern "C" fn(i32, f64, i32);
+
+    /// A quota driven by a flag which is owned by the caller. The flag is expected to stay valid
+    /// while the solver is running.
+    struct ExternalQuota {
+        is_cancelled: *const AtomicI32,
+    }
+
+    unsafe impl Send for ExternalQuota {}
+
+    unsafe impl Sync for ExternalQuota {}
+
+    impl Quota for ExternalQuota {
+        fn is_reached(&self) -> bool {
+            unsafe { (*self.is_cancelled).load(Ordering::Relaxed) != 0 }
+        }
+    }
 
     fn to_string(pointer: *const c_char) -> String {
         let slice = unsafe { CStr::from_ptr(pointer).to_bytes() };
@@ -138,23 +159,200 @@ mod interop {
         failure: Callback,
     ) {
         catch_panic(failure, || {
-            let problem = to_string(problem);
-            let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len as usize).to_vec() };
-            let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
-
-            let result =
-                if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
-                    .map_err(|errors| get_errors_serialized(&errors))
-                    .and_then(|problem| {
-                        read_config(BufReader::new(to_string(config).as_bytes()))
-                            .map_err(|err| to_config_error(err.as_str()))
-                            .map(|config| (problem, config))
-                    })
-                    .and_then(|(problem, config)| get_solution_serialized(Arc::new(problem), config));
+            let result = solve(problem, matrices, matrices_len, config, None, None);
+
+            call_back(result, success, failure);
+        });
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Search progress is reported
+    /// via `progress` callback after each generation: generation number, best known cost and amount
+    /// of unassigned jobs. Search is stopped and the best known solution is returned once value pointed
+    /// by `is_cancelled` is set to non-zero by the caller. Null pointer means no cancellation.
+    ///
+    /// A non-null `is_cancelled` has to point to a 32 bit integer aligned to 4 bytes which is not freed
+    /// or moved until `success` or `failure` callback is called. The value is read from the solver
+    /// threads, so the caller should write it atomically, e.g. with a single aligned 32 bit store.
+    #[no_mangle]
+    extern "C" fn solve_pragmatic_with_progress(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: *const i32,
+        config: *const c_char,
+        progress: ProgressCallback,
+        is_cancelled: *const AtomicI32,
+        success: Callback,
+        failure: Callback,
+    ) {
+        catch_panic(failure, || {
+            let progress: CoreProgressCallback = Arc::new(move |state: &Progress| {
+                progress(state.generation as i32, state.cost, state.unassigned as i32)
+            });
+            let quota: Option<Arc<dyn Quota + Send + Sync>> =
+                if is_cancelled.is_null() { None } else { Some(Arc::new(ExternalQuota { is_cancelled })) };
+
+            let result = solve(problem, matrices, matrices_len, config, Some(progress), quota);
 
             call_back(result, success, failure);
         });
     }
+
+    fn solve(
+        problem: *const c_char,
+        matrices: *const *const c_char,
+        matrices_len: *const i32,
+        config: *const c_char,
+        progress: Option<CoreProgressCallback>,
+        quota: Option<Arc<dyn Quota + Send + Sync>>,
+    ) -> Result<String, String> {
+        let problem = to_string(problem);
+        let matrices = unsafe { slice::from_raw_parts(matrices, matrices_len as usize).to_vec() };
+        let matrices = matrices.iter().map(|m| to_string(*m)).collect::<Vec<_>>();
+
+        if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(|errors| get_errors_serialized(&errors))
+            .and_then(|problem| {
+                read_config(BufReader::new(to_string(config).as_bytes()))
+                    .map_err(|err| to_config_error(err.as_str()))
+                    .map(|config| (problem, config))
+            })
+            .and_then(|(problem, config)| {
+                get_solution_serialized_with_progress(Arc::new(problem), config, progress, quota)
+            })
+    }
+}
+
+#[cfg(feature = "py_binding")]
+#[cfg(not(target_arch = "wasm32"))]
+mod py_interop {
+    use super::*;
+    use crate::extensions::solve::config::read_config;
+    use pyo3::exceptions::{PyRuntimeError, PyValueError};
+    use pyo3::prelude::*;
+    use pyo3::types::PyString;
+    use vrp_pragmatic::checker::{CheckerContext, CheckerError};
+    use vrp_pragmatic::format::problem::{deserialize_matrix, deserialize_problem};
+    use vrp_pragmatic::format::solution::deserialize_solution;
+
+    /// Converts python object (a dict or json string) to json string.
+    fn to_json_string(py: Python, value: &PyAny) -> PyResult<String> {
+        if value.is_instance_of::<PyString>()? {
+            value.extract()
+        } else {
+            py.import("json")?.call_method1("dumps", (value,))?.extract()
+        }
+    }
+
+    /// Parses json string to python object.
+    fn from_json_string(py: Python, json: String) -> PyResult<PyObject> {
+        Ok(py.import("json")?.call_method1("loads", (json,))?.into())
+    }
+
+    fn to_json_strings(py: Python, values: Option<Vec<&PyAny>>) -> PyResult<Vec<String>> {
+        values.unwrap_or_default().into_iter().map(|value| to_json_string(py, value)).collect()
+    }
+
+    /// Returns a list of unique locations which can be used to request a routing matrix.
+    /// A `problem` should be passed in `pragmatic` format as a dict or json string.
+    #[pyfunction]
+    fn get_routing_locations(py: Python, problem: &PyAny) -> PyResult<PyObject> {
+        let problem = to_json_string(py, problem)?;
+
+        let locations = deserialize_problem(BufReader::new(problem.as_bytes()))
+            .map_err(|errors| get_errors_serialized(&errors))
+            .and_then(|problem| get_locations_serialized(&problem))
+            .map_err(PyValueError::new_err)?;
+
+        from_json_string(py, locations)
+    }
+
+    /// Converts `problem` from format specified by `format` to `pragmatic` format.
+    /// `inputs` is a list of input file contents.
+    #[pyfunction]
+    fn convert_to_pragmatic(py: Python, format: &str, inputs: Vec<String>) -> PyResult<PyObject> {
+        let readers = inputs.iter().map(|input| BufReader::new(input.as_bytes())).collect();
+
+        let problem = import_problem(format, Some(readers)).map_err(PyValueError::new_err)?;
+
+        let mut buffer = String::new();
+        let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+        serialize_problem(writer, &problem).map_err(|err| PyRuntimeError::new_err(err.to_string()))?;
+
+        from_json_string(py, buffer)
+    }
+
+    /// Solves Vehicle Routing Problem passed in `pragmatic` format. Optional `matrices` is a list of
+    /// routing matrices and optional `config` has the same structure as solver config file.
+    /// GIL is released while solver is running.
+    #[pyfunction]
+    #[pyo3(signature = (problem, matrices = None, config = None))]
+    fn solve_pragmatic(
+        py: Python,
+        problem: &PyAny,
+        matrices: Option<Vec<&PyAny>>,
+        config: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let problem = to_json_string(py, problem)?;
+        let matrices = to_json_strings(py, matrices)?;
+
+        let problem = if matrices.is_empty() { problem.read_pragmatic() } else { (problem, matrices).read_pragmatic() }
+            .map_err(|errors| PyValueError::new_err(get_errors_serialized(&errors)))?;
+
+        let config = match config {
+            Some(config) => read_config(BufReader::new(to_json_string(py, config)?.as_bytes()))
+                .map_err(|err| PyValueError::new_err(to_config_error(err.as_str())))?,
+            None => Config::default(),
+        };
+
+        let solution = py
+            .allow_threads(move || get_solution_serialized(Arc::new(problem), config))
+            .map_err(PyRuntimeError::new_err)?;
+
+        from_json_string(py, solution)
+    }
+
+    /// Checks `solution` feasibility for `problem` passed in `pragmatic` format. Returns a list of
+    /// found violations which is empty when solution is feasible.
+    #[pyfunction]
+    #[pyo3(signature = (problem, solution, matrices = None))]
+    fn check_pragmatic(
+        py: Python,
+        problem: &PyAny,
+        solution: &PyAny,
+        matrices: Option<Vec<&PyAny>>,
+    ) -> PyResult<PyObject> {
+        let problem = deserialize_problem(BufReader::new(to_json_string(py, problem)?.as_bytes()))
+            .map_err(|errors| PyValueError::new_err(get_errors_serialized(&errors)))?;
+        let solution = deserialize_solution(BufReader::new(to_json_string(py, solution)?.as_bytes()))
+            .map_err(|err| PyValueError::new_err(format!("cannot read solution: '{}'", err)))?;
+        let matrices = to_json_strings(py, matrices)?
+            .iter()
+            .map(|matrix| deserialize_matrix(BufReader::new(matrix.as_bytes())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|errors| PyValueError::new_err(get_errors_serialized(&errors)))?;
+        let matrices = if matrices.is_empty() { None } else { Some(matrices) };
+
+        let core_problem = (problem.clone(), matrices.clone())
+            .read_pragmatic()
+            .map_err(|errors| PyValueError::new_err(get_errors_serialized(&errors)))?;
+
+        let violations = py.allow_threads(move || {
+            CheckerContext::new(Arc::new(core_problem), problem, matrices, solution).check().err().unwrap_or_default()
+        });
+
+        from_json_string(py, CheckerError::to_json(violations.as_slice()).map_err(PyValueError::new_err)?)
+    }
+
+    /// A python module which exposes solver functionality.
+    #[pymodule]
+    fn vrp_cli(_py: Python, module: &PyModule) -> PyResult<()> {
+        module.add_function(wrap_pyfunction!(get_routing_locations, module)?)?;
+        module.add_function(wrap_pyfunction!(convert_to_pragmatic, module)?)?;
+        module.add_function(wrap_pyfunction!(solve_pragmatic, module)?)?;
+        module.add_function(wrap_pyfunction!(check_pragmatic, module)?)?;
+
+        Ok(())
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -237,17 +435,41 @@ pub fn get_locations_serialized(problem: &Problem) -> Result<String, String> {
 
 /// Gets solution serialized in json.
 pub fn get_solution_serialized(problem: Arc<CoreProblem>, config: Config) -> Result<String, String> {
-    let (solution, _, metrics) = create_builder_from_config(problem.clone(), &config)
-        .and_then(|builder| builder.build())
-        .and_then(|solver| solver.solve())
-        .map_err(|err| {
-            FormatError::new(
-                "E0003".to_string(),
-                "cannot find any solution".to_string(),
-                format!("please submit a bug and share original problem and routing matrix. Error: '{}'", err),
-            )
-            .to_json()
-        })?;
+    get_solution_serialized_with_progress(problem, config, None, None)
+}
+
+/// Gets solution serialized in json. Search is stopped with the best known solution once
+/// `quota` is reached.
+pub fn get_solution_serialized_with_quota(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    quota: Option<Arc<dyn Quota + Send + Sync>>,
+) -> Result<String, String> {
+    get_solution_serialized_with_progress(problem, config, None, quota)
+}
+
+/// Gets solution serialized in json. Search progress is reported via `progress` callback after
+/// each generation and search is stopped with the best known solution once `quota` is reached.
+pub fn get_solution_serialized_with_progress(
+    problem: Arc<CoreProblem>,
+    config: Config,
+    progress: Option<ProgressCallback>,
+    quota: Option<Arc<dyn Quota + Send + Sync>>,
+) -> Result<String, String> {
+    validate_config(&config).map_err(|errors| FormatError::format_many_to_json(&errors))?;
+
+    let builder = create_builder_from_config(problem.clone(), &config).map_err(|err| to_config_error(err.as_str()))?;
+    let builder = if let Some(progress) = progress { builder.with_progress(progress) } else { builder };
+    let builder = if let Some(quota) = quota { builder.with_quota(quota) } else { builder };
+
+    let (solution, _, metrics) = builder.build().and_then(|solver| solver.solve()).map_err(|err| {
+        FormatError::new(
+            "E0003".to_string(),
+            "cannot find any solution".to_string(),
+            format!("please submit a bug and share original problem and routing matrix. Error: '{}'", err),
+        )
+        .to_json()
+    })?;
 
     let mut buffer = String::new();
     let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
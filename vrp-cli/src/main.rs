@@ -12,9 +12,13 @@ fn main() {
 mod cli {
     use super::commands::import::{get_import_app, run_import};
     use super::commands::solve::{get_solve_app, run_solve};
+    use crate::commands::analyze::{get_analyze_app, run_analyze};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
+    use crate::commands::diff::{get_diff_app, run_diff};
     use crate::commands::generate::{get_generate_app, run_generate};
+    use crate::commands::lock::{get_lock_app, run_lock};
+    use crate::commands::serve::{get_serve_app, run_serve};
     use clap::{crate_version, App};
     use std::process;
 
@@ -27,6 +31,10 @@ mod cli {
             .subcommand(get_import_app())
             .subcommand(get_check_app())
             .subcommand(get_generate_app())
+            .subcommand(get_lock_app())
+            .subcommand(get_analyze_app())
+            .subcommand(get_diff_app())
+            .subcommand(get_serve_app())
             .get_matches();
 
         match matches.subcommand() {
@@ -34,6 +42,10 @@ mod cli {
             ("import", Some(import_matches)) => run_import(import_matches),
             ("check", Some(check_matches)) => run_check(check_matches),
             ("generate", Some(generate_matches)) => run_generate(generate_matches),
+            ("lock", Some(lock_matches)) => run_lock(lock_matches),
+            ("analyze", Some(analyze_matches)) => run_analyze(analyze_matches),
+            ("diff", Some(diff_matches)) => run_diff(diff_matches),
+            ("serve", Some(serve_matches)) => run_serve(serve_matches),
             ("", None) => {
                 eprintln!("No subcommand was used. Use -h to print help information.");
                 process::exit(1);
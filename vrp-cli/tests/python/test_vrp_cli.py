@@ -0,0 +1,56 @@
+import json
+import os
+
+import pytest
+import vrp_cli
+
+DATA_DIR = os.path.join(os.path.dirname(__file__), "..", "..", "..", "examples", "data", "pragmatic")
+
+
+def read_json(name):
+    with open(os.path.join(DATA_DIR, name)) as file:
+        return json.load(file)
+
+
+def create_config(max_generations):
+    return {"termination": {"maxGenerations": max_generations}}
+
+
+def test_can_get_routing_locations():
+    locations = vrp_cli.get_routing_locations(read_json("simple.basic.problem.json"))
+
+    assert locations == read_json("simple.basic.locations.json")
+
+
+def test_can_solve_and_check_problem():
+    problem = read_json("simple.basic.problem.json")
+    matrices = [read_json("simple.basic.matrix.json")]
+
+    solution = vrp_cli.solve_pragmatic(problem, matrices, create_config(10))
+    violations = vrp_cli.check_pragmatic(problem, solution, matrices)
+
+    assert len(solution["tours"]) > 0
+    assert violations == []
+
+
+def test_can_accept_json_strings():
+    problem = json.dumps(read_json("simple.basic.problem.json"))
+
+    solution = vrp_cli.solve_pragmatic(problem, config=json.dumps(create_config(10)))
+
+    assert "statistic" in solution
+
+
+def test_can_convert_csv_to_pragmatic():
+    jobs = "ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END\njob1,52.5225,13.4095,1,3,,\n"
+    vehicles = "ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE\n" \
+               "vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,1,car\n"
+
+    problem = vrp_cli.convert_to_pragmatic("csv", [jobs, vehicles])
+
+    assert [job["id"] for job in problem["plan"]["jobs"]] == ["job1"]
+
+
+def test_can_raise_error_on_invalid_problem():
+    with pytest.raises(ValueError):
+        vrp_cli.solve_pragmatic({"plan": {"jobs": []}})
@@ -0,0 +1,19 @@
+use super::*;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+#[test]
+fn can_run_analyze_problem() {
+    let out_path = std::env::temp_dir().join(format!("vrp_cli_problem_analysis_{}.json", std::process::id()));
+    let args = vec!["analyze", "pragmatic", "--problem-file", PRAGMATIC_PROBLEM_PATH, "-o", out_path.to_str().unwrap()];
+    let matches = get_analyze_app().get_matches_from_safe(args).unwrap();
+
+    run_analyze(&matches);
+
+    let analysis: serde_json::Value =
+        serde_json::from_reader(BufReader::new(open_file(out_path.to_str().unwrap(), "analysis"))).unwrap();
+    std::fs::remove_file(out_path).expect("cannot remove analysis file");
+    assert_eq!(analysis["jobs"]["total"], 3);
+    assert_eq!(analysis["minTours"], 1);
+    assert!(analysis["unreachableJobs"].as_array().unwrap().is_empty());
+}
@@ -20,3 +20,30 @@ fn can_run_check_solution() {
 
     run_check(&matches);
 }
+
+#[test]
+fn can_run_check_solution_with_json_output() {
+    let out_path = std::env::temp_dir().join(format!("check_violations_{}.json", std::process::id()));
+    let out_path_str = out_path.to_str().unwrap();
+    let args = vec![
+        "check",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--out-format",
+        "json",
+        "--out-result",
+        out_path_str,
+    ];
+    let matches = get_check_app().get_matches_from_safe(args).unwrap();
+
+    run_check(&matches);
+
+    let result = std::fs::read_to_string(&out_path).unwrap();
+    std::fs::remove_file(&out_path).ok();
+    assert_eq!(result.trim(), "[]");
+}
@@ -0,0 +1,29 @@
+use super::*;
+
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_run_diff_solutions() {
+    let out_path = std::env::temp_dir().join(format!("vrp_cli_solution_diff_{}.json", std::process::id()));
+    let args = vec![
+        "diff",
+        "pragmatic",
+        "--base-solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--time-threshold",
+        "60",
+        "-o",
+        out_path.to_str().unwrap(),
+    ];
+    let matches = get_diff_app().get_matches_from_safe(args).unwrap();
+
+    run_diff(&matches);
+
+    let diff: serde_json::Value =
+        serde_json::from_reader(BufReader::new(open_file(out_path.to_str().unwrap(), "diff"))).unwrap();
+    std::fs::remove_file(out_path).expect("cannot remove diff file");
+    assert!(diff["tours"].as_array().unwrap().is_empty());
+    assert_eq!(diff["statistic"]["cost"], 0.);
+}
@@ -0,0 +1,48 @@
+use super::*;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+#[test]
+fn can_run_lock_solution() {
+    let out_path = std::env::temp_dir().join(format!("vrp_cli_locked_problem_{}.json", std::process::id()));
+    let args = vec![
+        "lock",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--relation-type",
+        "strict",
+        "--time-range",
+        "2019-07-04T09:00:00Z,2019-07-04T10:30:00Z",
+        "-o",
+        out_path.to_str().unwrap(),
+    ];
+    let matches = get_lock_app().get_matches_from_safe(args).unwrap();
+
+    run_lock(&matches);
+
+    let problem = deserialize_problem(BufReader::new(open_file(out_path.to_str().unwrap(), "problem"))).unwrap();
+    std::fs::remove_file(out_path).expect("cannot remove locked problem");
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].jobs, vec!["departure", "job1", "job2"]);
+}
+
+#[test]
+fn can_reject_unknown_relation_type() {
+    let args = vec![
+        "lock",
+        "pragmatic",
+        "--problem-file",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution-file",
+        PRAGMATIC_SOLUTION_PATH,
+        "--relation-type",
+        "fixed",
+    ];
+
+    assert!(get_lock_app().get_matches_from_safe(args).is_err());
+}
@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn can_get_default_server_config() {
+    let matches = get_serve_app().get_matches_from_safe(vec!["serve"]).unwrap();
+
+    let config = get_server_config(&matches).unwrap();
+
+    assert_eq!(matches.value_of(ADDRESS_ARG_NAME), Some("127.0.0.1:8000"));
+    assert_eq!(config.workers, 1);
+    assert_eq!(config.queue_size, 16);
+    assert_eq!(config.connections, 32);
+    assert_eq!(config.timeout, Duration::from_secs(30));
+    assert_eq!(config.job_ttl, Duration::from_secs(3600));
+}
+
+#[test]
+fn can_get_server_config_from_args() {
+    let args = vec![
+        "serve",
+        "--address",
+        "0.0.0.0:9000",
+        "-w",
+        "4",
+        "--queue-size",
+        "2",
+        "--connections",
+        "8",
+        "--timeout",
+        "5",
+        "--job-ttl",
+        "60",
+    ];
+    let matches = get_serve_app().get_matches_from_safe(args).unwrap();
+
+    let config = get_server_config(&matches).unwrap();
+
+    assert_eq!(matches.value_of(ADDRESS_ARG_NAME), Some("0.0.0.0:9000"));
+    assert_eq!(config.workers, 4);
+    assert_eq!(config.queue_size, 2);
+    assert_eq!(config.connections, 8);
+    assert_eq!(config.timeout, Duration::from_secs(5));
+    assert_eq!(config.job_ttl, Duration::from_secs(60));
+}
+
+#[test]
+fn can_reject_zero_values() {
+    vec!["--workers", "--connections", "--timeout"].into_iter().for_each(|arg_name| {
+        let matches = get_serve_app().get_matches_from_safe(vec!["serve", arg_name, "0"]).unwrap();
+
+        let result = get_server_config(&matches);
+
+        assert!(result.is_err(), "{} should be rejected", arg_name);
+    });
+}
@@ -36,6 +36,77 @@ fn can_solve_solomon_problem_with_generation_limit() {
     run_solve_with_out_writer(&matches);
 }
 
+#[test]
+fn can_solve_solomon_problem_with_best_known_solution() {
+    let args = vec![
+        "solve",
+        "solomon",
+        "../examples/data/scientific/solomon/C101.100.txt",
+        "--best-known",
+        "../examples/data/scientific/solomon/C101.100.best.txt",
+        "--max-generations",
+        "10",
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_csv_output() {
+    let unassigned_path = std::env::temp_dir().join(format!("vrp_cli_unassigned_{}.csv", std::process::id()));
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--out-format",
+        "csv",
+        "--out-unassigned",
+        unassigned_path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let unassigned = std::fs::read_to_string(&unassigned_path).expect("cannot read unassigned csv");
+    std::fs::remove_file(unassigned_path).expect("cannot remove unassigned csv");
+    assert!(unassigned.starts_with("job_id,code,description\n"));
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_unassigned_explanation() {
+    let problem_path = std::env::temp_dir().join(format!("vrp_cli_unassigned_problem_{}.json", std::process::id()));
+    let out_path = std::env::temp_dir().join(format!("vrp_cli_explained_solution_{}.json", std::process::id()));
+    let mut problem: serde_json::Value =
+        serde_json::from_reader(BufReader::new(open_file(PRAGMATIC_PROBLEM_PATH, "problem"))).unwrap();
+    problem["plan"]["jobs"][0]["skills"] = serde_json::json!({ "allOf": ["unknown_skill"] });
+    std::fs::write(&problem_path, problem.to_string()).expect("cannot write problem");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        problem_path.to_str().unwrap(),
+        "--max-generations",
+        "10",
+        "--explain-unassigned",
+        "-o",
+        out_path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve(&matches, create_write_buffer);
+
+    let solution: serde_json::Value =
+        serde_json::from_reader(BufReader::new(open_file(out_path.to_str().unwrap(), "solution"))).unwrap();
+    std::fs::remove_file(problem_path).expect("cannot remove problem");
+    std::fs::remove_file(out_path).expect("cannot remove solution");
+    let reason = &solution["unassigned"][0]["reasons"][0];
+    assert_eq!(reason["code"], "SKILL_CONSTRAINT");
+    assert_eq!(reason["details"][0]["vehicleId"], "vehicle_1");
+    assert_eq!(reason["details"][0]["shiftIndex"], 0);
+}
+
 #[test]
 fn can_solve_lilim_problem_with_time_limit() {
     let args = vec!["solve", "lilim", LILIM_PROBLEM_PATH, "--max-time", "10"];
@@ -58,3 +129,221 @@ fn can_specify_search_mode_setting() {
         get_solve_app().get_matches_from_safe(args).unwrap();
     }
 }
+
+#[test]
+fn can_solve_pragmatic_problem_with_change_set() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--init-solution",
+        "../examples/data/pragmatic/simple.basic.solution.json",
+        "--change-set",
+        "../examples/data/pragmatic/simple.basic.change-set.json",
+        "--max-generations",
+        "10",
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_require_init_solution_with_change_set() {
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--change-set",
+        "../examples/data/pragmatic/simple.basic.change-set.json",
+    ];
+
+    get_solve_app().get_matches_from_safe(args).unwrap_err();
+}
+
+#[test]
+fn can_write_checkpoints_and_resume_from_them() {
+    use vrp_pragmatic::format::solution::deserialize_solution;
+
+    let checkpoint_dir = std::env::temp_dir().join(format!("vrp_cli_checkpoints_{}", std::process::id()));
+    std::fs::create_dir_all(&checkpoint_dir).expect("cannot create checkpoint dir");
+    let checkpoint_path = checkpoint_dir.join("checkpoint_{generation}.json");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--checkpoint",
+        checkpoint_path.to_str().unwrap(),
+        "--checkpoint-generations",
+        "5",
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let checkpoints = std::fs::read_dir(&checkpoint_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("json"))
+        .collect::<Vec<_>>();
+    assert!(!checkpoints.is_empty());
+    checkpoints.iter().for_each(|path| {
+        let solution = deserialize_solution(BufReader::new(File::open(path).unwrap()))
+            .unwrap_or_else(|err| panic!("cannot read checkpoint '{:?}': '{}'", path, err));
+        assert!(!solution.tours.is_empty());
+    });
+
+    let init_solution = checkpoints.last().unwrap();
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--init-solution",
+        init_solution.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    std::fs::remove_dir_all(checkpoint_dir).expect("cannot remove checkpoint dir");
+}
+
+#[test]
+fn can_write_pareto_front() {
+    use vrp_pragmatic::format::solution::deserialize_solution;
+
+    let front_dir = std::env::temp_dir().join(format!("vrp_cli_pareto_front_{}", std::process::id()));
+    std::fs::create_dir_all(&front_dir).expect("cannot create pareto front dir");
+    let front_path = front_dir.join("solution_{index}.json");
+    let args = vec![
+        "solve",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--max-generations",
+        "10",
+        "--pareto-front",
+        front_path.to_str().unwrap(),
+    ];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+
+    let solution =
+        deserialize_solution(BufReader::new(File::open(front_dir.join("solution_0.json")).unwrap())).unwrap();
+    let objectives = solution.extras.and_then(|extras| extras.objectives).expect("no objective values");
+    assert_eq!(
+        objectives.iter().map(|objective| objective.objective_type.as_str()).collect::<Vec<_>>(),
+        vec!["minimize-unassigned", "minimize-tours", "minimize-cost"]
+    );
+
+    std::fs::remove_dir_all(front_dir).expect("cannot remove pareto front dir");
+}
+
+#[test]
+fn can_require_checkpoint_path_with_checkpoint_interval() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--checkpoint-time", "60"];
+
+    get_solve_app().get_matches_from_safe(args).unwrap_err();
+}
+
+#[test]
+fn can_solve_pragmatic_problem_with_config_preset() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--config-preset", "fast", "--max-generations", "10"];
+    let matches = get_solve_app().get_matches_from_safe(args).unwrap();
+
+    run_solve_with_out_writer(&matches);
+}
+
+#[test]
+fn can_reject_config_preset_with_config() {
+    let args = vec!["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH, "--config-preset", "fast", "--config", "config.json"];
+
+    get_solve_app().get_matches_from_safe(args).unwrap_err();
+}
+
+#[test]
+fn can_create_interruption_quota_which_is_not_reached_without_signal() {
+    let quota = create_interruption_quota();
+
+    assert!(!quota.is_reached());
+}
+
+#[test]
+fn can_reach_interruption_quota_when_flag_is_set() {
+    static IS_TEST_INTERRUPTED: AtomicBool = AtomicBool::new(false);
+    let quota = InterruptionQuota { is_interrupted: &IS_TEST_INTERRUPTED };
+    assert!(!quota.is_reached());
+
+    IS_TEST_INTERRUPTED.store(true, Ordering::Relaxed);
+
+    assert!(quota.is_reached());
+}
+
+#[test]
+fn can_write_best_known_solution_when_interrupted() {
+    use std::sync::atomic::AtomicUsize;
+    use vrp_core::solver::ProgressCallback;
+    use vrp_pragmatic::format::problem::PragmaticProblem;
+    use vrp_pragmatic::format::solution::{deserialize_solution, PragmaticSolution};
+
+    static IS_TEST_INTERRUPTED: AtomicBool = AtomicBool::new(false);
+    let problem = Arc::new(std::fs::read_to_string(PRAGMATIC_PROBLEM_PATH).unwrap().read_pragmatic().unwrap());
+    let last_generation = Arc::new(AtomicUsize::new(0));
+    let progress: ProgressCallback = {
+        let last_generation = last_generation.clone();
+        Arc::new(move |progress: &Progress| {
+            last_generation.store(progress.generation, Ordering::Relaxed);
+            if progress.generation == 5 {
+                IS_TEST_INTERRUPTED.store(true, Ordering::Relaxed);
+            }
+        })
+    };
+
+    let (solution, _, _) = Builder::new(problem.clone())
+        .with_max_generations(Some(1000))
+        .with_progress(progress)
+        .with_quota(Arc::new(InterruptionQuota { is_interrupted: &IS_TEST_INTERRUPTED }))
+        .build()
+        .and_then(|solver| solver.solve())
+        .expect("cannot solve interrupted problem");
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    solution.write_pragmatic_json(&problem, writer).expect("cannot write solution");
+    let solution = deserialize_solution(BufReader::new(buffer.as_bytes())).expect("cannot read solution");
+    assert!(last_generation.load(Ordering::Relaxed) < 1000);
+    assert!(!solution.tours.is_empty());
+    assert!(solution.unassigned.is_none());
+}
+
+#[test]
+fn can_override_only_specified_termination_criteria_of_config_preset() {
+    let config = get_config_preset("fast").unwrap();
+    let builder = create_builder_from_config(vrp_core::models::examples::create_example_problem(), &config).unwrap();
+    let cost_variation = builder.cost_variation;
+    assert!(cost_variation.is_some());
+
+    let builder = override_termination(builder, None, Some(10), None);
+
+    assert_eq!(builder.max_time, Some(10));
+    assert_eq!(builder.max_generations, Some(500));
+    assert_eq!(builder.cost_variation, cost_variation);
+}
+
+#[test]
+fn can_return_error_when_getting_locations_for_unsupported_format() {
+    let formats = get_formats();
+
+    for format in &["tsplib", "cordeau"] {
+        let (_, _, _, locations_writer) = formats.get(format).unwrap();
+
+        let result =
+            locations_writer.0(File::open(SOLOMON_PROBLEM_PATH).unwrap(), BufWriter::new(Box::new(DummyWrite {})));
+
+        assert_eq!(result, Err(format!("getting locations is not supported for {} format", format)));
+    }
+}
@@ -0,0 +1,159 @@
+use super::*;
+use crate::helpers::generate::*;
+use vrp_pragmatic::format::Location;
+
+fn create_job(id: &str, lat: f64, lng: f64, demand: i32) -> Job {
+    let mut job = Job { id: id.to_string(), ..create_test_job(lat, lng) };
+    job.pickups.as_mut().unwrap()[0].demand = Some(vec![demand]);
+
+    job
+}
+
+fn create_problem(jobs: Vec<Job>, vehicles: Vec<VehicleType>) -> Problem {
+    Problem {
+        plan: Plan { jobs, relations: None },
+        fleet: Fleet { vehicles, profiles: vec![create_test_vehicle_profile()], states: None },
+        objectives: None,
+    }
+}
+
+fn create_vehicle_type(vehicle_ids: &[&str], capacity: i32, limits: Option<VehicleLimits>) -> VehicleType {
+    VehicleType {
+        vehicle_ids: vehicle_ids.iter().map(|id| id.to_string()).collect(),
+        capacity: vec![capacity],
+        limits,
+        ..create_test_vehicle_type()
+    }
+}
+
+fn create_limits(tour_size: Option<usize>, allowed_areas: Option<Vec<AreaLimit>>) -> Option<VehicleLimits> {
+    Some(VehicleLimits { max_distance: None, shift_time: None, tour_size, allowed_areas })
+}
+
+fn analyze(problem: Problem) -> ProblemAnalysis {
+    analyze_pragmatic_problem(problem, None).expect("cannot analyze problem")
+}
+
+#[test]
+fn can_collect_problem_statistics() {
+    let problem = create_problem(
+        vec![create_job("job1", 0.1, 0.1, 2), create_job("job2", 0.2, 0.2, 3), create_job("job3", 0.3, 0.3, 1)],
+        vec![create_vehicle_type(&["v1", "v2"], 10, None)],
+    );
+
+    let analysis = analyze(problem);
+
+    assert_eq!(analysis.jobs.total, 3);
+    assert_eq!(analysis.jobs.pickups, 3);
+    assert_eq!(analysis.jobs.multi, 0);
+    assert_eq!(analysis.fleet.vehicle_types, 1);
+    assert_eq!(analysis.fleet.vehicles, 2);
+    assert_eq!(analysis.fleet.shifts, 2);
+    assert_eq!(analysis.fleet.profiles, 1);
+    assert_eq!(analysis.capacity.len(), 1);
+    assert_eq!(analysis.capacity[0].demand, 6);
+    assert_eq!(analysis.capacity[0].capacity, 20);
+    assert_eq!(analysis.capacity[0].ratio, Some(0.3));
+    assert_eq!(analysis.time_windows.places, 3);
+    assert_eq!(analysis.time_windows.unrestricted_places, 0);
+    assert_eq!(analysis.time_windows.min_width, Some(7200.));
+    assert!(analysis.incompatible_jobs.is_empty());
+    assert!(analysis.unreachable_jobs.is_empty());
+    assert_eq!(analysis.min_tours, 1);
+}
+
+parameterized_test! {can_estimate_min_tours, (demand, capacity, tour_size, expected), {
+    can_estimate_min_tours_impl(demand, capacity, tour_size, expected);
+}}
+
+can_estimate_min_tours! {
+    case01: (1, 10, None, 1),
+    case02: (5, 10, None, 3),
+    case03: (1, 10, Some(2), 3),
+    case04: (4, 10, Some(4), 2),
+}
+
+fn can_estimate_min_tours_impl(demand: i32, capacity: i32, tour_size: Option<usize>, expected: usize) {
+    let jobs = (1..=5).map(|idx| create_job(format!("job{}", idx).as_str(), 0.1, 0.1, demand)).collect();
+    let limits = tour_size.and_then(|tour_size| create_limits(Some(tour_size), None));
+    let problem = create_problem(jobs, vec![create_vehicle_type(&["v1", "v2", "v3"], capacity, limits)]);
+
+    let analysis = analyze(problem);
+
+    assert_eq!(analysis.min_tours, expected);
+}
+
+#[test]
+fn can_detect_incompatible_jobs() {
+    let area = AreaLimit {
+        priority: None,
+        outer_shape: vec![
+            Location::Coordinate { lat: -1., lng: -1. },
+            Location::Coordinate { lat: -1., lng: 1. },
+            Location::Coordinate { lat: 1., lng: 1. },
+            Location::Coordinate { lat: 1., lng: -1. },
+        ],
+    };
+    let skill_job = Job {
+        skills: Some(JobSkills { all_of: Some(vec!["unknown".to_string()]), one_of: None, none_of: None }),
+        ..create_job("job1", 0.1, 0.1, 1)
+    };
+    let problem = create_problem(
+        vec![skill_job, create_job("job2", 5., 5., 1), create_job("job3", 0.5, 0.5, 1)],
+        vec![create_vehicle_type(&["v1"], 10, create_limits(None, Some(vec![area])))],
+    );
+
+    let analysis = analyze(problem);
+
+    assert_eq!(
+        analysis.incompatible_jobs.iter().map(|issue| (issue.job_id.as_str(), issue.code.as_str())).collect::<Vec<_>>(),
+        vec![("job1", "SKILL_CONSTRAINT"), ("job2", "AREA_CONSTRAINT")]
+    );
+}
+
+#[test]
+fn can_detect_unreachable_jobs() {
+    let mut vehicle = create_vehicle_type(&["v1"], 10, None);
+    vehicle.shifts[0].end = Some(ShiftEnd {
+        earliest: None,
+        latest: "2020-07-04T19:30:00.00Z".to_string(),
+        location: Location::Coordinate { lat: 0., lng: 0. },
+    });
+    vehicle.shifts[0].start.earliest = "2020-07-04T18:00:00.00Z".to_string();
+    let mut far_job = create_job("job2", 0.5, 0.5, 1);
+    far_job.pickups.as_mut().unwrap()[0].places[0].duration = 3600.;
+    let problem = create_problem(vec![create_job("job1", 0.001, 0.001, 1), far_job], vec![vehicle]);
+
+    let analysis = analyze(problem);
+
+    assert_eq!(analysis.unreachable_jobs, vec!["job2".to_string()]);
+}
+
+#[test]
+fn can_return_validation_errors() {
+    let problem = create_problem(
+        vec![create_job("job1", 0.1, 0.1, 1), create_job("job1", 0.2, 0.2, 1)],
+        vec![create_vehicle_type(&["v1"], 10, None)],
+    );
+
+    let result = analyze_pragmatic_problem(problem, None);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_report_time_parse_errors() {
+    let mut job = create_job("job1", 0.1, 0.1, 1);
+    let mut task = job.pickups.take().unwrap().remove(0);
+    task.demand = None;
+    task.places[0].times = Some(vec![vec!["not a time".to_string(), "2020-07-04T19:30:00.00Z".to_string()]]);
+    job.services = Some(vec![task]);
+    let problem = create_problem(vec![job], vec![create_vehicle_type(&["v1"], 10, None)]);
+
+    let result = analyze_pragmatic_problem(problem, None);
+
+    assert_eq!(
+        result.err().map(|errors| errors.iter().map(|err| err.code.clone()).collect::<Vec<_>>()),
+        Some(vec!["E1103".to_string()])
+    );
+}
@@ -0,0 +1,220 @@
+use super::*;
+use vrp_pragmatic::format::solution::{Schedule, Statistic, Tour, UnassignedJob};
+use vrp_pragmatic::format::Location;
+
+type TourData<'a> = (&'a str, Vec<(&'a str, &'a str, &'a str)>);
+
+fn create_solution(cost: f64, tours: Vec<TourData>, unassigned: Vec<&str>) -> Solution {
+    Solution {
+        statistic: Statistic { cost, ..Statistic::default() },
+        tours: tours
+            .into_iter()
+            .map(|(vehicle_id, activities)| Tour {
+                vehicle_id: vehicle_id.to_string(),
+                type_id: "vehicle".to_string(),
+                shift_index: 0,
+                stops: activities
+                    .into_iter()
+                    .map(|(job_id, activity_type, time)| Stop {
+                        location: Location::new_coordinate(0., 0.),
+                        time: Schedule { arrival: time.to_string(), departure: time.to_string() },
+                        distance: 0,
+                        load: vec![0],
+                        activities: vec![Activity {
+                            job_id: job_id.to_string(),
+                            activity_type: activity_type.to_string(),
+                            location: None,
+                            time: None,
+                            job_tag: None,
+                        }],
+                    })
+                    .collect(),
+                statistic: Statistic::default(),
+            })
+            .collect(),
+        unassigned: if unassigned.is_empty() {
+            None
+        } else {
+            Some(
+                unassigned
+                    .into_iter()
+                    .map(|job_id| UnassignedJob { job_id: job_id.to_string(), reasons: vec![] })
+                    .collect(),
+            )
+        },
+        violations: None,
+        extras: None,
+    }
+}
+
+fn get_job_moves(moves: &[JobMove]) -> Vec<(&str, Option<&str>)> {
+    moves.iter().map(|job_move| (job_move.job_id.as_str(), job_move.vehicle_id.as_deref())).collect()
+}
+
+#[test]
+fn can_detect_no_changes() {
+    let tours = vec![(
+        "v1",
+        vec![("departure", "departure", "2020-07-04T09:00:00Z"), ("job1", "delivery", "2020-07-04T10:00:00Z")],
+    )];
+    let base = create_solution(10., tours.clone(), vec!["job2"]);
+    let actual = create_solution(10., tours, vec!["job2"]);
+
+    let diff = diff_solutions(&base, &actual, 0).unwrap();
+
+    assert!(diff.tours.is_empty());
+    assert!(diff.added_unassigned.is_empty());
+    assert!(diff.removed_unassigned.is_empty());
+    assert_eq!(diff.statistic.cost, 0.);
+    assert_eq!(diff.statistic.tours, 0);
+}
+
+#[test]
+fn can_detect_jobs_moved_between_tours() {
+    let base = create_solution(
+        10.,
+        vec![
+            ("v1", vec![("job1", "delivery", "2020-07-04T10:00:00Z"), ("job2", "delivery", "2020-07-04T11:00:00Z")]),
+            ("v2", vec![("job3", "delivery", "2020-07-04T10:00:00Z")]),
+        ],
+        vec![],
+    );
+    let actual = create_solution(
+        12.,
+        vec![
+            ("v1", vec![("job1", "delivery", "2020-07-04T10:00:00Z")]),
+            ("v2", vec![("job3", "delivery", "2020-07-04T10:00:00Z"), ("job2", "delivery", "2020-07-04T11:00:00Z")]),
+        ],
+        vec![],
+    );
+
+    let diff = diff_solutions(&base, &actual, 0).unwrap();
+
+    assert_eq!(diff.tours.len(), 2);
+    assert_eq!(diff.tours[0].vehicle_id, "v1");
+    assert!(diff.tours[0].added_jobs.is_empty());
+    assert_eq!(get_job_moves(&diff.tours[0].removed_jobs), vec![("job2", Some("v2"))]);
+    assert_eq!(diff.tours[1].vehicle_id, "v2");
+    assert_eq!(get_job_moves(&diff.tours[1].added_jobs), vec![("job2", Some("v1"))]);
+    assert!(diff.tours[1].removed_jobs.is_empty());
+    assert!(!diff.tours[0].order_changed && !diff.tours[1].order_changed);
+    assert_eq!(diff.statistic.cost, 2.);
+}
+
+#[test]
+fn can_detect_order_change() {
+    let base = create_solution(
+        10.,
+        vec![("v1", vec![("job1", "delivery", "2020-07-04T10:00:00Z"), ("job2", "delivery", "2020-07-04T10:00:00Z")])],
+        vec![],
+    );
+    let actual = create_solution(
+        10.,
+        vec![("v1", vec![("job2", "delivery", "2020-07-04T10:00:00Z"), ("job1", "delivery", "2020-07-04T10:00:00Z")])],
+        vec![],
+    );
+
+    let diff = diff_solutions(&base, &actual, 0).unwrap();
+
+    assert_eq!(diff.tours.len(), 1);
+    assert!(diff.tours[0].order_changed);
+    assert!(diff.tours[0].added_jobs.is_empty());
+    assert!(diff.tours[0].removed_jobs.is_empty());
+    assert!(diff.tours[0].time_shifts.is_empty());
+}
+
+parameterized_test! {can_detect_time_shifts, (time_threshold, expected), {
+    can_detect_time_shifts_impl(time_threshold, expected);
+}}
+
+can_detect_time_shifts! {
+    case01_below_threshold: (600, vec![]),
+    case02_above_threshold: (300, vec![("job1", 600)]),
+    case03_zero_threshold: (0, vec![("departure", -60), ("job1", 600)]),
+}
+
+fn can_detect_time_shifts_impl(time_threshold: i64, expected: Vec<(&str, i64)>) {
+    let base = create_solution(
+        10.,
+        vec![(
+            "v1",
+            vec![("departure", "departure", "2020-07-04T09:00:00Z"), ("job1", "delivery", "2020-07-04T10:00:00Z")],
+        )],
+        vec![],
+    );
+    let actual = create_solution(
+        10.,
+        vec![(
+            "v1",
+            vec![("departure", "departure", "2020-07-04T08:59:00Z"), ("job1", "delivery", "2020-07-04T10:10:00Z")],
+        )],
+        vec![],
+    );
+
+    let diff = diff_solutions(&base, &actual, time_threshold).unwrap();
+
+    let time_shifts = diff
+        .tours
+        .iter()
+        .flat_map(|tour| tour.time_shifts.iter())
+        .map(|time_shift| (time_shift.job_id.as_str(), time_shift.shift))
+        .collect::<Vec<_>>();
+    assert_eq!(time_shifts, expected);
+}
+
+#[test]
+fn can_detect_time_shifts_of_multiple_breaks_in_one_tour() {
+    let create_breaks_solution = |second_break_time: &str| {
+        create_solution(
+            10.,
+            vec![(
+                "v1",
+                vec![
+                    ("break", "break", "2020-07-04T11:00:00Z"),
+                    ("job1", "delivery", "2020-07-04T12:00:00Z"),
+                    ("break", "break", second_break_time),
+                ],
+            )],
+            vec![],
+        )
+    };
+    let base = create_breaks_solution("2020-07-04T14:00:00Z");
+    let actual = create_breaks_solution("2020-07-04T14:30:00Z");
+
+    let diff = diff_solutions(&base, &actual, 0).unwrap();
+
+    assert_eq!(diff.tours.len(), 1);
+    assert!(!diff.tours[0].order_changed);
+    assert!(diff.tours[0].added_jobs.is_empty());
+    assert!(diff.tours[0].removed_jobs.is_empty());
+    let time_shifts = diff.tours[0]
+        .time_shifts
+        .iter()
+        .map(|time_shift| (time_shift.job_id.as_str(), time_shift.before.as_str(), time_shift.shift))
+        .collect::<Vec<_>>();
+    assert_eq!(time_shifts, vec![("break", "2020-07-04T14:00:00Z", 1800)]);
+}
+
+#[test]
+fn can_detect_unassigned_jobs_changes() {
+    let base = create_solution(10., vec![("v1", vec![("job1", "delivery", "2020-07-04T10:00:00Z")])], vec!["job2"]);
+    let actual = create_solution(
+        10.,
+        vec![
+            ("v1", vec![("job2", "delivery", "2020-07-04T10:00:00Z")]),
+            ("v2", vec![("job3", "pickup", "2020-07-04T10:00:00Z")]),
+        ],
+        vec!["job1"],
+    );
+
+    let diff = diff_solutions(&base, &actual, 0).unwrap();
+
+    assert_eq!(diff.added_unassigned, vec!["job1".to_string()]);
+    assert_eq!(diff.removed_unassigned, vec!["job2".to_string()]);
+    assert_eq!(diff.tours.len(), 2);
+    assert_eq!(get_job_moves(&diff.tours[0].added_jobs), vec![("job2", None)]);
+    assert_eq!(get_job_moves(&diff.tours[0].removed_jobs), vec![("job1", None)]);
+    assert_eq!(get_job_moves(&diff.tours[1].added_jobs), vec![("job3", None)]);
+    assert_eq!(diff.statistic.tours, 1);
+    assert_eq!(diff.statistic.unassigned, 0);
+}
@@ -13,6 +13,7 @@ fn can_generate_fleet_of_specific_size() {
                 profile_type: "car_type".to_string(),
                 speed: None,
             }],
+            states: None,
         },
         objectives: None,
     };
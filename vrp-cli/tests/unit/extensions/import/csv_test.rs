@@ -17,11 +17,133 @@ vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,10,car
 vehicle2,52.4959,13.3539,50,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,20,truck
 ";
 
-    let result = read_csv_problem(BufReader::new(jobs_csv.as_bytes()), BufReader::new(vehicles_csv.as_bytes()))
-        .expect("cannot read csv");
+    let result = read_csv_problem(
+        BufReader::new(jobs_csv.as_bytes()),
+        BufReader::new(vehicles_csv.as_bytes()),
+        None::<BufReader<&[u8]>>,
+    )
+    .expect("cannot read csv");
 
     assert_eq!(result.plan.jobs.len(), 3);
     assert_eq!(result.fleet.vehicles.len(), 2);
+    assert_eq!(result.fleet.vehicles[0].vehicle_ids.len(), 10);
+    assert_eq!(result.fleet.vehicles[1].vehicle_ids.len(), 20);
+}
+
+#[test]
+fn can_read_csv_problem_with_extended_features() {
+    let jobs_csv = r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END,SKILLS
+job1,52.52599,13.45413,2;1,5,2020-07-04T08:00:00Z;2020-07-04T14:00:00Z,2020-07-04T10:00:00Z;2020-07-04T16:00:00Z,fridge
+job2,52.5225,13.4095,1;3,3,,,fridge;lift
+job2,52.5165,13.3808,-1;-3,3,,,
+job3,52.5316,13.3884,,5,,,
+";
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE,SKILLS,FIXED_COST,DISTANCE_COST,TIME_COST,MAX_DISTANCE,SHIFT_TIME,TOUR_SIZE
+vehicle1,52.4664,13.4023,40;10,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,2,car,fridge;lift,10,0.001,0.002,100000,480,
+";
+    let breaks_csv = r"
+TYPE_ID,TW_START,TW_END,DURATION,LAT,LNG
+vehicle1,2020-07-04T12:00:00Z,2020-07-04T14:00:00Z,30,,
+vehicle1,2020-07-04T16:00:00Z,2020-07-04T18:00:00Z,15,52.5,13.4
+";
+
+    let result = read_csv_problem(
+        BufReader::new(jobs_csv.as_bytes()),
+        BufReader::new(vehicles_csv.as_bytes()),
+        Some(BufReader::new(breaks_csv.as_bytes())),
+    )
+    .expect("cannot read csv");
+
+    let get_job = |id: &str| result.plan.jobs.iter().find(|job| job.id == id).unwrap();
+
+    let job1 = get_job("job1");
+    let job1_task = &job1.pickups.as_ref().unwrap()[0];
+    assert_eq!(job1_task.demand, Some(vec![2, 1]));
+    assert_eq!(job1_task.places[0].times.as_ref().unwrap().len(), 2);
+    assert_eq!(job1.skills.as_ref().unwrap().all_of, Some(vec!["fridge".to_string()]));
+
+    let job2 = get_job("job2");
+    assert_eq!(job2.pickups.as_ref().unwrap()[0].demand, Some(vec![1, 3]));
+    assert_eq!(job2.deliveries.as_ref().unwrap()[0].demand, Some(vec![1, 3]));
+    assert_eq!(job2.skills.as_ref().unwrap().all_of, Some(vec!["fridge".to_string(), "lift".to_string()]));
+
+    let job3 = get_job("job3");
+    assert!(job3.services.as_ref().unwrap()[0].demand.is_none());
+    assert!(job3.skills.is_none());
+
+    let vehicle = &result.fleet.vehicles[0];
+    assert_eq!(vehicle.capacity, vec![40, 10]);
+    assert_eq!(vehicle.skills, Some(vec!["fridge".to_string(), "lift".to_string()]));
+    assert_eq!(vehicle.costs.fixed, Some(10.));
+    assert_eq!(vehicle.costs.distance, 0.001);
+    assert_eq!(vehicle.costs.time, 0.002);
+    let limits = vehicle.limits.as_ref().unwrap();
+    assert_eq!(limits.max_distance, Some(100000.));
+    assert_eq!(limits.shift_time, Some(480. * 60.));
+    assert_eq!(limits.tour_size, None);
+    let breaks = vehicle.shifts[0].breaks.as_ref().unwrap();
+    assert_eq!(breaks.len(), 2);
+    assert_eq!(breaks[0].duration, 1800.);
+    assert!(breaks[0].locations.is_none());
+    assert_eq!(breaks[1].locations.as_ref().unwrap().len(), 1);
+}
+
+parameterized_test! {can_detect_invalid_extended_features, (jobs_csv, breaks_csv, expected), {
+    can_detect_invalid_extended_features_impl(jobs_csv, breaks_csv, expected);
+}}
+
+can_detect_invalid_extended_features! {
+    case01: ("ID,LAT,LNG,DEMAND,DURATION\njob1,52.5,13.4,1;-1,5\n", "TYPE_ID,TW_START,TW_END,DURATION\n",
+             ("jobs", "job 'job1' has demand with mixed signs")),
+    case02: ("ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END\njob1,52.5,13.4,1,5,2020-07-04T08:00:00Z,\n",
+             "TYPE_ID,TW_START,TW_END,DURATION\n",
+             ("jobs", "time window start and end mismatch: 1 vs 0")),
+    case03: ("ID,LAT,LNG,DEMAND,DURATION\njob1,52.5,13.4,a,5\n", "TYPE_ID,TW_START,TW_END,DURATION\n",
+             ("jobs", "cannot parse value: 'a'")),
+    case04: ("ID,LAT,LNG,DEMAND,DURATION\njob1,52.5,13.4,1,5\n",
+             "TYPE_ID,TW_START,TW_END,DURATION\nvehicle2,2020-07-04T12:00:00Z,2020-07-04T14:00:00Z,30\n",
+             ("vehicles", "unknown vehicle type id in breaks: 'vehicle2'")),
+}
+
+fn can_detect_invalid_extended_features_impl(jobs_csv: &str, breaks_csv: &str, expected: (&str, &str)) {
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE
+vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,10,car
+";
+
+    let result = read_csv_problem(
+        BufReader::new(jobs_csv.as_bytes()),
+        BufReader::new(vehicles_csv.as_bytes()),
+        Some(BufReader::new(breaks_csv.as_bytes())),
+    )
+    .err()
+    .expect("Should return error!");
+
+    assert_eq!(result.cause, format!("cannot read {}", expected.0));
+    assert_eq!(result.details, Some(expected.1.to_string()));
+}
+
+#[test]
+fn can_create_vehicle_ids_for_each_vehicle_of_type() {
+    let jobs_csv = r"
+ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END
+job1,52.52599,13.45413,2,5,,
+";
+    let vehicles_csv = r"
+ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE
+vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,2,car
+";
+
+    let result = read_csv_problem(
+        BufReader::new(jobs_csv.as_bytes()),
+        BufReader::new(vehicles_csv.as_bytes()),
+        None::<BufReader<&[u8]>>,
+    )
+    .expect("cannot read csv");
+
+    assert_eq!(result.fleet.vehicles[0].vehicle_ids, vec!["vehicle1_1".to_string(), "vehicle1_2".to_string()]);
 }
 
 #[test]
@@ -32,9 +154,13 @@ job2,52.5225,13.4095,1,3,,
 job2,52.5165,13.3808,3,,
 ";
 
-    let result = read_csv_problem(BufReader::new(invalid_jobs.as_bytes()), BufReader::new("".as_bytes()))
-        .err()
-        .expect("Should return error!");
+    let result = read_csv_problem(
+        BufReader::new(invalid_jobs.as_bytes()),
+        BufReader::new("".as_bytes()),
+        None::<BufReader<&[u8]>>,
+    )
+    .err()
+    .expect("Should return error!");
 
     assert_eq!(result.code, "E0000");
     assert_eq!(result.cause, "cannot read jobs");
@@ -57,7 +183,7 @@ can_handle_invalid_input_amount! {
         case01: None,
         case02: Some(0),
         case03: Some(1),
-        case04: Some(3),
+        case04: Some(4),
 }
 
 fn can_handle_invalid_input_amount_impl(input_size: Option<usize>) {
@@ -71,5 +197,5 @@ job1,52.5225,13.4095,1,3,,
             .err()
             .expect("Should return error!");
 
-    assert_eq!(result, "csv format expects two files with jobs and vehicles as an input");
+    assert_eq!(result, "csv format expects two files with jobs and vehicles and optional file with breaks as an input");
 }
@@ -299,6 +299,7 @@ fn can_write_hre_problem() {
                 }),
             }],
             profiles: vec![Profile { name: "normal_car".to_string(), profile_type: "car".to_string(), speed: None }],
+            states: None,
         },
         objectives: None,
     };
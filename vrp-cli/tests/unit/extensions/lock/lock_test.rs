@@ -0,0 +1,123 @@
+use super::*;
+use std::fs::File;
+use std::io::BufReader;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::deserialize_solution;
+
+fn read_simple_problem_and_solution() -> (Problem, Solution) {
+    let problem = File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let solution = File::open("../examples/data/pragmatic/simple.basic.solution.json").unwrap();
+
+    (deserialize_problem(BufReader::new(problem)).unwrap(), deserialize_solution(BufReader::new(solution)).unwrap())
+}
+
+fn create_options(relation_type: RelationType, time_range: Option<(&str, &str)>) -> LockOptions {
+    LockOptions {
+        relation_type,
+        vehicle_ids: None,
+        time_range: time_range.map(|(start, end)| (start.to_string(), end.to_string())),
+    }
+}
+
+fn get_relation_jobs(problem: &Problem) -> Vec<Vec<String>> {
+    problem.plan.relations.iter().flatten().map(|relation| relation.jobs.clone()).collect()
+}
+
+parameterized_test! {can_lock_solution_tours, (relation_type, time_range, expected), {
+    can_lock_solution_tours_impl(relation_type, time_range, expected);
+}}
+
+can_lock_solution_tours! {
+    case01: (RelationType::Any, None, vec![vec!["job1", "job2", "job3", "job3"]]),
+    case02: (RelationType::Strict, None, vec![vec!["departure", "job1", "job2", "job3", "job3", "arrival"]]),
+    case03: (RelationType::Sequence, Some(("2019-07-04T09:00:00Z", "2019-07-04T10:30:00Z")),
+             vec![vec!["departure", "job1", "job2"]]),
+    case04: (RelationType::Any, Some(("2019-07-04T10:20:00Z", "2019-07-04T11:00:00Z")), vec![vec!["job2", "job3", "job3"]]),
+    case05: (RelationType::Strict, Some(("2019-07-04T11:00:00Z", "2019-07-04T12:00:00Z")), vec![]),
+}
+
+fn can_lock_solution_tours_impl(
+    relation_type: RelationType,
+    time_range: Option<(&str, &str)>,
+    expected: Vec<Vec<&str>>,
+) {
+    let (problem, solution) = read_simple_problem_and_solution();
+
+    let problem = create_locked_problem(problem, &solution, &create_options(relation_type, time_range)).unwrap();
+
+    assert_eq!(get_relation_jobs(&problem), expected);
+    assert!(problem
+        .plan
+        .relations
+        .iter()
+        .flatten()
+        .all(|relation| relation.vehicle_id == "vehicle_1" && relation.shift_index == Some(0)));
+}
+
+#[test]
+fn can_skip_jobs_from_existing_relations_and_unselected_vehicles() {
+    let (mut problem, solution) = read_simple_problem_and_solution();
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Any,
+        jobs: vec!["job1".to_string()],
+        vehicle_id: "vehicle_1".to_string(),
+        shift_index: None,
+    }]);
+
+    let locked =
+        create_locked_problem(problem.clone(), &solution, &create_options(RelationType::Strict, None)).unwrap();
+    assert_eq!(get_relation_jobs(&locked), vec![vec!["job1"], vec!["departure", "job2", "job3", "job3", "arrival"]]);
+
+    let options =
+        LockOptions { vehicle_ids: Some(vec!["vehicle_2".to_string()]), ..create_options(RelationType::Any, None) };
+    let locked = create_locked_problem(problem, &solution, &options).unwrap();
+    assert_eq!(get_relation_jobs(&locked), vec![vec!["job1"]]);
+}
+
+#[test]
+fn can_downgrade_strict_relation_when_middle_job_is_skipped() {
+    let (mut problem, solution) = read_simple_problem_and_solution();
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Any,
+        jobs: vec!["job2".to_string()],
+        vehicle_id: "vehicle_1".to_string(),
+        shift_index: None,
+    }]);
+
+    let locked = create_locked_problem(problem, &solution, &create_options(RelationType::Strict, None)).unwrap();
+
+    assert_eq!(get_relation_jobs(&locked), vec![vec!["job2"], vec!["departure", "job1", "job3", "job3", "arrival"]]);
+    assert!(matches!(locked.plan.relations.unwrap()[1].type_field, RelationType::Sequence));
+}
+
+#[test]
+fn can_keep_strict_relation_when_tour_part_is_contiguous() {
+    let (problem, solution) = read_simple_problem_and_solution();
+    let options = create_options(RelationType::Strict, Some(("2019-07-04T09:00:00Z", "2019-07-04T10:30:00Z")));
+
+    let locked = create_locked_problem(problem, &solution, &options).unwrap();
+
+    assert_eq!(get_relation_jobs(&locked), vec![vec!["departure", "job1", "job2"]]);
+    assert!(matches!(locked.plan.relations.unwrap()[0].type_field, RelationType::Strict));
+}
+
+parameterized_test! {can_detect_invalid_input, (job_id, vehicle_id, time_range, expected), {
+    can_detect_invalid_input_impl(job_id, vehicle_id, time_range, expected);
+}}
+
+can_detect_invalid_input! {
+    case01: ("job4", "vehicle_1", None, "cannot find job with id 'job4' in the problem"),
+    case02: ("job1", "vehicle_2", None, "cannot find vehicle with id 'vehicle_2' in the problem"),
+    case03: ("job1", "vehicle_1", Some(("2019-07-04T11:00:00Z", "2019-07-04T10:00:00Z")),
+             "time range start is later than its end"),
+}
+
+fn can_detect_invalid_input_impl(job_id: &str, vehicle_id: &str, time_range: Option<(&str, &str)>, expected: &str) {
+    let (problem, mut solution) = read_simple_problem_and_solution();
+    solution.tours[0].vehicle_id = vehicle_id.to_string();
+    solution.tours[0].stops[1].activities[0].job_id = job_id.to_string();
+
+    let result = create_locked_problem(problem, &solution, &create_options(RelationType::Any, time_range));
+
+    assert_eq!(result.err(), Some(expected.to_string()));
+}
@@ -0,0 +1,73 @@
+use super::*;
+use std::io::BufReader;
+
+#[test]
+fn can_read_request_with_body() {
+    let data = "POST /solve?debug=true HTTP/1.1\r\nHost: localhost\r\ncontent-length: 4\r\n\r\n{}\r\n";
+
+    let request = read_request(&mut BufReader::new(data.as_bytes())).expect("cannot read request");
+
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/solve");
+    assert_eq!(request.body, b"{}\r\n".to_vec());
+}
+
+#[test]
+fn can_read_request_without_body() {
+    let data = "get /jobs/1 HTTP/1.1\r\nHost: localhost\r\n\r\n";
+
+    let request = read_request(&mut BufReader::new(data.as_bytes())).expect("cannot read request");
+
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path, "/jobs/1");
+    assert!(request.body.is_empty());
+}
+
+parameterized_test! {can_reject_invalid_request, data, {
+    can_reject_invalid_request_impl(data);
+}}
+
+can_reject_invalid_request! {
+    case01: "GET /jobs/1\r\n\r\n",
+    case02: "\r\n",
+    case03: "POST /solve HTTP/1.1\r\nContent-Length: abc\r\n\r\n",
+    case04: "POST /solve HTTP/1.1\r\nContent-Length: 10\r\n\r\n{}",
+    case05: "POST /solve HTTP/1.1\r\nContent-Length: 33554433\r\n\r\n{}",
+}
+
+fn can_reject_invalid_request_impl(data: &str) {
+    let result = read_request(&mut BufReader::new(data.as_bytes()));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_write_response() {
+    let mut buffer = Vec::new();
+
+    write_response(&mut buffer, &Response::error(404, "not found")).expect("cannot write response");
+
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "HTTP/1.1 404 Not Found\r\nContent-Type: application/json\r\nContent-Length: 21\r\n\
+         Connection: close\r\n\r\n{\"error\":\"not found\"}"
+    );
+}
+
+#[test]
+fn can_reject_too_long_header_line() {
+    let data = format!("GET /jobs/1 HTTP/1.1\r\nHost: {}\r\n\r\n", "a".repeat(10 * 1024));
+
+    let result = read_request(&mut BufReader::new(data.as_bytes()));
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn can_reject_too_many_header_lines() {
+    let data = format!("GET /jobs/1 HTTP/1.1\r\n{}\r\n", "X-Header: value\r\n".repeat(100));
+
+    let result = read_request(&mut BufReader::new(data.as_bytes()));
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,97 @@
+use super::*;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use vrp_core::construction::Quota;
+
+fn wait_for_status(pool: &JobPool, id: usize, expected: JobStatus) {
+    for _ in 0..1000 {
+        if pool.status(id) == Some(expected) {
+            return;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    panic!("job {} has not reached {:?} status, actual is {:?}", id, expected, pool.status(id));
+}
+
+fn create_blocked_task() -> (Task, std::sync::mpsc::Sender<()>) {
+    let (sender, receiver) = channel::<()>();
+    let task: Task = Box::new(move |quota| {
+        while !quota.is_reached() && receiver.try_recv().is_err() {
+            thread::sleep(Duration::from_millis(1));
+        }
+        Ok("done".to_string())
+    });
+
+    (task, sender)
+}
+
+#[test]
+fn can_run_submitted_jobs() {
+    let pool = JobPool::new(2, 4, Duration::from_secs(60));
+
+    let success = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    let failure = pool.submit(Box::new(|_| Err("error".to_string()))).unwrap();
+
+    wait_for_status(&pool, success, JobStatus::Finished);
+    wait_for_status(&pool, failure, JobStatus::Failed);
+    assert_eq!(pool.result(success), Some((JobStatus::Finished, Some(Ok("solution".to_string())))));
+    assert_eq!(pool.result(failure), Some((JobStatus::Failed, Some(Err("error".to_string())))));
+    assert_eq!(pool.status(failure + 1), None);
+}
+
+#[test]
+fn can_cancel_queued_and_running_jobs() {
+    let pool = JobPool::new(1, 4, Duration::from_secs(60));
+    let (blocked_task, _unblock) = create_blocked_task();
+    let running = pool.submit(blocked_task).unwrap();
+    wait_for_status(&pool, running, JobStatus::Running);
+    let queued = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+
+    assert_eq!(pool.cancel(queued), Some(JobStatus::Queued));
+    assert_eq!(pool.cancel(running), Some(JobStatus::Running));
+
+    let next = pool.submit(Box::new(|_| Ok("next".to_string()))).unwrap();
+    wait_for_status(&pool, next, JobStatus::Finished);
+    assert_eq!(pool.result(queued), Some((JobStatus::Cancelled, None)));
+    assert_eq!(pool.result(running), Some((JobStatus::Cancelled, None)));
+}
+
+#[test]
+fn can_remove_processed_job_on_cancel() {
+    let pool = JobPool::new(1, 1, Duration::from_secs(60));
+    let id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    wait_for_status(&pool, id, JobStatus::Finished);
+
+    assert_eq!(pool.cancel(id), Some(JobStatus::Finished));
+    assert_eq!(pool.status(id), None);
+    assert_eq!(pool.cancel(id), None);
+}
+
+#[test]
+fn can_reject_job_when_queue_is_full() {
+    let pool = JobPool::new(1, 1, Duration::from_secs(60));
+    let (blocked_task, unblock) = create_blocked_task();
+    let running = pool.submit(blocked_task).unwrap();
+    wait_for_status(&pool, running, JobStatus::Running);
+
+    let queued = pool.submit(Box::new(|_| Ok("queued".to_string())));
+    let rejected = pool.submit(Box::new(|_| Ok("rejected".to_string())));
+
+    assert!(queued.is_ok());
+    assert_eq!(rejected, Err("job queue is full".to_string()));
+    unblock.send(()).unwrap();
+    wait_for_status(&pool, queued.unwrap(), JobStatus::Finished);
+}
+
+#[test]
+fn can_remove_processed_jobs_after_ttl() {
+    let pool = JobPool::new(1, 1, Duration::from_millis(200));
+    let id = pool.submit(Box::new(|_| Ok("solution".to_string()))).unwrap();
+    wait_for_status(&pool, id, JobStatus::Finished);
+
+    thread::sleep(Duration::from_millis(300));
+
+    assert_eq!(pool.status(id), None);
+    assert_eq!(pool.result(id), None);
+}
@@ -0,0 +1,195 @@
+use super::*;
+use std::io::{Read, Write};
+use std::time::Duration;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+fn read_json(path: &str) -> serde_json::Value {
+    serde_json::from_str(std::fs::read_to_string(path).unwrap().as_str()).unwrap()
+}
+
+fn create_config(connections: usize, timeout: Duration) -> ServerConfig {
+    ServerConfig { workers: 1, queue_size: 4, connections, timeout, job_ttl: Duration::from_secs(60) }
+}
+
+fn create_service() -> Service {
+    Service::new(&create_config(1, Duration::from_secs(1)))
+}
+
+fn start_server(config: ServerConfig) -> std::net::SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    thread::spawn(move || serve(listener, Arc::new(Service::new(&config)), &config));
+
+    address
+}
+
+fn create_request(method: &str, path: &str, body: Option<serde_json::Value>) -> Request {
+    Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        body: body.map(|body| body.to_string().into_bytes()).unwrap_or_default(),
+    }
+}
+
+fn parse_response(response: &Response) -> serde_json::Value {
+    serde_json::from_str(response.body.as_str()).unwrap()
+}
+
+fn wait_for_result(service: &Service, id: u64) -> Response {
+    for _ in 0..1000 {
+        let response = service.handle(&create_request("GET", format!("/jobs/{}/result", id).as_str(), None));
+        if response.status != 409 {
+            return response;
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    panic!("job {} is not processed", id);
+}
+
+parameterized_test! {can_handle_unknown_requests, (method, path, expected_status), {
+    can_handle_unknown_requests_impl(method, path, expected_status);
+}}
+
+can_handle_unknown_requests! {
+    case01: ("GET", "/", 404),
+    case02: ("GET", "/solve", 404),
+    case03: ("PUT", "/jobs/1", 404),
+    case04: ("GET", "/jobs/1", 404),
+    case05: ("GET", "/jobs/abc", 404),
+    case06: ("GET", "/jobs/1/result", 404),
+    case07: ("DELETE", "/jobs/1", 404),
+    case08: ("POST", "/solve", 400),
+    case09: ("POST", "/check", 400),
+}
+
+fn can_handle_unknown_requests_impl(method: &str, path: &str, expected_status: u16) {
+    let response = create_service().handle(&create_request(method, path, None));
+
+    assert_eq!(response.status, expected_status);
+    assert!(parse_response(&response)["error"].is_string());
+}
+
+#[test]
+fn can_solve_problem_asynchronously() {
+    let service = create_service();
+    let body = serde_json::json!({
+        "problem": read_json(PRAGMATIC_PROBLEM_PATH),
+        "matrices": [read_json(PRAGMATIC_MATRIX_PATH)],
+        "config": { "termination": { "maxGenerations": 10 } }
+    });
+
+    let response = service.handle(&create_request("POST", "/solve", Some(body)));
+    assert_eq!(response.status, 202);
+    let id = parse_response(&response)["id"].as_u64().unwrap();
+
+    let response = wait_for_result(&service, id);
+    assert_eq!(response.status, 200);
+    assert_eq!(parse_response(&response)["tours"].as_array().unwrap().len(), 1);
+
+    let response = service.handle(&create_request("GET", format!("/jobs/{}", id).as_str(), None));
+    assert_eq!(response.status, 200);
+    assert_eq!(parse_response(&response)["status"], "finished");
+
+    let response = service.handle(&create_request("DELETE", format!("/jobs/{}", id).as_str(), None));
+    assert_eq!(response.status, 200);
+    assert_eq!(service.handle(&create_request("GET", format!("/jobs/{}", id).as_str(), None)).status, 404);
+}
+
+#[test]
+fn can_reject_invalid_problem_on_solve() {
+    let mut problem = read_json(PRAGMATIC_PROBLEM_PATH);
+    problem["plan"]["jobs"][1]["id"] = problem["plan"]["jobs"][0]["id"].clone();
+
+    let response =
+        create_service().handle(&create_request("POST", "/solve", Some(serde_json::json!({ "problem": problem }))));
+
+    assert_eq!(response.status, 400);
+    assert_eq!(parse_response(&response)[0]["code"], "E1100");
+}
+
+#[test]
+fn can_get_locations() {
+    let response =
+        create_service().handle(&create_request("POST", "/locations", Some(read_json(PRAGMATIC_PROBLEM_PATH))));
+
+    assert_eq!(response.status, 200);
+    assert!(!parse_response(&response).as_array().unwrap().is_empty());
+}
+
+#[test]
+fn can_check_solution() {
+    let body = serde_json::json!({
+        "problem": read_json(PRAGMATIC_PROBLEM_PATH),
+        "solution": read_json(PRAGMATIC_SOLUTION_PATH),
+        "matrices": [read_json(PRAGMATIC_MATRIX_PATH)],
+    });
+
+    let response = create_service().handle(&create_request("POST", "/check", Some(body)));
+
+    assert_eq!(response.status, 200);
+    assert!(parse_response(&response).as_array().unwrap().is_empty());
+}
+
+#[test]
+fn can_import_csv_problem() {
+    let jobs = "ID,LAT,LNG,DEMAND,DURATION,TW_START,TW_END\njob1,52.52599,13.45413,2,5,,\n";
+    let vehicles = "ID,LAT,LNG,CAPACITY,TW_START,TW_END,AMOUNT,PROFILE\n\
+                    vehicle1,52.4664,13.4023,40,2020-07-04T08:00:00Z,2020-07-04T20:00:00Z,2,car\n";
+    let body = serde_json::json!({ "inputs": [jobs, vehicles] });
+
+    let service = create_service();
+    let response = service.handle(&create_request("POST", "/import/csv", Some(body)));
+
+    assert_eq!(response.status, 200);
+    assert_eq!(parse_response(&response)["plan"]["jobs"].as_array().unwrap().len(), 1);
+    assert_eq!(
+        service.handle(&create_request("POST", "/import/unknown", Some(serde_json::json!({ "inputs": [] })))).status,
+        400
+    );
+}
+
+#[test]
+fn can_serve_requests_over_tcp() {
+    let address = start_server(create_config(2, Duration::from_secs(5)));
+
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(b"GET /jobs/42 HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    assert!(response.ends_with("{\"error\":\"unknown job id: 42\"}"));
+}
+
+#[test]
+fn can_close_idle_connection_after_timeout() {
+    let address = start_server(create_config(1, Duration::from_millis(200)));
+
+    let mut stream = TcpStream::connect(address).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 400 Bad Request\r\n"));
+}
+
+#[test]
+fn can_reject_connection_when_handlers_are_busy() {
+    let address = start_server(create_config(1, Duration::from_secs(5)));
+
+    // NOTE the first connection occupies the only handler, the second one waits in the queue
+    let _busy = TcpStream::connect(address).unwrap();
+    thread::sleep(Duration::from_millis(100));
+    let _queued = TcpStream::connect(address).unwrap();
+    thread::sleep(Duration::from_millis(100));
+
+    let mut stream = TcpStream::connect(address).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    assert!(response.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+    assert!(response.ends_with("{\"error\":\"too many connections\"}"));
+}
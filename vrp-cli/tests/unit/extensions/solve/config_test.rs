@@ -51,10 +51,15 @@ fn can_read_config() {
         PopulationType::Elitism { .. } => unreachable!(),
     }
 
+    let islands = evolution_config.islands.expect("no islands config");
+    assert_eq!(islands.populations.len(), 2);
+    assert_eq!(islands.migration_interval, Some(100));
+    assert_eq!(islands.migration_size, Some(1));
+
     let mutation_config = config.mutation.expect("cannot get mutation");
     match mutation_config {
         MutationType::Composite { inners, .. } => {
-            assert_eq!(inners.len(), 3);
+            assert_eq!(inners.len(), 4);
             match inners.first().unwrap() {
                 MutationType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(*probability, 0.05);
@@ -73,6 +78,15 @@ fn can_read_config() {
                 _ => unreachable!(),
             }
 
+            match inners.get(2).unwrap() {
+                MutationType::Crossover { probability, crossovers, recreates } => {
+                    assert_eq!(*probability, 0.05);
+                    assert_eq!(crossovers.len(), 2);
+                    assert_eq!(recreates.len(), 2);
+                }
+                _ => unreachable!(),
+            }
+
             match inners.last().unwrap() {
                 MutationType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(*probability, 0.01);
@@ -88,6 +102,14 @@ fn can_read_config() {
     let termination = config.termination.expect("no termination config");
     assert_eq!(termination.max_time, Some(300));
     assert_eq!(termination.max_generations, Some(3000));
+
+    let set_partitioning = config
+        .post_processing
+        .and_then(|post_processing| post_processing.set_partitioning)
+        .expect("no set partitioning config");
+    assert_eq!(set_partitioning.max_routes, Some(1000));
+    assert_eq!(set_partitioning.node_limit, Some(10000));
+    assert_eq!(set_partitioning.max_time, Some(5.));
 }
 
 #[test]
@@ -105,6 +127,7 @@ fn can_create_builder_from_config() {
     assert_eq!(builder.config.population.initial.methods.len(), 1);
     assert_eq!(builder.max_time, Some(300));
     assert_eq!(builder.max_generations, Some(3000));
+    assert!(builder.config.post_processing.is_some());
 }
 
 #[test]
@@ -114,5 +137,193 @@ fn can_create_default_config() {
     assert!(config.evolution.is_none());
     assert!(config.mutation.is_none());
     assert!(config.termination.is_none());
+    assert!(config.post_processing.is_none());
     assert!(config.telemetry.is_none());
 }
+
+#[test]
+fn can_create_builder_with_islands_from_config() {
+    let config = r#"
+    {
+      "evolution": {
+        "islands": {
+          "populations": [
+            { "type": "elitism", "maxSize": 2 },
+            { "type": "rosomaxa", "selectionSize": 4 }
+          ],
+          "migrationInterval": 50,
+          "migrationSize": 2
+        }
+      }
+    }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let islands = config.evolution.as_ref().and_then(|evolution| evolution.islands.as_ref()).expect("no islands");
+    assert_eq!(islands.populations.len(), 2);
+    assert_eq!(islands.migration_interval, Some(50));
+    assert_eq!(islands.migration_size, Some(2));
+
+    let builder = create_builder_from_config(create_example_problem(), &config);
+    assert!(builder.is_ok());
+}
+
+parameterized_test! {can_create_builder_from_config_preset, (name, max_time, max_generations), {
+    can_create_builder_from_config_preset_impl(name, max_time, max_generations);
+}}
+
+can_create_builder_from_config_preset! {
+    case01: ("fast", 60, 500),
+    case02: ("balanced", 300, 3000),
+    case03: ("thorough", 1800, 20000),
+}
+
+fn can_create_builder_from_config_preset_impl(name: &str, max_time: usize, max_generations: usize) {
+    let config = get_config_preset(name).unwrap();
+
+    let builder = create_builder_from_config(create_example_problem(), &config).unwrap();
+
+    assert_eq!(builder.max_time, Some(max_time));
+    assert_eq!(builder.max_generations, Some(max_generations));
+}
+
+#[test]
+fn can_use_explicit_set_partitioning_limits_in_config_preset() {
+    let config = get_config_preset("thorough").unwrap();
+
+    let set_partitioning = config
+        .post_processing
+        .and_then(|post_processing| post_processing.set_partitioning)
+        .expect("no set partitioning config");
+    assert_eq!(set_partitioning.max_routes, Some(500));
+    assert_eq!(set_partitioning.node_limit, Some(5000));
+    assert_eq!(set_partitioning.max_time, Some(5.));
+}
+
+#[test]
+fn can_reject_unknown_config_preset() {
+    assert!(get_config_preset("unknown").is_err());
+}
+
+parameterized_test! {can_validate_config, (mutation, expected), {
+    can_validate_config_impl(mutation, expected);
+}}
+
+can_validate_config! {
+    case01_valid: (r#"{ "type": "composite", "probability": 1, "inners": [
+        { "type": "ruin-recreate", "probability": 1,
+          "ruins": [{ "weight": 1, "methods": [{ "type": "random-job", "probability": 1, "min": 1, "max": 2, "threshold": 0.1 }] }],
+          "recreates": [{ "type": "regret", "weight": 1, "start": 2, "end": 3 }] }
+    ] }"#, vec![]),
+    case02_probability: (r#"{ "type": "composite", "probability": 1.5, "inners": [
+        { "type": "local-search", "probability": -1, "times": { "min": 1, "max": 2 },
+          "operators": [{ "type": "inter-route-best", "weight": 1, "noise": { "probability": 2, "min": 0.9, "max": 1.1 } }] }
+    ] }"#, vec![
+        ("E0005", "mutation.probability"),
+        ("E0005", "mutation.inners[0].probability"),
+        ("E0005", "mutation.inners[0].operators[0].noise.probability"),
+    ]),
+    case03_empty: (r#"{ "type": "composite", "probability": 1, "inners": [
+        { "type": "ruin-recreate", "probability": 1, "ruins": [{ "weight": 1, "methods": [] }], "recreates": [] },
+        { "type": "composite", "probability": 1, "inners": [] }
+    ] }"#, vec![
+        ("E0006", "mutation.inners[0].ruins[0].methods"),
+        ("E0006", "mutation.inners[0].recreates"),
+        ("E0006", "mutation.inners[1].inners"),
+    ]),
+    case04_range: (r#"{ "type": "ruin-recreate", "probability": 1,
+        "ruins": [{ "weight": 1, "methods": [
+            { "type": "neighbour", "probability": 1, "min": 8, "max": 4, "threshold": 0.1 },
+            { "type": "cluster", "probability": 1, "min": 1, "max": 2, "cmin": 9, "cmax": 3, "threshold": 0.1 }
+        ] }],
+        "recreates": [{ "type": "skip-best", "weight": 1, "start": 3, "end": 1 }]
+    }"#, vec![
+        ("E0007", "mutation.ruins[0].methods[0].min"),
+        ("E0007", "mutation.ruins[0].methods[1].cmin"),
+        ("E0007", "mutation.recreates[0].start"),
+    ]),
+    case05_ruin_size: (r#"{ "type": "ruin-recreate", "probability": 1,
+        "ruins": [{ "weight": 1, "methods": [
+            { "type": "random-job", "probability": 1, "minRatio": 0.01, "maxRatio": 0.05, "threshold": 0.1 },
+            { "type": "random-route", "probability": 1, "threshold": 0.1 },
+            { "type": "random-job", "probability": 1, "min": 1, "threshold": 0.1 },
+            { "type": "neighbour", "probability": 1, "min": 1, "max": 2, "minRatio": 0.1, "maxRatio": 0.2, "threshold": 0.1 },
+            { "type": "worst-job", "probability": 1, "minRatio": 0, "maxRatio": 1.5, "threshold": 0.1, "skip": 4 },
+            { "type": "cluster", "probability": 1, "minRatio": 0.2, "maxRatio": 0.1, "cmin": 3, "cmax": 9, "threshold": 0.1 }
+        ] }],
+        "recreates": [{ "type": "cheapest", "weight": 1 }]
+    }"#, vec![
+        ("E0008", "mutation.ruins[0].methods[2]"),
+        ("E0008", "mutation.ruins[0].methods[3]"),
+        ("E0008", "mutation.ruins[0].methods[4]"),
+        ("E0007", "mutation.ruins[0].methods[5].minRatio"),
+    ]),
+}
+
+fn can_validate_config_impl(mutation: &str, expected: Vec<(&str, &str)>) {
+    let config = format!(r#"{{ "mutation": {} }}"#, mutation);
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let errors = validate_config(&config).err().unwrap_or_default();
+
+    assert_eq!(errors.len(), expected.len());
+    errors.iter().zip(expected.iter()).for_each(|(error, (code, path))| {
+        assert_eq!(error.code, *code);
+        assert!(error.action.contains(format!("'{}", path).as_str()), "unexpected action: {}", error.action);
+    });
+}
+
+#[test]
+fn can_return_validation_errors_from_builder() {
+    let config = r#"{ "evolution": { "islands": { "populations": [] } } }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let result = create_builder_from_config(create_example_problem(), &config);
+
+    assert!(result.err().unwrap().starts_with("E0006"));
+}
+
+#[test]
+fn can_reject_zero_migration_interval() {
+    let config = r#"{ "evolution": { "islands": {
+        "populations": [{ "type": "elitism" }], "migrationInterval": 0
+    } } }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let errors = validate_config(&config).err().unwrap_or_default();
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "E0009");
+    assert!(errors[0].action.contains("'evolution.islands.migrationInterval'"));
+}
+
+parameterized_test! {can_get_ruin_size, (size, total, expected), {
+    can_get_ruin_size_impl(size, total, expected);
+}}
+
+can_get_ruin_size! {
+    case01_absolute: ((Some(2), Some(8), None, None), 1000, Some((2, 8))),
+    case02_ratio: ((None, None, Some(0.01), Some(0.05)), 1000, Some((10, 50))),
+    case03_ratio_small: ((None, None, Some(0.01), Some(0.05)), 20, Some((1, 1))),
+    case04_auto: ((None, None, None, None), 1000, None),
+}
+
+fn can_get_ruin_size_impl(
+    size: (Option<usize>, Option<usize>, Option<f64>, Option<f64>),
+    total: usize,
+    expected: Option<(usize, usize)>,
+) {
+    let (min, max, min_ratio, max_ratio) = size;
+    let size = RuinSizeConfig { min, max, min_ratio, max_ratio };
+
+    assert_eq!(get_ruin_size(&size, total), expected);
+}
+
+#[test]
+fn can_scale_job_limit_when_ruin_size_is_omitted() {
+    let characteristics = ProblemCharacteristics { jobs: 400, actors: 10, avg_route_size: 40., tw_tightness: 0. };
+
+    let limit = create_job_limit(&characteristics, &RuinSizeConfig::default(), 0.2);
+
+    assert_eq!((limit.min, limit.max), (16, 32));
+    assert_eq!(limit.threshold, 0.2);
+}
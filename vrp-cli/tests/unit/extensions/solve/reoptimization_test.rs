@@ -0,0 +1,115 @@
+use super::*;
+use std::fs::File;
+use vrp_pragmatic::format::problem::deserialize_problem;
+use vrp_pragmatic::format::solution::{deserialize_solution, Activity};
+
+fn read_simple_problem_and_solution() -> (Problem, Solution) {
+    let problem = File::open("../examples/data/pragmatic/simple.basic.problem.json").unwrap();
+    let solution = File::open("../examples/data/pragmatic/simple.basic.solution.json").unwrap();
+
+    (deserialize_problem(BufReader::new(problem)).unwrap(), deserialize_solution(BufReader::new(solution)).unwrap())
+}
+
+fn get_tour_job_ids(solution: &Solution) -> Vec<Vec<String>> {
+    solution.tours.iter().map(|tour| get_job_ids(tour).collect()).collect()
+}
+
+fn create_new_job(id: &str) -> Job {
+    let (problem, _) = read_simple_problem_and_solution();
+    Job { id: id.to_string(), ..problem.plan.jobs.first().unwrap().clone() }
+}
+
+#[test]
+fn can_read_change_set() {
+    let file = File::open("../examples/data/pragmatic/simple.basic.change-set.json").unwrap();
+
+    let change_set = read_change_set(BufReader::new(file)).unwrap();
+
+    assert_eq!(change_set.new_jobs.unwrap().len(), 1);
+    assert_eq!(change_set.cancelled_jobs, Some(vec!["job2".to_string()]));
+    assert!(change_set.unavailable_vehicles.is_none());
+}
+
+#[test]
+fn can_lock_unaffected_tours_when_new_jobs_added() {
+    let (problem, solution) = read_simple_problem_and_solution();
+    let change_set = ChangeSet { new_jobs: Some(vec![create_new_job("job4")]), ..ChangeSet::default() };
+
+    let (problem, solution) = apply_change_set(problem, solution, &change_set, Some(RelationType::Any)).unwrap();
+
+    assert_eq!(problem.plan.jobs.len(), 4);
+    assert_eq!(get_tour_job_ids(&solution), vec![vec!["job1", "job2", "job3", "job3"]]);
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].vehicle_id, "vehicle_1");
+    assert_eq!(relations[0].jobs, vec!["job1", "job2", "job3", "job3"]);
+}
+
+parameterized_test! {can_lock_unaffected_tours_with_multi_jobs_and_reserved_activities, (relation_type, expected), {
+    can_lock_unaffected_tours_with_multi_jobs_and_reserved_activities_impl(relation_type, expected);
+}}
+
+can_lock_unaffected_tours_with_multi_jobs_and_reserved_activities! {
+    case01: (RelationType::Any, vec!["job1", "job2", "job3", "job3"]),
+    case02: (RelationType::Sequence, vec!["departure", "job1", "job2", "job3", "break", "job3", "arrival"]),
+    case03: (RelationType::Strict, vec!["departure", "job1", "job2", "job3", "break", "job3", "arrival"]),
+}
+
+fn can_lock_unaffected_tours_with_multi_jobs_and_reserved_activities_impl(
+    relation_type: RelationType,
+    expected: Vec<&str>,
+) {
+    let (problem, mut solution) = read_simple_problem_and_solution();
+    solution.tours[0].stops[3].activities.insert(
+        0,
+        Activity {
+            job_id: "break".to_string(),
+            activity_type: "break".to_string(),
+            location: None,
+            time: None,
+            job_tag: None,
+        },
+    );
+    let change_set = ChangeSet { new_jobs: Some(vec![create_new_job("job4")]), ..ChangeSet::default() };
+
+    let (problem, _) = apply_change_set(problem, solution, &change_set, Some(relation_type)).unwrap();
+
+    let relations = problem.plan.relations.expect("no relations");
+    assert_eq!(relations.len(), 1);
+    assert_eq!(relations[0].jobs, expected);
+}
+
+#[test]
+fn can_remove_cancelled_jobs_without_locking_affected_tours() {
+    let (problem, solution) = read_simple_problem_and_solution();
+    let change_set = ChangeSet { cancelled_jobs: Some(vec!["job2".to_string()]), ..ChangeSet::default() };
+
+    let (problem, solution) = apply_change_set(problem, solution, &change_set, Some(RelationType::Any)).unwrap();
+
+    assert_eq!(problem.plan.jobs.len(), 2);
+    assert_eq!(get_tour_job_ids(&solution), vec![vec!["job1", "job3", "job3"]]);
+    assert!(problem.plan.relations.is_none());
+}
+
+#[test]
+fn can_remove_tours_of_unavailable_vehicles() {
+    let (mut problem, solution) = read_simple_problem_and_solution();
+    problem.fleet.vehicles[0].vehicle_ids.push("vehicle_2".to_string());
+    let change_set = ChangeSet { unavailable_vehicles: Some(vec!["vehicle_1".to_string()]), ..ChangeSet::default() };
+
+    let (problem, solution) = apply_change_set(problem, solution, &change_set, Some(RelationType::Any)).unwrap();
+
+    assert_eq!(problem.fleet.vehicles[0].vehicle_ids, vec!["vehicle_2"]);
+    assert!(solution.tours.is_empty());
+    assert!(problem.plan.relations.is_none());
+}
+
+#[test]
+fn can_reject_new_job_with_existing_id() {
+    let (problem, solution) = read_simple_problem_and_solution();
+    let change_set = ChangeSet { new_jobs: Some(vec![create_new_job("job1")]), ..ChangeSet::default() };
+
+    let result = apply_change_set(problem, solution, &change_set, None);
+
+    assert_eq!(result.err(), Some("new job has the same id as existing one: 'job1'".to_string()));
+}
@@ -1,12 +1,16 @@
 use super::*;
+use crate::extensions::solve::config::read_config;
 use crate::helpers::generate::{create_test_job, create_test_vehicle_type};
+use std::sync::Mutex;
+use vrp_core::solver::Progress;
+use vrp_core::utils::CancellationQuota;
 use vrp_pragmatic::format::problem::{Fleet, Plan, Profile};
 
 #[test]
 fn can_get_locations_serialized() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], relations: None },
-        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![create_test_vehicle_type()], profiles: vec![], states: None },
         objectives: None,
     };
 
@@ -22,6 +26,7 @@ fn can_get_solution_serialized() {
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
             profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            states: None,
         },
         objectives: None,
     };
@@ -35,3 +40,58 @@ fn can_get_solution_serialized() {
     assert!(solution.contains("tours"));
     assert!(solution.contains("stops"));
 }
+
+#[test]
+fn can_get_solution_serialized_with_progress_and_cancellation() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            states: None,
+        },
+        objectives: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+    let quota = CancellationQuota::default();
+    let generations = Arc::new(Mutex::new(vec![]));
+    let progress: ProgressCallback = {
+        let quota = quota.clone();
+        let generations = generations.clone();
+        Arc::new(move |progress: &Progress| {
+            generations.lock().unwrap().push(progress.generation);
+            if progress.generation == 5 {
+                quota.cancel();
+            }
+        })
+    };
+
+    let solution =
+        get_solution_serialized_with_progress(problem, Config::default(), Some(progress), Some(Arc::new(quota)))
+            .unwrap();
+
+    assert_eq!(generations.lock().unwrap().clone(), (0..=5).collect::<Vec<_>>());
+    assert!(solution.contains("tours"));
+}
+
+#[test]
+fn can_get_config_validation_errors_as_json() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_test_job(1., 0.)], relations: None },
+        fleet: Fleet {
+            vehicles: vec![create_test_vehicle_type()],
+            profiles: vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }],
+            states: None,
+        },
+        objectives: None,
+    };
+    let problem = Arc::new(problem.read_pragmatic().unwrap());
+    let config = r#"{ "evolution": { "islands": { "populations": [], "migrationInterval": 0 } } }"#;
+    let config = read_config(BufReader::new(config.as_bytes())).unwrap();
+
+    let errors = get_solution_serialized_with_progress(problem, config, None, None).err().unwrap();
+
+    let errors: serde_json::Value = serde_json::from_str(errors.as_str()).expect("errors should be json");
+    let codes = errors.as_array().unwrap().iter().map(|error| error["code"].as_str().unwrap()).collect::<Vec<_>>();
+    assert_eq!(codes, vec!["E0006", "E0009"]);
+}
@@ -3,6 +3,7 @@
 pub mod dbscan;
 pub mod gsom;
 pub mod nsga2;
+pub mod set_partitioning;
 
 pub mod geometry;
 pub mod statistics;
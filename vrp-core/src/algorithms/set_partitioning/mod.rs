@@ -0,0 +1,159 @@
+//! This module contains a simple branch and bound algorithm which solves set partitioning problem
+//! with optional (at most once) items.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/algorithms/set_partitioning/set_partitioning_test.rs"]
+mod set_partitioning_test;
+
+use crate::construction::Quota;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+/// Represents a column in set partitioning problem: a subset of items within its cost.
+pub struct Column {
+    /// Indices of items covered by the column.
+    pub items: Vec<usize>,
+    /// A column cost.
+    pub cost: f64,
+}
+
+/// Represents a set partitioning solution: indices of selected columns within their total cost.
+pub type Partitioning = (Vec<usize>, f64);
+
+/// Solves set partitioning problem: selects columns with minimal total cost in the way that each
+/// item in `0..required` range is covered exactly once and each other item is covered at most once.
+///
+/// * `columns` - a list of columns.
+/// * `required` - a size of required items range.
+/// * `node_limit` - a max amount of explored branch and bound nodes.
+/// * `quota` - an optional quota which stops the search when reached.
+/// * `initial` - an optional known partitioning used as initial upper bound.
+///
+/// Returns the best found partitioning or `None` if there is no partitioning better than initial one.
+pub fn solve_set_partitioning(
+    columns: &[Column],
+    required: usize,
+    node_limit: usize,
+    quota: Option<&dyn Quota>,
+    initial: Option<Partitioning>,
+) -> Option<Partitioning> {
+    let total = columns.iter().flat_map(|column| column.items.iter()).map(|item| item + 1).max().unwrap_or(0);
+    let total = total.max(required);
+
+    let mut item_columns = vec![vec![]; required];
+    let mut min_ratios = vec![f64::MAX; required];
+
+    columns.iter().enumerate().for_each(|(idx, column)| {
+        let required_items = column.items.iter().filter(|&&item| item < required).count();
+        if required_items == 0 {
+            return;
+        }
+
+        let ratio = column.cost / required_items as f64;
+        column.items.iter().filter(|&&item| item < required).for_each(|&item| {
+            item_columns[item].push(idx);
+            min_ratios[item] = min_ratios[item].min(ratio);
+        });
+    });
+
+    if min_ratios.contains(&f64::MAX) {
+        return None;
+    }
+
+    item_columns.iter_mut().for_each(|candidates| {
+        candidates.sort_by(|&a, &b| compare_floats(columns[a].cost, columns[b].cost));
+    });
+
+    let mut search = Search {
+        columns,
+        required,
+        item_columns,
+        min_ratios,
+        covered: vec![false; total],
+        selected: vec![],
+        best: None,
+        upper_bound: initial.as_ref().map_or(f64::MAX, |(_, cost)| *cost),
+        nodes: 0,
+        node_limit,
+        quota,
+    };
+
+    let lower_bound = search.min_ratios.iter().sum();
+    search.run(0., lower_bound, required);
+
+    search.best
+}
+
+struct Search<'a> {
+    columns: &'a [Column],
+    required: usize,
+    item_columns: Vec<Vec<usize>>,
+    min_ratios: Vec<f64>,
+    covered: Vec<bool>,
+    selected: Vec<usize>,
+    best: Option<Partitioning>,
+    upper_bound: f64,
+    nodes: usize,
+    node_limit: usize,
+    quota: Option<&'a dyn Quota>,
+}
+
+impl<'a> Search<'a> {
+    fn run(&mut self, cost: f64, lower_bound: f64, uncovered: usize) {
+        if self.nodes >= self.node_limit || self.is_quota_reached() {
+            return;
+        }
+
+        self.nodes += 1;
+
+        if compare_floats(cost + lower_bound, self.upper_bound) != Ordering::Less {
+            return;
+        }
+
+        if uncovered == 0 {
+            self.upper_bound = cost;
+            self.best = Some((self.selected.clone(), cost));
+            return;
+        }
+
+        // NOTE branch on uncovered item with the smallest amount of feasible columns
+        let branch = (0..self.required)
+            .filter(|&item| !self.covered[item])
+            .map(|item| (item, self.get_candidates(item)))
+            .min_by_key(|(_, candidates)| candidates.len());
+
+        let candidates = match branch {
+            Some((_, candidates)) if !candidates.is_empty() => candidates,
+            _ => return,
+        };
+
+        for column_idx in candidates {
+            let column = &self.columns[column_idx];
+            let required_items = column.items.iter().filter(|&&item| item < self.required).collect::<Vec<_>>();
+            let ratios = required_items.iter().map(|&&item| self.min_ratios[item]).sum::<f64>();
+
+            column.items.iter().for_each(|&item| self.covered[item] = true);
+            self.selected.push(column_idx);
+
+            self.run(cost + column.cost, lower_bound - ratios, uncovered - required_items.len());
+
+            self.selected.pop();
+            column.items.iter().for_each(|&item| self.covered[item] = false);
+        }
+    }
+
+    fn is_quota_reached(&self) -> bool {
+        match self.quota {
+            Some(quota) => quota.is_reached(),
+            None => false,
+        }
+    }
+
+    fn get_candidates(&self, item: usize) -> Vec<usize> {
+        self.item_columns[item]
+            .iter()
+            .filter(|&&column_idx| self.columns[column_idx].items.iter().all(|&other| !self.covered[other]))
+            .cloned()
+            .collect()
+    }
+}
@@ -102,8 +102,11 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + Add<Output = T> + Sub<Output
     }
 
     fn recalculate_states(&self, ctx: &mut RouteContext) {
+        // NOTE vehicle might have some load already, e.g. when it has started its tour before
+        let initial_load = ctx.route.actor.vehicle.dimens.get_initial_load().cloned().unwrap_or_default();
+
         let (_, max_load) = self.actualize_intervals(ctx).into_iter().fold(
-            (T::default(), T::default()),
+            (initial_load, T::default()),
             |(acc, max), (start_idx, end_idx)| {
                 let (route, state) = ctx.as_mut();
 
@@ -421,6 +421,11 @@ impl RegistryContext {
         self.registry.next().map(move |actor| self.index[&actor].clone())
     }
 
+    /// Returns routes of all available actors.
+    pub fn available<'a>(&'a self) -> impl Iterator<Item = RouteContext> + 'a {
+        self.registry.available().map(move |actor| self.index[&actor].clone())
+    }
+
     /// Sets this route as used.
     /// Returns whether the route was already marked as used in the registry.
     pub fn use_route(&mut self, route: &RouteContext) -> bool {
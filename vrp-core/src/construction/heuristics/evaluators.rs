@@ -7,7 +7,7 @@ use std::sync::Arc;
 use crate::construction::constraints::{ActivityConstraintViolation, ConstraintPipeline};
 use crate::construction::heuristics::*;
 use crate::models::common::Cost;
-use crate::models::problem::{Job, Multi, Single};
+use crate::models::problem::{Actor, Job, Multi, Single};
 use crate::models::solution::{Activity, Place};
 use crate::utils::unwrap_from_result;
 use std::iter::repeat;
@@ -93,6 +93,37 @@ pub fn evaluate_job_constraint_in_route(
     }
 }
 
+/// Evaluates possibility to insert the job into every route of the solution and into an empty route
+/// of every available actor. Returns a violated constraint code for each actor which cannot serve the job.
+pub fn evaluate_job_constraint_per_actor(job: &Job, ctx: &InsertionContext) -> Vec<(Arc<Actor>, i32)> {
+    let constraint = &ctx.problem.constraint;
+
+    ctx.solution
+        .routes
+        .iter()
+        .cloned()
+        .chain(ctx.solution.registry.available())
+        .filter_map(|route_ctx| {
+            let code = match constraint.evaluate_hard_route(&ctx.solution, &route_ctx, job) {
+                Some(violation) => Some(violation.code),
+                None => match evaluate_job_constraint_in_route(
+                    job,
+                    constraint,
+                    &route_ctx,
+                    InsertionPosition::Any,
+                    0.,
+                    None,
+                ) {
+                    InsertionResult::Failure(failure) => Some(failure.constraint),
+                    InsertionResult::Success(_) => None,
+                },
+            };
+
+            code.map(|code| (route_ctx.route.actor.clone(), code))
+        })
+        .collect()
+}
+
 fn evaluate_single(
     job: &Job,
     single: &Arc<Single>,
@@ -9,6 +9,7 @@ use std::ops::{Add, Mul, Sub};
 
 const CAPACITY_DIMENSION_KEY: &str = "cpc";
 const DEMAND_DIMENSION_KEY: &str = "dmd";
+const INITIAL_LOAD_DIMENSION_KEY: &str = "iload";
 const LOAD_DIMENSION_SIZE: usize = 8;
 
 /// Represents a load type used to represent customer's demand or vehicle's load.
@@ -40,6 +41,10 @@ pub trait CapacityDimension<T: Load + Add<Output = T> + Sub<Output = T> + 'stati
     fn set_capacity(&mut self, demand: T) -> &mut Self;
     /// Gets capacity.
     fn get_capacity(&self) -> Option<&T>;
+    /// Sets initial load which vehicle has at the start of its tour.
+    fn set_initial_load(&mut self, load: T) -> &mut Self;
+    /// Gets initial load which vehicle has at the start of its tour.
+    fn get_initial_load(&self) -> Option<&T>;
 }
 
 /// A trait to get or set demand.
@@ -78,6 +83,15 @@ impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> CapacityDimension<T>
     fn get_capacity(&self) -> Option<&T> {
         self.get_value(CAPACITY_DIMENSION_KEY)
     }
+
+    fn set_initial_load(&mut self, load: T) -> &mut Self {
+        self.set_value(INITIAL_LOAD_DIMENSION_KEY, load);
+        self
+    }
+
+    fn get_initial_load(&self) -> Option<&T> {
+        self.get_value(INITIAL_LOAD_DIMENSION_KEY)
+    }
 }
 
 impl<T: Load + Add<Output = T> + Sub<Output = T> + 'static> DemandDimension<T> for Dimensions {
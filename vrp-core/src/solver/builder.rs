@@ -1,11 +1,12 @@
 use crate::construction::heuristics::InsertionContext;
 use crate::construction::Quota;
 use crate::models::{Problem, Solution};
-use crate::solver::evolution::EvolutionConfig;
+use crate::solver::evolution::{EvolutionConfig, EvolutionStrategy};
 use crate::solver::mutation::*;
 use crate::solver::population::Population;
+use crate::solver::post_processing::PostProcessing;
 use crate::solver::termination::*;
-use crate::solver::{Solver, Telemetry};
+use crate::solver::{CheckpointCallback, CheckpointInterval, ProgressCallback, Solver, Telemetry};
 use crate::utils::{DefaultRandom, TimeQuota};
 use std::sync::Arc;
 
@@ -53,6 +54,9 @@ pub struct Builder {
     /// A cost variation parameters for termination criteria.
     pub cost_variation: Option<(usize, f64)>,
 
+    /// An external quota, e.g. cancellation.
+    pub quota: Option<Arc<dyn Quota + Send + Sync>>,
+
     /// An evolution configuration..
     pub config: EvolutionConfig,
 }
@@ -60,7 +64,13 @@ pub struct Builder {
 impl Builder {
     /// Creates a new instance of `Builder`.
     pub fn new(problem: Arc<Problem>) -> Self {
-        Self { max_generations: None, max_time: None, cost_variation: None, config: EvolutionConfig::new(problem) }
+        Self {
+            max_generations: None,
+            max_time: None,
+            cost_variation: None,
+            quota: None,
+            config: EvolutionConfig::new(problem),
+        }
     }
 }
 
@@ -71,6 +81,30 @@ impl Builder {
         self
     }
 
+    /// Sets a callback which receives search progress after each generation. Default is none.
+    pub fn with_progress(mut self, progress: ProgressCallback) -> Self {
+        self.config.telemetry.set_progress(progress);
+        self
+    }
+
+    /// Sets a callback which receives the best known solution with given interval, e.g. to
+    /// write it to a file, so an interrupted search still leaves a usable solution. Default is none.
+    pub fn with_checkpoint(mut self, interval: CheckpointInterval, checkpoint: CheckpointCallback) -> Self {
+        self.config.telemetry.log("configured to use checkpoints");
+        self.config.telemetry.set_checkpoint(interval, checkpoint);
+        self
+    }
+
+    /// Sets an external quota which stops search when reached, e.g. [`CancellationQuota`].
+    /// In this case, the best known solution is returned. Default is none.
+    ///
+    /// [`CancellationQuota`]: ../utils/struct.CancellationQuota.html
+    pub fn with_quota(mut self, quota: Arc<dyn Quota + Send + Sync>) -> Self {
+        self.config.telemetry.log("configured to use external quota");
+        self.quota = Some(quota);
+        self
+    }
+
     /// Sets max generations to be run by evolution. Default is 3000.
     pub fn with_max_generations(mut self, limit: Option<usize>) -> Self {
         self.max_generations = limit;
@@ -145,6 +179,20 @@ impl Builder {
         self
     }
 
+    /// Sets evolution strategy. Default is simple evolution with single population.
+    pub fn with_strategy(mut self, strategy: Arc<dyn EvolutionStrategy + Send + Sync>) -> Self {
+        self.config.telemetry.log("configured to use custom evolution strategy");
+        self.config.strategy = strategy;
+        self
+    }
+
+    /// Sets post processing applied to the best known solution. Default is none.
+    pub fn with_post_processing(mut self, post_processing: Arc<dyn PostProcessing + Send + Sync>) -> Self {
+        self.config.telemetry.log("configured to use post processing");
+        self.config.post_processing = Some(post_processing);
+        self
+    }
+
     /// Builds [`Solver`](./struct.Solver.html) instance.
     pub fn build(self) -> Result<Solver, String> {
         let problem = self.config.problem.clone();
@@ -188,6 +236,11 @@ impl Builder {
                 }
             };
 
+        let quota: Option<Arc<dyn Quota + Send + Sync>> = match (quota, self.quota) {
+            (Some(time_quota), Some(quota)) => Some(Arc::new(CompositeQuota { quotas: vec![time_quota, quota] })),
+            (time_quota, quota) => time_quota.or(quota),
+        };
+
         let mut config = self.config;
         config.termination = Arc::new(CompositeTermination::new(criterias));
         config.quota = quota;
@@ -201,3 +254,14 @@ impl Builder {
 fn create_time_quota(limit: usize) -> Option<Arc<dyn Quota + Sync + Send>> {
     Some(Arc::new(TimeQuota::new(limit as f64)))
 }
+
+/// A quota which is reached when any of inner quotas is reached.
+struct CompositeQuota {
+    quotas: Vec<Arc<dyn Quota + Send + Sync>>,
+}
+
+impl Quota for CompositeQuota {
+    fn is_reached(&self) -> bool {
+        self.quotas.iter().any(|quota| quota.is_reached())
+    }
+}
@@ -4,6 +4,7 @@ use crate::models::Problem;
 use crate::solver::evolution::{EvolutionStrategy, RunSimple};
 use crate::solver::mutation::*;
 use crate::solver::population::*;
+use crate::solver::post_processing::PostProcessing;
 use crate::solver::telemetry::Telemetry;
 use crate::solver::termination::*;
 use crate::solver::TelemetryMode;
@@ -30,6 +31,9 @@ pub struct EvolutionConfig {
     /// A quota for evolution execution.
     pub quota: Option<Arc<dyn Quota + Send + Sync>>,
 
+    /// A post processing applied to the best known solution.
+    pub post_processing: Option<Arc<dyn PostProcessing + Send + Sync>>,
+
     /// Random generator.
     pub random: Arc<dyn Random + Send + Sync>,
 
@@ -90,6 +94,7 @@ impl EvolutionConfig {
             ])),
             strategy: Arc::new(RunSimple::default()),
             quota: None,
+            post_processing: None,
             random,
             telemetry: Telemetry::new(TelemetryMode::None),
         }
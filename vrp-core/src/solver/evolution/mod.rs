@@ -4,17 +4,26 @@ mod evolution_test;
 
 use crate::construction::heuristics::InsertionContext;
 use crate::solver::mutation::*;
+use crate::solver::post_processing::{PostProcessing, POST_PROCESSING_KEY};
 use crate::solver::telemetry::Telemetry;
 use crate::solver::termination::*;
 use crate::solver::{Metrics, Population, RefinementContext};
 use crate::utils::Timer;
+use hashbrown::HashMap;
+use std::any::Any;
+use std::sync::Arc;
 
 mod config;
 pub use self::config::*;
 
+mod run_islands;
+pub use self::run_islands::{PopulationFactory, RunIslands};
+
 mod run_simple;
 pub use self::run_simple::RunSimple;
 
+type RefinementState = HashMap<String, Box<dyn Any + Sync + Send>>;
+
 /// Defines evolution result type.
 pub type EvolutionResult = Result<(Box<dyn Population + Send + Sync>, Option<Metrics>), String>;
 
@@ -66,6 +75,10 @@ impl EvolutionSimulator {
             std::mem::replace(&mut self.config.quota, None),
         );
 
+        if let Some(post_processing) = self.config.post_processing.clone() {
+            refinement_ctx.state.insert(POST_PROCESSING_KEY.to_string(), Box::new(post_processing));
+        }
+
         self.config.telemetry.log(
             format!(
                 "problem has total jobs: {}, actors: {}",
@@ -156,4 +169,28 @@ fn on_generation(
 
     telemetry.on_generation(refinement_ctx, termination_estimate, generation_time, is_improved);
     refinement_ctx.population.on_generation(&refinement_ctx.statistics);
+
+    on_post_processing(refinement_ctx);
+}
+
+fn on_post_processing(refinement_ctx: &RefinementContext) {
+    if let Some(post_processing) = get_post_processing(&refinement_ctx.state) {
+        post_processing.on_generation(refinement_ctx);
+    }
+}
+
+fn get_post_processing(state: &RefinementState) -> Option<&Arc<dyn PostProcessing + Send + Sync>> {
+    state.get(POST_PROCESSING_KEY).and_then(|s| s.downcast_ref::<Arc<dyn PostProcessing + Send + Sync>>())
+}
+
+/// Copies state entries which can be shared between refinement contexts, e.g. post processing.
+/// Other entries are specific to the context which has created them.
+fn copy_shared_state(state: &RefinementState) -> RefinementState {
+    let mut shared = RefinementState::new();
+
+    if let Some(post_processing) = get_post_processing(state) {
+        shared.insert(POST_PROCESSING_KEY.to_string(), Box::new(post_processing.clone()));
+    }
+
+    shared
 }
@@ -0,0 +1,148 @@
+use crate::solver::evolution::*;
+use crate::solver::population::Individual;
+use crate::solver::{RefinementContext, Telemetry};
+use crate::utils::{parallel_into_collect, Timer};
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// A function which creates a new population for an island.
+pub type PopulationFactory = Arc<dyn Fn() -> Box<dyn Population + Send + Sync> + Send + Sync>;
+
+/// An island model evolution algorithm which evolves multiple independent populations (islands)
+/// in parallel and periodically migrates best individuals between them using ring topology.
+///
+/// A population passed within refinement context is used to keep track of the best individuals
+/// found across all islands. Shared state of refinement context, such as post processing, is copied
+/// to each island, so it is informed about individuals discovered there.
+pub struct RunIslands {
+    populations: Vec<PopulationFactory>,
+    migration_interval: usize,
+    migration_size: usize,
+}
+
+impl RunIslands {
+    /// Creates a new instance of `RunIslands`.
+    ///
+    /// * `populations` - factories used to create population for each island.
+    /// * `migration_interval` - specifies how often (in generations) migration happens.
+    /// * `migration_size` - specifies how many best individuals migrate from each island.
+    pub fn new(populations: Vec<PopulationFactory>, migration_interval: usize, migration_size: usize) -> Self {
+        assert!(!populations.is_empty());
+        assert!(migration_interval > 0);
+
+        Self { populations, migration_interval, migration_size }
+    }
+
+    fn create_islands(&self, refinement_ctx: &RefinementContext) -> Vec<RefinementContext> {
+        self.populations
+            .iter()
+            .map(|factory| {
+                let mut island =
+                    RefinementContext::new(refinement_ctx.problem.clone(), factory(), refinement_ctx.quota.clone());
+                island.state = copy_shared_state(&refinement_ctx.state);
+
+                let individuals = refinement_ctx
+                    .population
+                    .ranked()
+                    .map(|(individual, _)| individual.deep_copy())
+                    .collect::<Vec<_>>();
+
+                if !individuals.is_empty() {
+                    island.population.add_all(individuals);
+                }
+
+                island
+            })
+            .collect()
+    }
+
+    fn migrate(&self, islands: &mut [RefinementContext]) {
+        if islands.len() < 2 || self.migration_size == 0 {
+            return;
+        }
+
+        let migrants = islands
+            .iter()
+            .map(|island| {
+                island
+                    .population
+                    .ranked()
+                    .take(self.migration_size)
+                    .map(|(individual, _)| individual.deep_copy())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let total = islands.len();
+        migrants.into_iter().enumerate().filter(|(_, migrants)| !migrants.is_empty()).for_each(|(idx, migrants)| {
+            islands[(idx + 1) % total].population.add_all(migrants);
+        });
+    }
+
+    fn get_improvements(refinement_ctx: &RefinementContext, islands: &[RefinementContext]) -> Vec<Individual> {
+        let global_best = refinement_ctx.population.ranked().next().map(|(individual, _)| individual);
+
+        islands
+            .iter()
+            .filter_map(|island| island.population.ranked().next().map(|(individual, _)| individual))
+            .filter(|individual| {
+                global_best.map_or(true, |best| refinement_ctx.population.cmp(individual, best) == Ordering::Less)
+            })
+            .map(|individual| individual.deep_copy())
+            .collect()
+    }
+}
+
+impl EvolutionStrategy for RunIslands {
+    fn run(
+        &self,
+        refinement_ctx: RefinementContext,
+        mutation: &(dyn Mutation + Send + Sync),
+        termination: &(dyn Termination + Send + Sync),
+        telemetry: Telemetry,
+    ) -> EvolutionResult {
+        let mut refinement_ctx = refinement_ctx;
+        let mut telemetry = telemetry;
+        let mut islands = self.create_islands(&refinement_ctx);
+
+        while !should_stop(&mut refinement_ctx, termination) {
+            let generation_time = Timer::start();
+
+            islands = parallel_into_collect(islands, |mut island| {
+                let parents = island.population.select().collect();
+
+                let offspring = mutation.mutate_all(&island, parents);
+
+                if should_add_solution(&island) {
+                    island.population.add_all(offspring);
+                }
+
+                island
+            });
+
+            if (refinement_ctx.statistics.generation + 1) % self.migration_interval == 0 {
+                self.migrate(islands.as_mut_slice());
+            }
+
+            let improvements = Self::get_improvements(&refinement_ctx, islands.as_slice());
+            let is_improved = if !improvements.is_empty() && should_add_solution(&refinement_ctx) {
+                refinement_ctx.population.add_all(improvements)
+            } else {
+                false
+            };
+
+            on_generation(&mut refinement_ctx, &mut telemetry, termination, generation_time, is_improved);
+
+            islands.iter_mut().for_each(|island| {
+                island.statistics = refinement_ctx.statistics.clone();
+                island.population.on_generation(&island.statistics);
+                on_post_processing(island);
+            });
+        }
+
+        telemetry.on_islands(&refinement_ctx, islands.as_slice());
+        telemetry.on_result(&refinement_ctx);
+
+        Ok((refinement_ctx.population, telemetry.get_metrics()))
+    }
+}
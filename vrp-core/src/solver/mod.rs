@@ -103,6 +103,7 @@ use std::sync::Arc;
 pub mod mutation;
 pub mod objectives;
 pub mod population;
+pub mod post_processing;
 pub mod termination;
 
 mod builder;
@@ -110,9 +111,12 @@ pub use self::builder::Builder;
 
 mod evolution;
 use self::evolution::{EvolutionConfig, EvolutionSimulator};
+pub use self::evolution::{EvolutionResult, EvolutionStrategy, PopulationFactory, RunIslands, RunSimple};
 
 mod telemetry;
-pub use self::telemetry::{Metrics, Telemetry, TelemetryMode};
+pub use self::telemetry::{
+    CheckpointCallback, CheckpointInterval, Metrics, Progress, ProgressCallback, Telemetry, TelemetryMode,
+};
 
 /// A key to store solution order information.
 pub const SOLUTION_ORDER_KEY: i32 = 100;
@@ -136,6 +140,7 @@ pub struct RefinementContext {
 }
 
 /// A refinement statistics to track evolution progress.
+#[derive(Clone)]
 pub struct Statistics {
     /// A number which specifies refinement generation.
     pub generation: usize,
@@ -203,13 +208,57 @@ impl Solver {
     /// # Ok::<(), String>(())
     /// ```
     pub fn solve(self) -> Result<(Solution, Cost, Option<Metrics>), String> {
+        let post_processing = self.config.post_processing.clone();
         let (population, metrics) = EvolutionSimulator::new(self.config)?.run()?;
 
         // NOTE select the first best individual from population
         let (insertion_ctx, _) = population.ranked().next().ok_or_else(|| "cannot find any solution".to_string())?;
+
+        let processed = post_processing.and_then(|post_processing| post_processing.process(insertion_ctx));
+        let insertion_ctx = processed.as_ref().unwrap_or(insertion_ctx);
         let solution = insertion_ctx.solution.to_solution(self.problem.extras.clone());
         let cost = self.problem.objective.fitness(insertion_ctx);
 
         Ok((solution, cost, metrics))
     }
+
+    /// Solves a Vehicle Routing Problem and returns all non-dominated solutions (pareto front)
+    /// found in the final population within their cost and objective fitness values. Objective
+    /// values are ordered as objectives are defined in the problem (primary first), solutions are
+    /// ordered the same way as in population, so the first one is the one returned by `solve`.
+    /// Post processing is applied only to the first solution as it targets the best known one.
+    ///
+    /// Please note, that objectives of different priority levels are compared hierarchically, so
+    /// only objectives within the same level can form a trade-off between solutions.
+    #[allow(clippy::type_complexity)]
+    pub fn solve_pareto_front(self) -> Result<(Vec<(Solution, Cost, Vec<f64>)>, Option<Metrics>), String> {
+        let problem = self.problem.clone();
+        let post_processing = self.config.post_processing.clone();
+        let (population, metrics) = EvolutionSimulator::new(self.config)?.run()?;
+
+        let front = population
+            .ranked()
+            .take_while(|(_, rank)| *rank == 0)
+            .enumerate()
+            .map(|(idx, (insertion_ctx, _))| {
+                let processed = post_processing
+                    .as_ref()
+                    .filter(|_| idx == 0)
+                    .and_then(|post_processing| post_processing.process(insertion_ctx));
+                let insertion_ctx = processed.as_ref().unwrap_or(insertion_ctx);
+
+                let solution = insertion_ctx.solution.to_solution(problem.extras.clone());
+                let cost = problem.objective.fitness(insertion_ctx);
+                let fitness = insertion_ctx.get_fitness_values().collect::<Vec<_>>();
+
+                (solution, cost, fitness)
+            })
+            .collect::<Vec<_>>();
+
+        if front.is_empty() {
+            Err("cannot find any solution".to_string())
+        } else {
+            Ok((front, metrics))
+        }
+    }
 }
@@ -0,0 +1,159 @@
+//! The crossover module contains various strategies to combine two parent solutions into one offspring.
+
+use crate::construction::heuristics::{InsertionContext, RouteContext};
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+use hashbrown::HashSet;
+use std::sync::Arc;
+
+/// A trait which specifies logic to combine two solutions.
+pub trait Crossover {
+    /// Combines two parents and returns an offspring which is based on the first one. Jobs which
+    /// are not assigned within offspring are marked as required, so the offspring is expected to
+    /// be repaired by recreate method.
+    fn cross(
+        &self,
+        refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext;
+}
+
+mod route_based_crossover;
+pub use self::route_based_crossover::RouteBasedCrossover;
+
+mod selective_route_exchange;
+pub use self::selective_route_exchange::SelectiveRouteExchange;
+
+/// Provides the way to use one of multiple crossover methods based on their weights.
+pub struct CompositeCrossover {
+    crossovers: Vec<Arc<dyn Crossover + Send + Sync>>,
+    weights: Vec<usize>,
+}
+
+impl CompositeCrossover {
+    /// Creates a new instance of `CompositeCrossover` with passed crossover methods.
+    pub fn new(crossovers: Vec<(Arc<dyn Crossover + Send + Sync>, usize)>) -> Self {
+        let weights = crossovers.iter().map(|(_, weight)| *weight).collect();
+        let crossovers = crossovers.into_iter().map(|(crossover, _)| crossover).collect();
+
+        Self { crossovers, weights }
+    }
+}
+
+impl Default for CompositeCrossover {
+    fn default() -> Self {
+        Self::new(vec![
+            (Arc::new(RouteBasedCrossover::default()), 50),
+            (Arc::new(SelectiveRouteExchange::default()), 50),
+        ])
+    }
+}
+
+impl Crossover for CompositeCrossover {
+    fn cross(
+        &self,
+        refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext {
+        let index = first.random.weighted(self.weights.as_slice());
+
+        self.crossovers[index].cross(refinement_ctx, first, second)
+    }
+}
+
+/// Checks whether route can be moved between solutions.
+fn is_movable_route(insertion_ctx: &InsertionContext, route_ctx: &RouteContext) -> bool {
+    let locked = &insertion_ctx.solution.locked;
+
+    route_ctx.route.tour.has_jobs()
+        && (locked.is_empty() || route_ctx.route.tour.jobs().all(|job| !locked.contains(&job)))
+}
+
+/// Creates an offspring from the first parent by removing routes with given indices and inserting
+/// copies of given routes taken from the second parent. Routes of the first parent which use the
+/// same actors as inserted ones are removed too. Inserted jobs are removed from remaining routes
+/// and jobs which are left without route are marked as required.
+fn exchange_routes(first: &InsertionContext, removed: &[usize], inserted: &[&RouteContext]) -> InsertionContext {
+    let mut offspring = first.deep_copy();
+    let solution = &mut offspring.solution;
+
+    let mut removed = removed.iter().cloned().collect::<HashSet<_>>();
+    let mut inserted_jobs = HashSet::<Job>::default();
+
+    let inserted = inserted
+        .iter()
+        .filter(|route_ctx| {
+            let conflict = solution.routes.iter().position(|rc| rc.route.actor == route_ctx.route.actor);
+
+            // NOTE skip route when its actor is used by route which cannot be removed
+            match conflict {
+                Some(idx) if !removed.contains(&idx) && !is_movable_route(first, &solution.routes[idx]) => false,
+                Some(idx) => {
+                    removed.insert(idx);
+                    true
+                }
+                None => true,
+            }
+        })
+        .map(|route_ctx| {
+            inserted_jobs.extend(route_ctx.route.tour.jobs());
+
+            let mut route_ctx = route_ctx.deep_copy();
+            route_ctx.mark_stale(true);
+            route_ctx
+        })
+        .collect::<Vec<_>>();
+
+    let mut required = Vec::new();
+    let routes = solution.routes.drain(0..).enumerate().collect::<Vec<_>>();
+    for (idx, mut route_ctx) in routes {
+        if removed.contains(&idx) {
+            solution.registry.free_route(&route_ctx);
+            required.extend(route_ctx.route.tour.jobs().filter(|job| !inserted_jobs.contains(job)));
+        } else {
+            let jobs = route_ctx.route.tour.jobs().filter(|job| inserted_jobs.contains(job)).collect::<Vec<_>>();
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+
+            solution.routes.push(route_ctx);
+        }
+    }
+
+    inserted.into_iter().for_each(|route_ctx| {
+        solution.registry.use_route(&route_ctx);
+        solution.routes.push(route_ctx);
+    });
+
+    solution.required.retain(|job| !inserted_jobs.contains(job));
+    solution.ignored.retain(|job| !inserted_jobs.contains(job));
+    solution.unassigned.retain(|job, _| !inserted_jobs.contains(job));
+    solution.required.extend(required);
+
+    offspring.restore();
+
+    offspring
+}
+
+/// Returns indices of randomly selected movable routes.
+fn select_random_routes(insertion_ctx: &InsertionContext, max_routes: usize) -> Vec<usize> {
+    let mut candidates = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| is_movable_route(insertion_ctx, route_ctx))
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() || max_routes == 0 {
+        return vec![];
+    }
+
+    let random = &insertion_ctx.random;
+    let amount = random.uniform_int(1, max_routes.min(candidates.len()) as i32) as usize;
+
+    (0..amount).map(|_| candidates.swap_remove(random.uniform_int(0, candidates.len() as i32 - 1) as usize)).collect()
+}
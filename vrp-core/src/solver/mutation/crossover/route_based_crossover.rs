@@ -0,0 +1,39 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/crossover/route_based_crossover_test.rs"]
+mod route_based_crossover_test;
+
+use super::*;
+
+/// A route based crossover (RBX) which inserts randomly selected routes of the second parent into
+/// the first one. Duplicated jobs are removed from routes of the first parent, jobs from routes
+/// replaced due to actor conflict become required.
+pub struct RouteBasedCrossover {
+    max_routes: usize,
+}
+
+impl RouteBasedCrossover {
+    /// Creates a new instance of `RouteBasedCrossover`.
+    pub fn new(max_routes: usize) -> Self {
+        Self { max_routes }
+    }
+}
+
+impl Default for RouteBasedCrossover {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl Crossover for RouteBasedCrossover {
+    fn cross(
+        &self,
+        _refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext {
+        let selected = select_random_routes(second, self.max_routes);
+        let inserted = selected.iter().map(|&idx| &second.solution.routes[idx]).collect::<Vec<_>>();
+
+        exchange_routes(first, &[], inserted.as_slice())
+    }
+}
@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/crossover/selective_route_exchange_test.rs"]
+mod selective_route_exchange_test;
+
+use super::*;
+
+/// A selective route exchange crossover (SREX), introduced by [`Nagata and Kobayashi (2010)`]:
+/// a random subset of routes is removed from the first parent and replaced by the same amount of
+/// routes of the second parent which have the most jobs in common with removed ones.
+///
+/// [`Nagata and Kobayashi (2010)`]: https://link.springer.com/chapter/10.1007/978-3-642-15844-5_54
+pub struct SelectiveRouteExchange {
+    max_routes: usize,
+}
+
+impl SelectiveRouteExchange {
+    /// Creates a new instance of `SelectiveRouteExchange`.
+    pub fn new(max_routes: usize) -> Self {
+        Self { max_routes }
+    }
+}
+
+impl Default for SelectiveRouteExchange {
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+impl Crossover for SelectiveRouteExchange {
+    fn cross(
+        &self,
+        _refinement_ctx: &RefinementContext,
+        first: &InsertionContext,
+        second: &InsertionContext,
+    ) -> InsertionContext {
+        let removed = select_random_routes(first, self.max_routes);
+        let removed_jobs =
+            removed.iter().flat_map(|&idx| first.solution.routes[idx].route.tour.jobs()).collect::<HashSet<_>>();
+
+        let mut candidates = second
+            .solution
+            .routes
+            .iter()
+            .filter(|route_ctx| is_movable_route(second, route_ctx))
+            .map(|route_ctx| {
+                let common = route_ctx.route.tour.jobs().filter(|job| removed_jobs.contains(job)).count();
+                (route_ctx, common)
+            })
+            .filter(|(_, common)| *common > 0)
+            .collect::<Vec<_>>();
+
+        candidates.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        let inserted = candidates.into_iter().take(removed.len()).map(|(route_ctx, _)| route_ctx).collect::<Vec<_>>();
+
+        exchange_routes(first, removed.as_slice(), inserted.as_slice())
+    }
+}
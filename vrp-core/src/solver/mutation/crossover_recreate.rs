@@ -0,0 +1,71 @@
+//! Contains a mutation operator based on crossover and recreate principle.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/mutation/crossover_recreate_test.rs"]
+mod crossover_recreate_test;
+
+use super::*;
+
+/// A mutation operator which combines two parents using crossover method and then repairs
+/// an offspring using recreate method. When individual is mutated alone, its partner is selected
+/// from population.
+pub struct CrossoverAndRecreate {
+    crossover: Box<dyn Crossover + Send + Sync>,
+    recreate: Box<dyn Recreate + Send + Sync>,
+}
+
+impl CrossoverAndRecreate {
+    /// Creates a new instance of `CrossoverAndRecreate` using given crossover and recreate methods.
+    pub fn new(recreate: Box<dyn Recreate + Send + Sync>, crossover: Box<dyn Crossover + Send + Sync>) -> Self {
+        Self { recreate, crossover }
+    }
+
+    /// Creates a new instance of `CrossoverAndRecreate` using default crossover and recreate methods.
+    pub fn new_from_problem(problem: Arc<Problem>) -> Self {
+        Self {
+            recreate: Box::new(CompositeRecreate::new_from_problem(problem)),
+            crossover: Box::new(CompositeCrossover::default()),
+        }
+    }
+
+    fn mutate_with_partners(
+        &self,
+        refinement_ctx: &RefinementContext,
+        insertion_ctx: &InsertionContext,
+        partners: &[&InsertionContext],
+    ) -> InsertionContext {
+        let objective = &refinement_ctx.problem.objective;
+        let partners = partners
+            .iter()
+            .filter(|partner| objective.total_order(insertion_ctx, partner) != Ordering::Equal)
+            .collect::<Vec<_>>();
+
+        if partners.is_empty() {
+            return insertion_ctx.deep_copy();
+        }
+
+        let partner = partners[insertion_ctx.random.uniform_int(0, partners.len() as i32 - 1) as usize];
+
+        self.recreate.run(refinement_ctx, self.crossover.cross(refinement_ctx, insertion_ctx, partner))
+    }
+}
+
+impl Mutation for CrossoverAndRecreate {
+    fn mutate_one(&self, refinement_ctx: &RefinementContext, insertion_ctx: &InsertionContext) -> InsertionContext {
+        let partners = refinement_ctx.population.select().collect::<Vec<_>>();
+
+        self.mutate_with_partners(refinement_ctx, insertion_ctx, partners.as_slice())
+    }
+
+    fn mutate_all(
+        &self,
+        refinement_ctx: &RefinementContext,
+        individuals: Vec<&InsertionContext>,
+    ) -> Vec<InsertionContext> {
+        let partners = individuals.clone();
+
+        parallel_into_collect(individuals, |insertion_ctx| {
+            self.mutate_with_partners(refinement_ctx, insertion_ctx, partners.as_slice())
+        })
+    }
+}
@@ -10,6 +10,9 @@ use crate::construction::heuristics::InsertionContext;
 use crate::solver::RefinementContext;
 use crate::utils::{parallel_into_collect, unwrap_from_result};
 
+mod crossover;
+pub use self::crossover::*;
+
 mod local;
 pub use self::local::*;
 
@@ -28,6 +31,9 @@ pub use self::local_search::LocalSearch;
 mod ruin_recreate;
 pub use self::ruin_recreate::RuinAndRecreate;
 
+mod crossover_recreate;
+pub use self::crossover_recreate::CrossoverAndRecreate;
+
 use crate::algorithms::nsga2::Objective;
 use crate::models::Problem;
 use std::cmp::Ordering;
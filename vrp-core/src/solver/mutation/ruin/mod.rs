@@ -19,6 +19,9 @@ pub use self::adjusted_string_removal::AdjustedStringRemoval;
 mod cluster_removal;
 pub use self::cluster_removal::ClusterRemoval;
 
+mod problem_characteristics;
+pub use self::problem_characteristics::ProblemCharacteristics;
+
 mod neighbour_removal;
 pub use self::neighbour_removal::NeighbourRemoval;
 
@@ -72,16 +75,25 @@ impl CompositeRuin {
         Self { ruins, weights }
     }
 
-    /// Creates a new instance of `CompositeRuin` with default ruin methods.
+    /// Creates a new instance of `CompositeRuin` with default ruin methods which parameters are
+    /// scaled according to problem characteristics.
     pub fn new_from_problem(problem: Arc<Problem>) -> Self {
-        let random_route = Arc::new(RandomRouteRemoval::default());
-        let random_job = Arc::new(RandomJobRemoval::new(JobRemovalLimit::default()));
+        let characteristics = ProblemCharacteristics::new(problem.as_ref());
+        let limit = |min: usize, max: usize| characteristics.scale_job_limit(JobRemovalLimit::new(min, max, 0.1));
+
+        let random_route = Arc::new(RandomRouteRemoval::new(1, characteristics.get_max_removed_routes(), 0.1));
+        let random_job = Arc::new(RandomJobRemoval::new(limit(8, 16)));
+        let adjusted_string = AdjustedStringRemoval::new(
+            characteristics.get_max_string_length(),
+            characteristics.scale_job_amount(10),
+            0.01,
+        );
 
         Self::new(vec![
             (
                 vec![
-                    (Arc::new(AdjustedStringRemoval::default()), 1.),
-                    (Arc::new(NeighbourRemoval::new(JobRemovalLimit::new(2, 8, 0.1))), 0.1),
+                    (Arc::new(adjusted_string), 1.),
+                    (Arc::new(NeighbourRemoval::new(limit(2, 8))), 0.1),
                     (random_job.clone(), 0.05),
                     (random_route.clone(), 0.01),
                 ],
@@ -89,7 +101,7 @@ impl CompositeRuin {
             ),
             (
                 vec![
-                    (Arc::new(WorstJobRemoval::default()), 1.),
+                    (Arc::new(WorstJobRemoval::new(4, limit(8, 16))), 1.),
                     (random_job.clone(), 0.05),
                     (random_route.clone(), 0.01),
                 ],
@@ -97,7 +109,7 @@ impl CompositeRuin {
             ),
             (
                 vec![
-                    (Arc::new(NeighbourRemoval::default()), 1.),
+                    (Arc::new(NeighbourRemoval::new(limit(8, 16))), 1.),
                     (random_job.clone(), 0.05),
                     (random_route.clone(), 0.01),
                 ],
@@ -107,7 +119,7 @@ impl CompositeRuin {
             (vec![(random_route.clone(), 1.), (random_job.clone(), 0.1)], 2),
             (
                 vec![
-                    (Arc::new(ClusterRemoval::new_with_defaults(problem)), 1.),
+                    (Arc::new(ClusterRemoval::new(problem, 3..9, limit(8, 16))), 1.),
                     (random_job, 0.05),
                     (random_route, 0.01),
                 ],
@@ -0,0 +1,117 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/mutation/ruin/problem_characteristics_test.rs"]
+mod problem_characteristics_test;
+
+use super::JobRemovalLimit;
+use crate::models::common::{TimeSpan, TimeWindow};
+use crate::models::problem::{Job, Single};
+use crate::models::Problem;
+
+/// A problem size which default ruin parameters are tuned for.
+const BASE_PROBLEM_SIZE: f64 = 100.;
+
+/// Keeps problem characteristics which are used to scale ruin parameters, so the same defaults
+/// are suitable for problems of very different size.
+pub struct ProblemCharacteristics {
+    /// Total amount of jobs.
+    pub jobs: usize,
+    /// Total amount of actors (vehicle shifts).
+    pub actors: usize,
+    /// Estimated average amount of jobs per route assuming that all actors are used.
+    pub avg_route_size: f64,
+    /// Time window tightness: zero means no time windows, one means very narrow time windows
+    /// comparing to actor's shift duration.
+    pub tw_tightness: f64,
+}
+
+impl ProblemCharacteristics {
+    /// Creates a new instance of `ProblemCharacteristics` estimated from given problem.
+    pub fn new(problem: &Problem) -> Self {
+        let jobs = problem.jobs.size();
+        let actors = problem.fleet.actors.len();
+
+        Self {
+            jobs,
+            actors,
+            avg_route_size: (jobs as f64 / actors.max(1) as f64).max(1.),
+            tw_tightness: estimate_tw_tightness(problem),
+        }
+    }
+
+    /// Returns a factor to scale amount of removed jobs tuned for a problem with about 100 jobs.
+    /// It grows sublinearly with problem size as reinsertion cost grows faster than ruin size and
+    /// it is decreased for tight time windows as removed jobs are harder to reinsert.
+    pub fn get_ruin_scale(&self) -> f64 {
+        let size_factor = (self.jobs as f64 / BASE_PROBLEM_SIZE).sqrt().clamp(0.5, 4.);
+        let tw_factor = 1. - 0.5 * self.tw_tightness;
+
+        size_factor * tw_factor
+    }
+
+    /// Scales amount of jobs tuned for a problem with about 100 jobs.
+    pub fn scale_job_amount(&self, amount: usize) -> usize {
+        ((amount as f64 * self.get_ruin_scale()).round() as usize).max(1)
+    }
+
+    /// Scales job removal limit tuned for a problem with about 100 jobs.
+    pub fn scale_job_limit(&self, limit: JobRemovalLimit) -> JobRemovalLimit {
+        let min = self.scale_job_amount(limit.min);
+        let max = self.scale_job_amount(limit.max).max(min);
+
+        JobRemovalLimit::new(min, max, limit.threshold)
+    }
+
+    /// Returns max length of removed string of jobs based on average route size.
+    pub fn get_max_string_length(&self) -> usize {
+        (self.avg_route_size.round() as usize).clamp(10, 30)
+    }
+
+    /// Returns max amount of removed routes based on fleet size.
+    pub fn get_max_removed_routes(&self) -> usize {
+        ((self.actors as f64 * 0.05).round() as usize).clamp(4, 16)
+    }
+}
+
+/// Estimates time window tightness as average ratio of job time windows to the longest actor's shift.
+fn estimate_tw_tightness(problem: &Problem) -> f64 {
+    let horizon = problem
+        .fleet
+        .actors
+        .iter()
+        .filter(|actor| actor.detail.time.end < TimeWindow::max().end)
+        .map(|actor| actor.detail.time.end - actor.detail.time.start)
+        .fold(0., f64::max);
+
+    if horizon <= 0. {
+        return 0.;
+    }
+
+    let get_flexibility = |single: &Single| {
+        single
+            .places
+            .iter()
+            .flat_map(|place| place.times.iter())
+            .map(|time| match time {
+                TimeSpan::Window(tw) => tw.end - tw.start,
+                TimeSpan::Offset(offset) => offset.end - offset.start,
+            })
+            .map(|duration| (duration / horizon).min(1.))
+            .fold(None, |acc: Option<f64>, ratio| Some(acc.map_or(ratio, |acc| acc.max(ratio))))
+            .unwrap_or(1.)
+    };
+
+    let flexibilities = problem
+        .jobs
+        .all()
+        .flat_map(|job| match job {
+            Job::Single(single) => vec![get_flexibility(single.as_ref())],
+            Job::Multi(multi) => multi.jobs.iter().map(|single| get_flexibility(single.as_ref())).collect(),
+        })
+        .collect::<Vec<_>>();
+
+    if flexibilities.is_empty() {
+        0.
+    } else {
+        1. - flexibilities.iter().sum::<f64>() / flexibilities.len() as f64
+    }
+}
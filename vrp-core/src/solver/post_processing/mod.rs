@@ -0,0 +1,21 @@
+//! The post processing module contains logic which is applied to the best known solution
+//! when evolution is finished.
+
+use crate::construction::heuristics::InsertionContext;
+use crate::solver::RefinementContext;
+
+mod set_partitioning;
+pub use self::set_partitioning::SetPartitioning;
+
+/// A key to store post processing in refinement context state.
+pub(crate) const POST_PROCESSING_KEY: &str = "post_processing";
+
+/// A trait which specifies the logic to improve the best known solution after evolution.
+pub trait PostProcessing {
+    /// Informs post processing about new generation event, so it can collect
+    /// information from the population.
+    fn on_generation(&self, refinement_ctx: &RefinementContext);
+
+    /// Processes given solution. Returns a new solution if it is considered as better.
+    fn process(&self, insertion_ctx: &InsertionContext) -> Option<InsertionContext>;
+}
@@ -0,0 +1,212 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/post_processing/set_partitioning_test.rs"]
+mod set_partitioning_test;
+
+use super::*;
+use crate::algorithms::nsga2::Objective;
+use crate::algorithms::set_partitioning::{solve_set_partitioning, Column};
+use crate::construction::heuristics::RouteContext;
+use crate::models::problem::{Actor, Job};
+use crate::models::Problem;
+use crate::utils::{compare_floats, TimeQuota};
+use hashbrown::HashMap;
+use std::cmp::Ordering;
+use std::iter::once;
+use std::sync::{Arc, Mutex};
+
+/// Identifies route by its actor and a set of served jobs: both are represented by their
+/// indices in the problem definition.
+type RouteKey = (usize, Vec<usize>);
+
+/// A post processing which collects distinct routes discovered during evolution and then
+/// assembles a new solution from them by solving set partitioning problem: each job assigned
+/// in the best known solution should be covered exactly once, each actor can be used at most once.
+pub struct SetPartitioning {
+    max_routes: usize,
+    node_limit: usize,
+    max_time: f64,
+    pool: Mutex<RoutePool>,
+}
+
+impl SetPartitioning {
+    /// Creates a new instance of `SetPartitioning`.
+    ///
+    /// * `max_routes` - a max amount of routes kept in the route pool: routes with the lowest
+    ///   cost per job are kept.
+    /// * `node_limit` - a max amount of nodes explored by branch and bound algorithm.
+    /// * `max_time` - a max time in seconds spent by branch and bound algorithm. It is a separate
+    ///   budget as post processing runs when the solver's termination criteria are already met.
+    pub fn new(max_routes: usize, node_limit: usize, max_time: f64) -> Self {
+        Self { max_routes, node_limit, max_time, pool: Mutex::new(RoutePool::default()) }
+    }
+
+    /// Returns amount of routes in the route pool.
+    pub fn size(&self) -> usize {
+        self.pool.lock().unwrap().routes.len()
+    }
+}
+
+impl Default for SetPartitioning {
+    fn default() -> Self {
+        Self::new(1000, 10_000, 5.)
+    }
+}
+
+impl PostProcessing for SetPartitioning {
+    fn on_generation(&self, refinement_ctx: &RefinementContext) {
+        let mut pool = self.pool.lock().unwrap();
+        pool.init(refinement_ctx.problem.as_ref());
+
+        refinement_ctx
+            .population
+            .ranked()
+            .flat_map(|(individual, _)| individual.solution.routes.iter())
+            .for_each(|route_ctx| pool.add(route_ctx));
+
+        pool.shrink(self.max_routes);
+    }
+
+    fn process(&self, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let mut pool = self.pool.lock().unwrap();
+        pool.init(insertion_ctx.problem.as_ref());
+        let solution = &insertion_ctx.solution;
+
+        let job_index = solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+            .zip(0_usize..)
+            .collect::<HashMap<Job, usize>>();
+        let required = job_index.len();
+
+        // NOTE routes of the best known solution go first, so they define the initial partitioning
+        let best_keys =
+            solution.routes.iter().filter_map(|route_ctx| pool.get_route_key(route_ctx)).collect::<Vec<_>>();
+        let candidates = solution
+            .routes
+            .iter()
+            .chain(pool.routes.iter().filter(|(key, _)| !best_keys.contains(key)).map(|(_, route_ctx)| route_ctx));
+
+        let mut actor_index = HashMap::<Arc<Actor>, usize>::default();
+        let (route_ctxs, items): (Vec<_>, Vec<_>) = candidates
+            .filter_map(|route_ctx| {
+                let jobs = route_ctx
+                    .route
+                    .tour
+                    .jobs()
+                    .map(|job| job_index.get(&job).cloned())
+                    .collect::<Option<Vec<_>>>()
+                    .filter(|jobs| !jobs.is_empty())?;
+
+                let next_index = required + actor_index.len();
+                let actor_idx = *actor_index.entry(route_ctx.route.actor.clone()).or_insert(next_index);
+
+                Some((route_ctx, jobs.into_iter().chain(once(actor_idx)).collect::<Vec<_>>()))
+            })
+            .unzip();
+
+        // NOTE penalize each route in order to prefer solutions with less routes
+        let route_penalty = route_ctxs.iter().map(|route_ctx| route_ctx.get_route_cost()).sum::<f64>() + 1.;
+        let columns = route_ctxs
+            .iter()
+            .zip(items)
+            .map(|(route_ctx, items)| Column { items, cost: route_penalty + route_ctx.get_route_cost() })
+            .collect::<Vec<_>>();
+
+        let best_size = solution.routes.len();
+        let initial_cost = columns.iter().take(best_size).map(|column| column.cost).sum::<f64>();
+
+        let (selected, _) = solve_set_partitioning(
+            columns.as_slice(),
+            required,
+            self.node_limit,
+            Some(&TimeQuota::new(self.max_time)),
+            Some(((0..best_size).collect(), initial_cost)),
+        )?;
+
+        let mut new_ctx = insertion_ctx.deep_copy();
+        let solution = &mut new_ctx.solution;
+
+        for route_ctx in solution.routes.iter() {
+            solution.registry.free_route(route_ctx);
+        }
+
+        solution.routes = selected
+            .into_iter()
+            .map(|idx| {
+                let mut route_ctx = route_ctxs[idx].deep_copy();
+                route_ctx.mark_stale(true);
+                route_ctx
+            })
+            .collect();
+
+        for route_ctx in solution.routes.iter() {
+            solution.registry.use_route(route_ctx);
+        }
+
+        new_ctx.restore();
+
+        if insertion_ctx.problem.objective.total_order(&new_ctx, insertion_ctx) == Ordering::Less {
+            Some(new_ctx)
+        } else {
+            None
+        }
+    }
+}
+
+/// Keeps the cheapest route for each actor and set of served jobs.
+#[derive(Default)]
+struct RoutePool {
+    job_index: HashMap<Job, usize>,
+    actor_index: HashMap<Arc<Actor>, usize>,
+    routes: HashMap<RouteKey, RouteContext>,
+}
+
+impl RoutePool {
+    fn init(&mut self, problem: &Problem) {
+        if self.job_index.is_empty() {
+            self.job_index = problem.jobs.all().zip(0_usize..).collect();
+            self.actor_index = problem.fleet.actors.iter().cloned().zip(0_usize..).collect();
+        }
+    }
+
+    fn add(&mut self, route_ctx: &RouteContext) {
+        if let Some(key) = self.get_route_key(route_ctx) {
+            match self.routes.get(&key) {
+                Some(known) if known.get_route_cost() <= route_ctx.get_route_cost() => {}
+                _ => {
+                    self.routes.insert(key, route_ctx.deep_copy());
+                }
+            }
+        }
+    }
+
+    fn shrink(&mut self, max_routes: usize) {
+        if self.routes.len() <= max_routes {
+            return;
+        }
+
+        let get_cost_per_job =
+            |(key, route_ctx): &(RouteKey, RouteContext)| route_ctx.get_route_cost() / key.1.len() as f64;
+
+        let mut routes = self.routes.drain().collect::<Vec<_>>();
+        routes.sort_by(|a, b| compare_floats(get_cost_per_job(a), get_cost_per_job(b)));
+        routes.truncate(max_routes);
+
+        self.routes = routes.into_iter().collect();
+    }
+
+    fn get_route_key(&self, route_ctx: &RouteContext) -> Option<RouteKey> {
+        let actor = *self.actor_index.get(&route_ctx.route.actor)?;
+        let mut jobs =
+            route_ctx.route.tour.jobs().map(|job| self.job_index.get(&job).cloned()).collect::<Option<Vec<_>>>()?;
+
+        if jobs.is_empty() {
+            return None;
+        }
+
+        jobs.sort_unstable();
+
+        Some((actor, jobs))
+    }
+}
@@ -6,6 +6,7 @@ mod telemetry_test;
 
 use crate::algorithms::nsga2::Objective;
 use crate::construction::heuristics::InsertionContext;
+use crate::models::Solution;
 use crate::solver::population::SelectionPhase;
 use crate::solver::{RefinementContext, Statistics};
 use crate::utils::Timer;
@@ -16,6 +17,31 @@ use std::sync::Arc;
 /// A logger type which is called with various information regarding the work done by the VRP solver.
 pub type InfoLogger = Arc<dyn Fn(&str)>;
 
+/// A callback type which is called with search progress after each generation.
+pub type ProgressCallback = Arc<dyn Fn(&Progress)>;
+
+/// Represents search progress: the best known individual's state after specific generation.
+pub struct Progress {
+    /// Generation sequence number.
+    pub generation: usize,
+    /// Best known solution cost.
+    pub cost: f64,
+    /// Total amount of unassigned jobs in best known solution.
+    pub unassigned: usize,
+}
+
+/// A callback type which is called with search progress and the best known solution on checkpoint.
+pub type CheckpointCallback = Arc<dyn Fn(&Progress, &Solution)>;
+
+/// Specifies how often checkpoint is made.
+#[derive(Clone, Copy)]
+pub enum CheckpointInterval {
+    /// Checkpoint is made every N generations, N should be greater than zero.
+    Generations(usize),
+    /// Checkpoint is made every N seconds.
+    Seconds(usize),
+}
+
 /// Encapsulates different measurements regarding algorithm evaluation.
 pub struct Metrics {
     /// Algorithm duration.
@@ -108,6 +134,9 @@ pub struct Telemetry {
     mode: TelemetryMode,
     improvement_tracker: ImprovementTracker,
     next_generation: Option<usize>,
+    progress: Option<ProgressCallback>,
+    checkpoint: Option<(CheckpointInterval, CheckpointCallback)>,
+    checkpoint_time: Timer,
 }
 
 impl Telemetry {
@@ -119,12 +148,28 @@ impl Telemetry {
             mode,
             improvement_tracker: ImprovementTracker::new(1000),
             next_generation: None,
+            progress: None,
+            checkpoint: None,
+            checkpoint_time: Timer::start(),
         }
     }
 
+    /// Sets a callback which is called with search progress after each generation
+    /// independently from telemetry mode.
+    pub fn set_progress(&mut self, progress: ProgressCallback) {
+        self.progress = Some(progress);
+    }
+
+    /// Sets a callback which is called with the best known solution with given interval
+    /// independently from telemetry mode.
+    pub fn set_checkpoint(&mut self, interval: CheckpointInterval, checkpoint: CheckpointCallback) {
+        self.checkpoint = Some((interval, checkpoint));
+    }
+
     /// Starts telemetry reporting.
     pub fn start(&mut self) {
         self.time = Timer::start();
+        self.checkpoint_time = Timer::start();
     }
 
     /// Reports initial solution statistics.
@@ -166,6 +211,9 @@ impl Telemetry {
 
         self.next_generation = Some(generation + 1);
 
+        self.on_progress(refinement_ctx);
+        self.on_checkpoint(refinement_ctx);
+
         let (log_best, log_population, track_population, should_dump_population) = match &self.mode {
             TelemetryMode::None => return,
             TelemetryMode::OnlyLogging { log_best, log_population, dump_population, .. } => {
@@ -195,6 +243,47 @@ impl Telemetry {
         }
     }
 
+    /// Reports search progress to the progress callback.
+    fn on_progress(&self, refinement_ctx: &RefinementContext) {
+        if let Some(progress) = &self.progress {
+            if let Some((best_individual, _)) = refinement_ctx.population.ranked().next() {
+                progress.deref()(&Progress {
+                    generation: refinement_ctx.statistics.generation,
+                    cost: refinement_ctx.problem.objective.fitness(best_individual),
+                    unassigned: best_individual.solution.unassigned.len(),
+                });
+            }
+        }
+    }
+
+    /// Passes the best known solution to the checkpoint callback if checkpoint is due.
+    fn on_checkpoint(&mut self, refinement_ctx: &RefinementContext) {
+        let is_due = match &self.checkpoint {
+            Some((CheckpointInterval::Generations(generations), _)) => {
+                refinement_ctx.statistics.generation % *generations == 0
+            }
+            Some((CheckpointInterval::Seconds(seconds), _)) => self.checkpoint_time.elapsed_secs() >= *seconds as u64,
+            None => false,
+        };
+
+        if !is_due {
+            return;
+        }
+
+        if let (Some((_, checkpoint)), Some((best_individual, _))) =
+            (&self.checkpoint, refinement_ctx.population.ranked().next())
+        {
+            let progress = Progress {
+                generation: refinement_ctx.statistics.generation,
+                cost: refinement_ctx.problem.objective.fitness(best_individual),
+                unassigned: best_individual.solution.unassigned.len(),
+            };
+
+            checkpoint.deref()(&progress, &best_individual.solution.to_solution(refinement_ctx.problem.extras.clone()));
+            self.checkpoint_time = Timer::start();
+        }
+    }
+
     /// Reports population state.
     fn on_population(
         &mut self,
@@ -246,6 +335,22 @@ impl Telemetry {
         }
     }
 
+    /// Logs best individuals of each island.
+    pub fn on_islands(&mut self, refinement_ctx: &RefinementContext, islands: &[RefinementContext]) {
+        match &self.mode {
+            TelemetryMode::OnlyLogging { .. } | TelemetryMode::All { .. } => {}
+            _ => return,
+        }
+
+        self.log(format!("[{}s] islands state:", self.time.elapsed_secs()).as_str());
+
+        islands
+            .iter()
+            .filter_map(|island| island.population.ranked().next())
+            .map(|(insertion_ctx, rank)| self.get_individual_metrics(refinement_ctx, insertion_ctx, rank))
+            .for_each(|metrics| self.log_individual(&metrics, None));
+    }
+
     /// Reports final statistic.
     pub fn on_result(&mut self, refinement_ctx: &RefinementContext) {
         let should_log_population = match &self.mode {
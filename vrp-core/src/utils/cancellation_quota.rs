@@ -0,0 +1,33 @@
+#[cfg(test)]
+#[path = "../../tests/unit/utils/cancellation_quota_test.rs"]
+mod cancellation_quota_test;
+
+use crate::construction::Quota;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// A quota which is reached when cancellation is requested via external flag.
+/// Cloned instances share the same flag, so one of them can be passed to the solver while
+/// another one is kept as a cancellation handle.
+#[derive(Clone, Default)]
+pub struct CancellationQuota {
+    is_cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationQuota {
+    /// Creates a new instance of `CancellationQuota` driven by given flag.
+    pub fn new(is_cancelled: Arc<AtomicBool>) -> Self {
+        Self { is_cancelled }
+    }
+
+    /// Requests cancellation.
+    pub fn cancel(&self) {
+        self.is_cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Quota for CancellationQuota {
+    fn is_reached(&self) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+}
@@ -1,5 +1,8 @@
 //! A collection of various utility helpers.
 
+mod cancellation_quota;
+pub use self::cancellation_quota::CancellationQuota;
+
 mod comparison;
 pub use self::comparison::*;
 
@@ -1,6 +1,7 @@
 use crate::algorithms::geometry::Point;
+use crate::construction::heuristics::InsertionContext;
 use crate::helpers::construction::constraints::create_constraint_pipeline_with_transport;
-use crate::helpers::models::domain::test_random;
+use crate::helpers::models::domain::{get_customer_id, test_random};
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
 use crate::models::common::Location;
@@ -9,7 +10,7 @@ use crate::models::solution::{Registry, Route};
 use crate::models::{Problem, Solution};
 use crate::solver::population::{Elitism, Population};
 use crate::solver::RefinementContext;
-use crate::utils::DefaultRandom;
+use crate::utils::{DefaultRandom, Random};
 use std::sync::Arc;
 
 /// Creates default population.
@@ -21,6 +22,39 @@ pub fn create_default_refinement_ctx(problem: Arc<Problem>) -> RefinementContext
     RefinementContext::new(problem.clone(), create_default_population(problem.clone()), None)
 }
 
+/// Creates insertion context with routes specified by vehicle id and customer ids.
+pub fn create_insertion_ctx_with_routes(
+    problem: Arc<Problem>,
+    routes: &[(&str, Vec<&str>)],
+    random: Arc<dyn Random + Send + Sync>,
+) -> InsertionContext {
+    let mut registry = Registry::new(problem.fleet.as_ref(), test_random());
+    let routes = routes
+        .iter()
+        .map(|(vehicle, customers)| {
+            let activities = customers
+                .iter()
+                .map(|customer| {
+                    let job = problem.jobs.all().find(|job| get_customer_id(job) == *customer).unwrap();
+                    let single = job.to_single().clone();
+                    let mut activity = test_activity_with_job(single.clone());
+                    activity.place.location = single.places.first().and_then(|place| place.location).unwrap();
+                    activity
+                })
+                .collect();
+
+            let route = create_route_with_activities(problem.fleet.as_ref(), vehicle, activities);
+            registry.use_actor(&route.actor);
+
+            route
+        })
+        .collect();
+
+    let solution = Solution { registry, routes, unassigned: Default::default(), extras: problem.extras.clone() };
+
+    InsertionContext::new_from_solution(problem, (solution, None), random)
+}
+
 /// Generates matrix routes. See `generate_matrix_routes`.
 pub fn generate_matrix_routes_with_defaults(rows: usize, cols: usize, is_open_vrp: bool) -> (Problem, Solution) {
     generate_matrix_routes(
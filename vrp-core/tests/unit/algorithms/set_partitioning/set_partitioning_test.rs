@@ -0,0 +1,47 @@
+use super::*;
+use crate::utils::CancellationQuota;
+
+fn create_columns(data: Vec<(Vec<usize>, f64)>) -> Vec<Column> {
+    data.into_iter().map(|(items, cost)| Column { items, cost }).collect()
+}
+
+parameterized_test! {can_solve_set_partitioning, (columns, required, initial, expected), {
+    can_solve_set_partitioning_impl(create_columns(columns), required, initial, expected);
+}}
+
+can_solve_set_partitioning! {
+    case01: (vec![(vec![0, 1], 10.), (vec![0], 4.), (vec![1], 4.)], 2, None, Some((vec![1, 2], 8.))),
+    case02: (vec![(vec![0, 1], 7.), (vec![0], 4.), (vec![1], 4.)], 2, None, Some((vec![0], 7.))),
+    case03: (vec![(vec![0, 1], 7.), (vec![0], 4.), (vec![1], 4.)], 2, Some((vec![0], 7.)), None),
+    case04: (vec![(vec![0, 1], 10.), (vec![0], 4.)], 2, None, Some((vec![0], 10.))),
+    case05: (vec![(vec![0], 4.)], 2, None, None),
+
+    case06: (vec![(vec![0, 2], 3.), (vec![1, 2], 3.), (vec![0, 3], 5.), (vec![1, 3], 5.)], 2, None, Some((vec![0, 3], 8.))),
+    case07: (vec![(vec![0, 1, 2], 10.), (vec![0, 2], 3.), (vec![1, 2], 3.)], 2, None, Some((vec![0], 10.))),
+}
+
+fn can_solve_set_partitioning_impl(
+    columns: Vec<Column>,
+    required: usize,
+    initial: Option<Partitioning>,
+    expected: Option<Partitioning>,
+) {
+    let result = solve_set_partitioning(columns.as_slice(), required, 1000, None, initial);
+
+    let result = result.map(|(mut selected, cost)| {
+        selected.sort();
+        (selected, cost)
+    });
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_stop_search_when_quota_is_reached() {
+    let columns = create_columns(vec![(vec![0, 1], 10.), (vec![0], 4.), (vec![1], 4.)]);
+    let quota = CancellationQuota::default();
+    quota.cancel();
+
+    let result = solve_set_partitioning(columns.as_slice(), 2, 1000, Some(&quota), None);
+
+    assert!(result.is_none());
+}
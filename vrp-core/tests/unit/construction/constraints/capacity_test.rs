@@ -4,7 +4,7 @@ use crate::helpers::construction::constraints::*;
 use crate::helpers::models::domain::create_empty_solution_context;
 use crate::helpers::models::problem::*;
 use crate::helpers::models::solution::*;
-use crate::models::common::SingleDimLoad;
+use crate::models::common::{CapacityDimension, Demand, SingleDimLoad};
 use crate::models::problem::{Job, Vehicle};
 use crate::models::solution::Activity;
 
@@ -130,3 +130,33 @@ fn can_evaluate_demand_on_activity_impl(
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn can_calculate_capacity_state_values_with_initial_load() {
+    let mut vehicle = create_test_vehicle(10);
+    vehicle.dimens.set_initial_load(SingleDimLoad::new(3));
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle).build();
+    let loaded_delivery = Demand::<SingleDimLoad> {
+        pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+        delivery: (SingleDimLoad::default(), SingleDimLoad::new(2)),
+    };
+    let mut ctx = create_route_context_with_activities(
+        &fleet,
+        "v1",
+        vec![
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(-1))),
+            test_activity_with_job(test_single_with_simple_demand(loaded_delivery)),
+            test_activity_with_job(test_single_with_simple_demand(create_simple_demand(4))),
+        ],
+    );
+
+    create_constraint_pipeline_with_simple_capacity().accept_route_state(&mut ctx);
+
+    let tour = &ctx.route.tour;
+    let state = &ctx.state;
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.start()), 4);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.get(1)), 3);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.get(2)), 1);
+    assert_eq!(get_simple_capacity_state(CURRENT_CAPACITY_KEY, state, tour.get(3)), 5);
+    assert_eq!(get_simple_capacity_state(MAX_FUTURE_CAPACITY_KEY, state, tour.start()), 5);
+}
@@ -204,6 +204,39 @@ mod single {
             unreachable!()
         }
     }
+
+    parameterized_test! {can_evaluate_job_constraint_per_actor, (job_location, expected), {
+        can_evaluate_job_constraint_per_actor_impl(job_location, expected);
+    }}
+
+    can_evaluate_job_constraint_per_actor! {
+        case01: (3, vec![]),
+        case02: (1111, vec![("v1", 1), ("v2", 1)]),
+    }
+
+    fn can_evaluate_job_constraint_per_actor_impl(job_location: Location, expected: Vec<(&str, i32)>) {
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver_with_costs(empty_costs()))
+            .add_vehicles(vec![VehicleBuilder::default().id("v1").build(), VehicleBuilder::default().id("v2").build()])
+            .build();
+        let mut registry = Registry::new(&fleet, test_random());
+        let actor = registry.all().find(|actor| get_vehicle_id(&actor.vehicle) == "v1").unwrap();
+        registry.use_actor(&actor);
+        let ctx = create_insertion_context(
+            registry,
+            create_constraint_pipeline_with_transport(),
+            vec![RouteContext::new(actor)],
+        );
+        let job = Job::Single(test_single_with_location(Some(job_location)));
+
+        let mut result = evaluate_job_constraint_per_actor(&job, &ctx)
+            .into_iter()
+            .map(|(actor, code)| (get_vehicle_id(&actor.vehicle).clone(), code))
+            .collect::<Vec<_>>();
+        result.sort();
+
+        assert_eq!(result, expected.into_iter().map(|(id, code)| (id.to_string(), code)).collect::<Vec<_>>());
+    }
 }
 
 mod multi {
@@ -1,8 +1,12 @@
 use super::*;
+use crate::algorithms::nsga2::MultiObjective;
 use crate::construction::Quota;
+use crate::helpers::solver::create_default_population;
 use crate::models::examples::create_example_problem;
-use crate::solver::TelemetryMode;
-use std::sync::Arc;
+use crate::models::Solution;
+use crate::solver::{Builder, CheckpointCallback, CheckpointInterval, Progress, ProgressCallback, TelemetryMode};
+use crate::utils::CancellationQuota;
+use std::sync::{Arc, Mutex};
 
 parameterized_test! {can_enable_telemetry_metrics, mode, {
         can_enable_telemetry_metrics_impl(mode);
@@ -72,3 +76,161 @@ fn can_use_quota() {
     assert_eq!(metrics.generations, 0);
     assert_eq!(metrics.evolution.len(), 1);
 }
+
+#[test]
+fn can_run_island_model() {
+    let problem = create_example_problem();
+    let populations = (0..2)
+        .map::<PopulationFactory, _>(|_| {
+            let problem = problem.clone();
+            Arc::new(move || create_default_population(problem.clone()))
+        })
+        .collect();
+    let messages = Arc::new(Mutex::new(Vec::<String>::new()));
+    let logger = {
+        let messages = messages.clone();
+        Arc::new(move |message: &str| messages.lock().unwrap().push(message.to_string()))
+    };
+    let config = EvolutionConfig {
+        strategy: Arc::new(RunIslands::new(populations, 10, 1)),
+        termination: Arc::new(MaxGeneration::new(50)),
+        telemetry: Telemetry::new(TelemetryMode::All {
+            logger,
+            log_best: 100,
+            log_population: 1000,
+            track_population: 100,
+            dump_population: false,
+        }),
+        ..EvolutionConfig::new(problem)
+    };
+    let evolution = EvolutionSimulator::new(config).unwrap();
+
+    let (population, metrics) = evolution.run().unwrap();
+
+    let metrics = metrics.expect("metrics are empty");
+    assert_eq!(metrics.generations, 50);
+    assert!(population.size() > 0);
+    let messages = messages.lock().unwrap();
+    let islands_idx = messages.iter().position(|message| message.contains("islands state")).expect("no islands state");
+    assert!(messages.iter().skip(islands_idx + 1).take(2).all(|message| message.contains("rank:")));
+}
+
+struct CountingPostProcessing {
+    calls: Mutex<usize>,
+}
+
+impl PostProcessing for CountingPostProcessing {
+    fn on_generation(&self, _: &RefinementContext) {
+        *self.calls.lock().unwrap() += 1;
+    }
+
+    fn process(&self, _: &InsertionContext) -> Option<InsertionContext> {
+        None
+    }
+}
+
+#[test]
+fn can_copy_shared_state_to_islands() {
+    let problem = create_example_problem();
+    let post_processing = Arc::new(CountingPostProcessing { calls: Mutex::new(0) });
+    let populations = (0..2)
+        .map::<PopulationFactory, _>(|_| {
+            let problem = problem.clone();
+            Arc::new(move || create_default_population(problem.clone()))
+        })
+        .collect();
+    let config = EvolutionConfig {
+        strategy: Arc::new(RunIslands::new(populations, 10, 1)),
+        termination: Arc::new(MaxGeneration::new(20)),
+        post_processing: Some(post_processing.clone()),
+        ..EvolutionConfig::new(problem)
+    };
+
+    EvolutionSimulator::new(config).unwrap().run().unwrap();
+
+    // NOTE post processing is informed about each generation of the main context and of both islands
+    assert!(*post_processing.calls.lock().unwrap() >= 3 * 20);
+}
+
+#[test]
+fn can_copy_only_shared_state() {
+    let post_processing: Arc<dyn PostProcessing + Send + Sync> =
+        Arc::new(CountingPostProcessing { calls: Mutex::new(0) });
+    let mut state = RefinementState::new();
+    state.insert(POST_PROCESSING_KEY.to_string(), Box::new(post_processing));
+    state.insert("coeff_var".to_string(), Box::new(vec![0_f64; 10]));
+
+    let shared = copy_shared_state(&state);
+
+    assert_eq!(shared.len(), 1);
+    assert!(get_post_processing(&shared).is_some());
+}
+
+#[test]
+fn can_report_progress_and_stop_search_on_cancellation() {
+    let quota = CancellationQuota::default();
+    let generations = Arc::new(Mutex::new(vec![]));
+    let progress: ProgressCallback = {
+        let quota = quota.clone();
+        let generations = generations.clone();
+        Arc::new(move |progress: &Progress| {
+            assert_eq!(progress.unassigned, 0);
+            generations.lock().unwrap().push(progress.generation);
+            if progress.generation == 10 {
+                quota.cancel();
+            }
+        })
+    };
+
+    let (solution, cost, _) = Builder::new(create_example_problem())
+        .with_max_generations(Some(1000))
+        .with_max_time(Some(300))
+        .with_progress(progress)
+        .with_quota(Arc::new(quota))
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert_eq!(generations.lock().unwrap().clone(), (0..=10).collect::<Vec<_>>());
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.unassigned.len(), 0);
+}
+
+#[test]
+fn can_make_checkpoints_with_best_known_solution() {
+    let checkpoints = Arc::new(Mutex::new(vec![]));
+    let checkpoint: CheckpointCallback = {
+        let checkpoints = checkpoints.clone();
+        Arc::new(move |progress: &Progress, solution: &Solution| {
+            checkpoints.lock().unwrap().push((progress.generation, progress.unassigned, solution.unassigned.len()));
+        })
+    };
+
+    Builder::new(create_example_problem())
+        .with_max_generations(Some(10))
+        .with_checkpoint(CheckpointInterval::Generations(3), checkpoint)
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert_eq!(checkpoints.lock().unwrap().clone(), vec![(0, 0, 0), (3, 0, 0), (6, 0, 0), (9, 0, 0)]);
+}
+
+#[test]
+fn can_solve_pareto_front() {
+    let problem = create_example_problem();
+
+    let (front, _) = Builder::new(problem.clone())
+        .with_max_generations(Some(10))
+        .build()
+        .and_then(|solver| solver.solve_pareto_front())
+        .unwrap();
+
+    assert!(!front.is_empty());
+    assert_eq!(front[0].1, 42.);
+    front.iter().for_each(|(solution, cost, fitness)| {
+        assert_eq!(solution.unassigned.len(), 0);
+        assert_eq!(fitness.len(), problem.objective.objectives().count());
+        assert_eq!(fitness.last().cloned(), Some(*cost));
+    });
+}
@@ -0,0 +1,25 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::*;
+use crate::helpers::utils::random::FakeRandom;
+
+#[test]
+fn can_exchange_routes_with_common_jobs() {
+    let (problem, _) = generate_matrix_routes_with_defaults(2, 2, false);
+    let problem = Arc::new(problem);
+    let first = create_insertion_ctx_with_routes(
+        problem.clone(),
+        &[("0", vec!["c0", "c1"]), ("1", vec!["c2", "c3"])],
+        Arc::new(FakeRandom::new(vec![1, 0], vec![])),
+    );
+    let second = create_insertion_ctx_with_routes(
+        problem.clone(),
+        &[("1", vec!["c2"]), ("0", vec!["c1", "c0", "c3"])],
+        test_random(),
+    );
+
+    let offspring = SelectiveRouteExchange::new(1).cross(&create_default_refinement_ctx(problem), &first, &second);
+
+    assert_eq!(get_customer_ids_from_routes(&offspring), vec![vec!["c2"], vec!["c1", "c0", "c3"]]);
+    assert!(offspring.solution.required.is_empty());
+}
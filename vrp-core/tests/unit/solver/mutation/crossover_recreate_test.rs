@@ -0,0 +1,31 @@
+use super::*;
+use crate::helpers::models::domain::test_random;
+use crate::helpers::solver::*;
+
+#[test]
+fn can_combine_parents_and_repair_offspring() {
+    let (problem, _) = generate_matrix_routes_with_defaults(2, 2, false);
+    let problem = Arc::new(problem);
+    let first = create_insertion_ctx_with_routes(
+        problem.clone(),
+        &[("0", vec!["c0", "c1"]), ("1", vec!["c2", "c3"])],
+        test_random(),
+    );
+    let second = create_insertion_ctx_with_routes(
+        problem.clone(),
+        &[("1", vec!["c1", "c2"]), ("0", vec!["c0", "c3"])],
+        test_random(),
+    );
+    let mutation = CrossoverAndRecreate::new_from_problem(problem.clone());
+
+    let offspring = mutation.mutate_all(&create_default_refinement_ctx(problem), vec![&first, &second]);
+
+    assert_eq!(offspring.len(), 2);
+    offspring.iter().for_each(|insertion_ctx| {
+        let assigned =
+            insertion_ctx.solution.routes.iter().map(|route_ctx| route_ctx.route.tour.job_count()).sum::<usize>();
+        assert_eq!(assigned, 4);
+        assert!(insertion_ctx.solution.required.is_empty());
+        assert!(insertion_ctx.solution.unassigned.is_empty());
+    });
+}
@@ -0,0 +1,87 @@
+use super::*;
+use crate::construction::constraints::ConstraintPipeline;
+use crate::helpers::models::domain::create_problem_with_constraint_jobs_and_fleet;
+use crate::helpers::models::problem::*;
+use crate::utils::compare_floats;
+use std::cmp::Ordering;
+
+fn create_characteristics(jobs: usize, actors: usize, tw_tightness: f64) -> ProblemCharacteristics {
+    ProblemCharacteristics { jobs, actors, avg_route_size: jobs as f64 / actors as f64, tw_tightness }
+}
+
+parameterized_test! {can_estimate_problem_characteristics, (jobs, vehicles, tw_size, expected), {
+    can_estimate_problem_characteristics_impl(jobs, vehicles, tw_size, expected);
+}}
+
+can_estimate_problem_characteristics! {
+    case01_no_tw: (10, 2, None, (5., 0.)),
+    case02_wide_tw: (10, 2, Some(1000.), (5., 0.)),
+    case03_tight_tw: (10, 2, Some(100.), (5., 0.9)),
+    case04_more_vehicles: (2, 4, Some(500.), (1., 0.5)),
+}
+
+fn can_estimate_problem_characteristics_impl(jobs: usize, vehicles: usize, tw_size: Option<f64>, expected: (f64, f64)) {
+    let jobs = (0..jobs)
+        .map(|idx| {
+            let mut builder = SingleBuilder::default();
+            builder.id(format!("job{}", idx).as_str());
+            if let Some(tw_size) = tw_size {
+                builder.times(vec![TimeWindow::new(0., tw_size)]);
+            } else {
+                builder.times(vec![]);
+            }
+            builder.build_as_job_ref()
+        })
+        .collect::<Vec<_>>();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles((0..vehicles).map(|idx| test_vehicle_with_id(format!("v{}", idx).as_str())).collect())
+        .build();
+    let problem = create_problem_with_constraint_jobs_and_fleet(ConstraintPipeline::default(), jobs, fleet);
+
+    let characteristics = ProblemCharacteristics::new(problem.as_ref());
+
+    assert_eq!(characteristics.jobs, problem.jobs.size());
+    assert_eq!(characteristics.actors, vehicles);
+    assert_eq!(compare_floats(characteristics.avg_route_size, expected.0), Ordering::Equal);
+    assert_eq!(compare_floats(characteristics.tw_tightness, expected.1), Ordering::Equal);
+}
+
+parameterized_test! {can_scale_job_limit, (jobs, tw_tightness, expected), {
+    can_scale_job_limit_impl(jobs, tw_tightness, expected);
+}}
+
+can_scale_job_limit! {
+    case01_small: (25, 0., (4, 8)),
+    case02_base: (100, 0., (8, 16)),
+    case03_medium: (400, 0., (16, 32)),
+    case04_large: (5000, 0., (32, 64)),
+    case05_tight_tw: (400, 1., (8, 16)),
+}
+
+fn can_scale_job_limit_impl(jobs: usize, tw_tightness: f64, expected: (usize, usize)) {
+    let characteristics = create_characteristics(jobs, 10, tw_tightness);
+
+    let limit = characteristics.scale_job_limit(JobRemovalLimit::new(8, 16, 0.1));
+
+    assert_eq!((limit.min, limit.max), expected);
+    assert_eq!(compare_floats(limit.threshold, 0.1), Ordering::Equal);
+}
+
+parameterized_test! {can_get_route_based_limits, (jobs, actors, expected), {
+    can_get_route_based_limits_impl(jobs, actors, expected);
+}}
+
+can_get_route_based_limits! {
+    case01_small_fleet: (100, 10, (10, 4)),
+    case02_long_routes: (1000, 50, (20, 4)),
+    case03_very_long_routes: (5000, 100, (30, 5)),
+    case04_large_fleet: (5000, 1000, (10, 16)),
+}
+
+fn can_get_route_based_limits_impl(jobs: usize, actors: usize, expected: (usize, usize)) {
+    let characteristics = create_characteristics(jobs, actors, 0.);
+
+    assert_eq!(characteristics.get_max_string_length(), expected.0);
+    assert_eq!(characteristics.get_max_removed_routes(), expected.1);
+}
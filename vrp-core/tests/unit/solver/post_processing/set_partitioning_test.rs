@@ -0,0 +1,135 @@
+use super::*;
+use crate::helpers::models::domain::test_random;
+use crate::helpers::models::solution::{create_route_with_activities, test_activity_with_job};
+use crate::helpers::solver::{create_default_population, generate_matrix_routes_with_defaults};
+use crate::models::examples::create_example_problem;
+use crate::models::solution::Registry;
+use crate::models::{Problem, Solution};
+use crate::solver::Builder;
+
+fn create_single_route_ctx(problem: Arc<Problem>) -> InsertionContext {
+    create_single_route_ctx_with_order(problem, false)
+}
+
+fn create_single_route_ctx_with_order(problem: Arc<Problem>, is_reversed: bool) -> InsertionContext {
+    let mut activities = problem
+        .jobs
+        .all()
+        .zip(0_usize..)
+        .map(|(job, location)| {
+            let mut activity = test_activity_with_job(job.to_single().clone());
+            activity.place.location = location;
+            activity
+        })
+        .collect::<Vec<_>>();
+    if is_reversed {
+        activities.reverse();
+    }
+    let route = create_route_with_activities(problem.fleet.as_ref(), "0", activities);
+
+    let mut registry = Registry::new(problem.fleet.as_ref(), test_random());
+    registry.use_actor(&route.actor);
+
+    let solution =
+        Solution { registry, routes: vec![route], unassigned: Default::default(), extras: problem.extras.clone() };
+
+    InsertionContext::new_from_solution(problem, (solution, None), test_random())
+}
+
+fn create_matrix_ctx() -> (Arc<Problem>, InsertionContext) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(2, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), test_random());
+
+    (problem, insertion_ctx)
+}
+
+#[test]
+fn can_keep_solution_without_better_routes() {
+    let (problem, insertion_ctx) = create_matrix_ctx();
+    let mut refinement_ctx = RefinementContext::new(problem.clone(), create_default_population(problem), None);
+    refinement_ctx.population.add(insertion_ctx.deep_copy());
+    let set_partitioning = SetPartitioning::default();
+
+    set_partitioning.on_generation(&refinement_ctx);
+
+    assert_eq!(set_partitioning.size(), 2);
+    assert!(set_partitioning.process(&insertion_ctx).is_none());
+}
+
+#[test]
+fn can_assemble_better_solution_from_collected_routes() {
+    let (problem, insertion_ctx) = create_matrix_ctx();
+    let mut refinement_ctx = RefinementContext::new(problem.clone(), create_default_population(problem.clone()), None);
+    refinement_ctx.population.add(create_single_route_ctx(problem));
+    let set_partitioning = SetPartitioning::default();
+
+    set_partitioning.on_generation(&refinement_ctx);
+    let result = set_partitioning.process(&insertion_ctx).expect("no better solution");
+
+    assert_eq!(insertion_ctx.solution.routes.len(), 2);
+    assert_eq!(result.solution.routes.len(), 1);
+    assert_eq!(result.solution.routes[0].route.tour.job_count(), 4);
+    assert!(result.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_keep_cheapest_route_for_the_same_jobs() {
+    let (problem, _) = create_matrix_ctx();
+    let get_route_cost = |insertion_ctx: &InsertionContext| insertion_ctx.solution.routes[0].get_route_cost();
+    let routes = vec![
+        create_single_route_ctx_with_order(problem.clone(), false),
+        create_single_route_ctx_with_order(problem.clone(), true),
+    ];
+    let expected_cost = get_route_cost(&routes[0]).min(get_route_cost(&routes[1]));
+    let set_partitioning = SetPartitioning::default();
+
+    routes.into_iter().for_each(|insertion_ctx| {
+        let mut refinement_ctx =
+            RefinementContext::new(problem.clone(), create_default_population(problem.clone()), None);
+        refinement_ctx.population.add(insertion_ctx);
+        set_partitioning.on_generation(&refinement_ctx);
+    });
+
+    let pool = set_partitioning.pool.lock().unwrap();
+    assert_eq!(pool.routes.len(), 1);
+    assert_eq!(pool.routes.values().next().unwrap().get_route_cost(), expected_cost);
+}
+
+#[test]
+fn can_keep_routes_with_lowest_cost_per_job_when_pool_is_full() {
+    let (problem, insertion_ctx) = create_matrix_ctx();
+    let expected_cost = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .map(|route_ctx| route_ctx.get_route_cost() / route_ctx.route.tour.job_count() as f64)
+        .min_by(|a, b| compare_floats(*a, *b))
+        .unwrap();
+    let mut refinement_ctx = RefinementContext::new(problem.clone(), create_default_population(problem), None);
+    refinement_ctx.population.add(insertion_ctx);
+    let set_partitioning = SetPartitioning::new(1, 10_000, 5.);
+
+    set_partitioning.on_generation(&refinement_ctx);
+
+    let pool = set_partitioning.pool.lock().unwrap();
+    assert_eq!(pool.routes.len(), 1);
+    let route_ctx = pool.routes.values().next().unwrap();
+    assert_eq!(route_ctx.get_route_cost() / route_ctx.route.tour.job_count() as f64, expected_cost);
+}
+
+#[test]
+fn can_run_solver_with_set_partitioning() {
+    let problem = create_example_problem();
+
+    let (solution, cost, _) = Builder::new(problem)
+        .with_max_generations(Some(100))
+        .with_post_processing(Arc::new(SetPartitioning::default()))
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert_eq!(cost, 42.);
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(solution.unassigned.len(), 0);
+}
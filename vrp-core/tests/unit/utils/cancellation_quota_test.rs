@@ -0,0 +1,23 @@
+use super::*;
+
+#[test]
+fn can_reach_quota_when_cancelled() {
+    let flag = Arc::new(AtomicBool::new(false));
+    let quota = CancellationQuota::new(flag.clone());
+    let handle = quota.clone();
+
+    assert!(!quota.is_reached());
+    handle.cancel();
+    assert!(quota.is_reached());
+    assert!(flag.load(Ordering::Relaxed));
+}
+
+#[test]
+fn can_reach_quota_when_external_flag_is_set() {
+    let flag = Arc::new(AtomicBool::new(false));
+    let quota = CancellationQuota::new(flag.clone());
+
+    flag.store(true, Ordering::Relaxed);
+
+    assert!(quota.is_reached());
+}
@@ -8,43 +8,57 @@ use crate::format::{get_coord_index, get_job_index};
 use hashbrown::HashSet;
 
 /// Checks assignment of jobs and vehicles.
-pub fn check_assignment(ctx: &CheckerContext) -> Result<(), String> {
-    check_vehicles(ctx)?;
-    check_jobs_presence(ctx)?;
-    check_jobs_match(ctx)?;
-    check_dispatch(ctx)?;
-
-    Ok(())
+pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<CheckerError>> {
+    run_checks(ctx, &[check_vehicles, check_jobs_presence, check_jobs_match, check_dispatch])
 }
 
 /// Checks that vehicles in each tour are used once per shift and they are known in problem.
-fn check_vehicles(ctx: &CheckerContext) -> Result<(), String> {
+fn check_vehicles(ctx: &CheckerContext) -> Result<(), Vec<CheckerError>> {
     let all_vehicles: HashSet<_> = ctx.problem.fleet.vehicles.iter().flat_map(|v| v.vehicle_ids.iter()).collect();
     let mut used_vehicles = HashSet::<(String, usize)>::new();
 
-    ctx.solution.tours.iter().try_for_each(|tour| {
-        if !all_vehicles.contains(&tour.vehicle_id) {
-            return Err(format!("Used vehicle with unknown id: {}", tour.vehicle_id));
-        }
+    let errors = ctx
+        .solution
+        .tours
+        .iter()
+        .enumerate()
+        .filter_map(|(tour_idx, tour)| {
+            if !all_vehicles.contains(&tour.vehicle_id) {
+                return Some(
+                    CheckerError::new("UNKNOWN_VEHICLE", format!("Used vehicle with unknown id: {}", tour.vehicle_id))
+                        .with_tour(tour_idx, tour),
+                );
+            }
 
-        if !(used_vehicles.insert((tour.vehicle_id.to_string(), tour.shift_index))) {
-            Err(format!("Vehicle with '{}' id used more than once for shift {}", tour.vehicle_id, tour.shift_index))
-        } else {
-            Ok(())
-        }
-    })?;
+            if !(used_vehicles.insert((tour.vehicle_id.to_string(), tour.shift_index))) {
+                Some(
+                    CheckerError::new(
+                        "VEHICLE_REUSED",
+                        format!(
+                            "Vehicle with '{}' id used more than once for shift {}",
+                            tour.vehicle_id, tour.shift_index
+                        ),
+                    )
+                    .with_tour(tour_idx, tour),
+                )
+            } else {
+                None
+            }
+        })
+        .collect();
 
-    Ok(())
+    into_result(errors)
 }
 
 /// Checks job task rules.
-fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
+fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), Vec<CheckerError>> {
     struct JobAssignment {
         pub tour_info: (String, usize),
         pub pickups: Vec<usize>,
         pub deliveries: Vec<usize>,
         pub replacements: Vec<usize>,
         pub services: Vec<usize>,
+        pub tasks: Vec<(String, Option<String>)>,
     }
     let new_assignment = |tour_info: (String, usize)| JobAssignment {
         tour_info,
@@ -52,25 +66,40 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         deliveries: vec![],
         replacements: vec![],
         services: vec![],
+        tasks: vec![],
     };
     let activity_types: HashSet<_> = vec!["pickup", "delivery", "service", "replacement"].into_iter().collect();
 
     let all_jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect::<HashMap<_, _>>();
     let mut used_jobs = HashMap::<String, JobAssignment>::new();
+    let mut failed_jobs = HashSet::<String>::new();
+    let mut errors = vec![];
 
-    ctx.solution.tours.iter().try_for_each(|tour| {
+    ctx.solution.tours.iter().enumerate().for_each(|(tour_idx, tour)| {
         tour.stops
             .iter()
-            .flat_map(|stop| stop.activities.iter())
             .enumerate()
-            .filter(|(_, activity)| activity_types.contains(&activity.activity_type.as_str()))
-            .try_for_each(|(idx, activity)| {
+            .flat_map(|(stop_idx, stop)| stop.activities.iter().map(move |activity| (stop_idx, activity)))
+            .enumerate()
+            .filter(|(_, (_, activity))| activity_types.contains(&activity.activity_type.as_str()))
+            .for_each(|(idx, (stop_idx, activity))| {
                 let tour_info = (tour.vehicle_id.clone(), tour.shift_index);
                 let asgn =
                     used_jobs.entry(activity.job_id.clone()).or_insert_with(|| new_assignment(tour_info.clone()));
 
                 if asgn.tour_info != tour_info {
-                    return Err(format!("Job served in multiple tours: '{}'", activity.job_id));
+                    if failed_jobs.insert(activity.job_id.clone()) {
+                        errors.push(
+                            CheckerError::new(
+                                "JOB_MULTIPLE_TOURS",
+                                format!("Job served in multiple tours: '{}'", activity.job_id),
+                            )
+                            .with_tour(tour_idx, tour)
+                            .with_stop(stop_idx)
+                            .with_job(&activity.job_id),
+                        );
+                    }
+                    return;
                 }
 
                 match activity.activity_type.as_str() {
@@ -81,13 +110,22 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
                     _ => {}
                 }
 
-                Ok(())
+                asgn.tasks.push((activity.activity_type.clone(), activity.job_tag.clone()));
             })
-    })?;
+    });
+
+    let mut job_ids = used_jobs.keys().filter(|id| !failed_jobs.contains(*id)).collect::<Vec<_>>();
+    job_ids.sort();
+
+    job_ids.into_iter().for_each(|id| {
+        let asgn = used_jobs.get(id).unwrap();
+        let job = if let Some(job) = all_jobs.get(id) {
+            job
+        } else {
+            errors.push(CheckerError::new("UNKNOWN_JOB", format!("Cannot find job with id {}", id)).with_job(id));
+            return;
+        };
 
-    used_jobs.iter().try_for_each(|(id, asgn)| {
-        // TODO validate whether each job task is served once
-        let job = all_jobs.get(id).ok_or_else(|| format!("Cannot find job with id {}", id))?;
         let expected_tasks = job.pickups.as_ref().map_or(0, |p| p.len())
             + job.deliveries.as_ref().map_or(0, |d| d.len())
             + job.services.as_ref().map_or(0, |s| s.len())
@@ -95,18 +133,26 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
         let assigned_tasks = asgn.pickups.len() + asgn.deliveries.len() + asgn.services.len() + asgn.replacements.len();
 
         if expected_tasks != assigned_tasks {
-            return Err(format!(
-                "Not all tasks served for '{}', expected: {}, assigned: {}",
-                id, expected_tasks, assigned_tasks
-            ));
-        }
-
-        if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
-            return Err(format!("Found pickup after delivery for '{}'", id));
+            errors.push(
+                CheckerError::new(
+                    "JOB_TASKS_MISMATCH",
+                    format!(
+                        "Not all tasks served for '{}', expected: {}, assigned: {}",
+                        id, expected_tasks, assigned_tasks
+                    ),
+                )
+                .with_job(id)
+                .with_values(expected_tasks, assigned_tasks),
+            );
+        } else if let Some(error) = check_job_tasks(job, &asgn.tasks) {
+            errors.push(error);
+        } else if !asgn.deliveries.is_empty() && asgn.pickups.iter().max() > asgn.deliveries.iter().min() {
+            errors.push(
+                CheckerError::new("PICKUP_AFTER_DELIVERY", format!("Found pickup after delivery for '{}'", id))
+                    .with_job(id),
+            );
         }
-
-        Ok(())
-    })?;
+    });
 
     let all_unassigned_jobs = ctx
         .solution
@@ -119,43 +165,67 @@ fn check_jobs_presence(ctx: &CheckerContext) -> Result<(), String> {
     let unique_unassigned_jobs = all_unassigned_jobs.iter().cloned().collect::<HashSet<_>>();
 
     if unique_unassigned_jobs.len() != all_unassigned_jobs.len() {
-        return Err("Duplicated job ids in the list of unassigned jobs".to_string());
+        errors.push(
+            CheckerError::new("UNASSIGNED_DUPLICATE", "Duplicated job ids in the list of unassigned jobs".to_string())
+                .with_values(unique_unassigned_jobs.len(), all_unassigned_jobs.len()),
+        );
     }
 
-    unique_unassigned_jobs.iter().try_for_each(|job_id| {
+    let mut unassigned_job_ids = unique_unassigned_jobs.iter().collect::<Vec<_>>();
+    unassigned_job_ids.sort();
+
+    unassigned_job_ids.into_iter().for_each(|job_id| {
         if !all_jobs.contains_key(job_id) {
-            return Err(format!("Unknown job id in the list of unassigned jobs: '{}'", job_id));
+            errors.push(
+                CheckerError::new(
+                    "UNASSIGNED_UNKNOWN_JOB",
+                    format!("Unknown job id in the list of unassigned jobs: '{}'", job_id),
+                )
+                .with_job(job_id),
+            );
+        } else if used_jobs.contains_key(job_id) {
+            errors.push(
+                CheckerError::new(
+                    "JOB_ASSIGNED_AND_UNASSIGNED",
+                    format!("Job present as assigned and unassigned: '{}'", job_id),
+                )
+                .with_job(job_id),
+            );
         }
-
-        if used_jobs.contains_key(job_id) {
-            return Err(format!("Job present as assigned and unassigned: '{}'", job_id));
+    });
+
+    // NOTE amount of jobs is compared only when there are no other issues as it is their consequence
+    if errors.is_empty() {
+        let all_used_job =
+            unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
+
+        if all_used_job.len() != all_jobs.len() {
+            errors.push(
+                CheckerError::new(
+                    "JOB_COUNT_MISMATCH",
+                    format!(
+                        "Amount of jobs present in problem and solution doesn't match: {} vs {}",
+                        all_jobs.len(),
+                        all_used_job.len()
+                    ),
+                )
+                .with_values(all_jobs.len(), all_used_job.len()),
+            );
         }
-
-        Ok(())
-    })?;
-
-    let all_used_job =
-        unique_unassigned_jobs.into_iter().chain(used_jobs.into_iter().map(|(id, _)| id)).collect::<Vec<_>>();
-
-    if all_used_job.len() != all_jobs.len() {
-        return Err(format!(
-            "Amount of jobs present in problem and solution doesn't match: {} vs {}",
-            all_jobs.len(),
-            all_used_job.len()
-        ));
     }
 
-    Ok(())
+    into_result(errors)
 }
 
 /// Checks job constraint violations.
-fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
-    let job_ids = ctx
+fn check_jobs_match(ctx: &CheckerContext) -> Result<(), Vec<CheckerError>> {
+    let errors = ctx
         .solution
         .tours
         .iter()
-        .flat_map(move |tour| {
-            tour.stops.iter().flat_map(move |stop| {
+        .enumerate()
+        .flat_map(move |(tour_idx, tour)| {
+            tour.stops.iter().enumerate().flat_map(move |(stop_idx, stop)| {
                 stop.activities
                     .iter()
                     .filter(move |activity| {
@@ -168,26 +238,28 @@ fn check_jobs_match(ctx: &CheckerContext) -> Result<(), String> {
                         )
                         .is_err()
                     })
-                    .map(|activity| {
-                        format!(
-                            "{}:{}",
-                            activity.job_id.clone(),
-                            activity.job_tag.as_ref().unwrap_or(&"<no tag>".to_string())
+                    .map(move |activity| {
+                        CheckerError::new(
+                            "ACTIVITY_MISMATCH",
+                            format!(
+                                "cannot match activity to job: {}:{}",
+                                activity.job_id,
+                                activity.job_tag.as_ref().unwrap_or(&"<no tag>".to_string())
+                            ),
                         )
+                        .with_tour(tour_idx, tour)
+                        .with_stop(stop_idx)
+                        .with_job(&activity.job_id)
                     })
             })
         })
-        .collect::<Vec<_>>();
+        .collect();
 
-    if !job_ids.is_empty() {
-        return Err(format!("cannot match activities to jobs: {}", job_ids.join(", ")));
-    }
-
-    Ok(())
+    into_result(errors)
 }
 
 /// Checks whether dispatch is properly assigned.
-fn check_dispatch(ctx: &CheckerContext) -> Result<(), String> {
+fn check_dispatch(ctx: &CheckerContext) -> Result<(), Vec<CheckerError>> {
     let vehicles_with_dispatch = ctx
         .problem
         .fleet
@@ -197,62 +269,87 @@ fn check_dispatch(ctx: &CheckerContext) -> Result<(), String> {
         .filter_map(|(v, shift)| shift.dispatch.as_ref().map(|ds| (v, ds)))
         .collect::<HashMap<_, _>>();
 
-    ctx.solution.tours.iter().try_fold((), |_, tour| {
+    check_tours(ctx, |tour| {
         let should_have_dispatch = vehicles_with_dispatch.contains_key(&tour.type_id);
-        let dispatch_in_tour = tour
-            .stops
-            .iter()
-            .enumerate()
-            .flat_map(|(stop_idx, stop)| {
-                stop.activities
-                    .iter()
-                    .enumerate()
-                    .map(move |(activity_index, activity)| (stop_idx, activity_index, activity))
-            })
-            .filter(|(_, _, activity)| activity.activity_type == "dispatch")
-            .collect::<Vec<_>>();
+        check_tour_dispatch(tour, should_have_dispatch).err().into_iter().collect()
+    })
+}
 
-        if dispatch_in_tour.len() > 1 {
-            return Err(format!("more than one dispatch in the tour: '{}'", tour.vehicle_id));
-        }
+/// Checks dispatch of the tour: the first found violation is returned as the rest checks depend on it.
+fn check_tour_dispatch(tour: &Tour, should_have_dispatch: bool) -> Result<(), CheckerError> {
+    let dispatch_in_tour = tour
+        .stops
+        .iter()
+        .enumerate()
+        .flat_map(|(stop_idx, stop)| {
+            stop.activities
+                .iter()
+                .enumerate()
+                .map(move |(activity_index, activity)| (stop_idx, activity_index, activity))
+        })
+        .filter(|(_, _, activity)| activity.activity_type == "dispatch")
+        .collect::<Vec<_>>();
 
-        if should_have_dispatch && dispatch_in_tour.is_empty() {
-            return Err(format!("tour should have dispatch, but none is found: '{}'", tour.vehicle_id));
-        }
+    if dispatch_in_tour.len() > 1 {
+        return Err(CheckerError::new(
+            "DISPATCH_VIOLATION",
+            format!("more than one dispatch in the tour: '{}'", tour.vehicle_id),
+        )
+        .with_values(1, dispatch_in_tour.len()));
+    }
 
-        if !should_have_dispatch && !dispatch_in_tour.is_empty() {
-            return Err(format!("tour should not have dispatch, but it is present: '{}'", tour.vehicle_id));
-        }
+    if should_have_dispatch && dispatch_in_tour.is_empty() {
+        return Err(CheckerError::new(
+            "DISPATCH_VIOLATION",
+            format!("tour should have dispatch, but none is found: '{}'", tour.vehicle_id),
+        ));
+    }
+
+    if !should_have_dispatch && !dispatch_in_tour.is_empty() {
+        return Err(CheckerError::new(
+            "DISPATCH_VIOLATION",
+            format!("tour should not have dispatch, but it is present: '{}'", tour.vehicle_id),
+        ));
+    }
 
-        if should_have_dispatch {
-            let (stop_idx, activity_idx, dispatch_activity) = dispatch_in_tour.first().unwrap();
-            let first_stop = tour.stops.first().unwrap();
-
-            match (stop_idx, activity_idx) {
-                (0, 1) => {
-                    if let Some(location) = &dispatch_activity.location {
-                        if *location != first_stop.location {
-                            return Err(format!(
-                                "invalid dispatch location: {}, expected to match the first stop",
-                                location
-                            ));
-                        }
+    if should_have_dispatch {
+        let (stop_idx, activity_idx, dispatch_activity) = dispatch_in_tour.first().unwrap();
+        let first_stop = tour.stops.first().unwrap();
+
+        match (stop_idx, activity_idx) {
+            (0, 1) => {
+                if let Some(location) = &dispatch_activity.location {
+                    if *location != first_stop.location {
+                        return Err(CheckerError::new(
+                            "DISPATCH_VIOLATION",
+                            format!("invalid dispatch location: {}, expected to match the first stop", location),
+                        )
+                        .with_stop(*stop_idx)
+                        .with_values(&first_stop.location, location));
                     }
                 }
-                (1, 0) => {
-                    if let Some(location) = &dispatch_activity.location {
-                        if *location == first_stop.location {
-                            return Err(format!(
-                                "invalid dispatch location: {}, expected not to match the first stop",
-                                location
-                            ));
-                        }
+            }
+            (1, 0) => {
+                if let Some(location) = &dispatch_activity.location {
+                    if *location == first_stop.location {
+                        return Err(CheckerError::new(
+                            "DISPATCH_VIOLATION",
+                            format!("invalid dispatch location: {}, expected not to match the first stop", location),
+                        )
+                        .with_stop(*stop_idx));
                     }
                 }
-                _ => return Err(format!("invalid dispatch activity index, expected: 1, got: '{}'", activity_idx)),
+            }
+            _ => {
+                return Err(CheckerError::new(
+                    "DISPATCH_VIOLATION",
+                    format!("invalid dispatch activity index, expected: 1, got: '{}'", activity_idx),
+                )
+                .with_stop(*stop_idx)
+                .with_values(1, activity_idx))
             }
         }
+    }
 
-        Ok(())
-    })
+    Ok(())
 }
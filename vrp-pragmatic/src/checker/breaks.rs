@@ -5,90 +5,128 @@ mod breaks_test;
 use super::*;
 
 /// Checks that breaks are properly assigned.
-pub fn check_breaks(context: &CheckerContext) -> Result<(), String> {
-    context.solution.tours.iter().try_for_each(|tour| {
-        let vehicle_shift = context.get_vehicle_shift(tour)?;
-        let actual_break_count = tour
-            .stops
-            .iter()
-            .flat_map(|stop| stop.activities.iter())
-            .filter(|activity| activity.activity_type == "break")
-            .count();
-        let matched_break_count = tour.stops.iter().try_fold(0, |acc, stop| {
-            stop.activities
-                .windows(stop.activities.len().min(2))
-                .flat_map(|leg| as_leg_info_with_break(context, tour, stop, leg))
-                .try_fold(acc, |acc, (from_loc, to, vehicle_break)| {
-                    // check time
-                    let visit_time = get_time_window(stop, to);
-                    let break_time_window = get_break_time_window(tour, &vehicle_break)?;
-                    if !visit_time.intersects(&break_time_window) {
-                        return Err(format!(
-                            "Break visit time '{:?}' is invalid: expected is in '{:?}'",
-                            visit_time, break_time_window
-                        ));
-                    }
+pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<CheckerError>> {
+    check_tours(context, |tour| check_tour_breaks(context, tour).unwrap_or_else(|err| vec![err.into()]))
+}
+
+/// Returns all break violations of the tour or an error if the tour cannot be checked.
+fn check_tour_breaks(context: &CheckerContext, tour: &Tour) -> Result<Vec<CheckerError>, String> {
+    let mut errors = vec![];
+
+    let vehicle_shift = context.get_vehicle_shift(tour)?;
+    let actual_break_count = tour
+        .stops
+        .iter()
+        .flat_map(|stop| stop.activities.iter())
+        .filter(|activity| activity.activity_type == "break")
+        .count();
+    let matched_break_count = tour.stops.iter().enumerate().try_fold(0, |acc, (stop_idx, stop)| {
+        stop.activities
+            .windows(stop.activities.len().min(2))
+            .flat_map(|leg| as_leg_info_with_break(context, tour, stop, leg))
+            .try_fold::<_, _, Result<_, String>>(acc, |acc, (from_loc, to, vehicle_break)| {
+                // check time
+                let visit_time = get_time_window(stop, to);
+                let break_time_window = get_break_time_window(tour, &vehicle_break)?;
+                if !visit_time.intersects(&break_time_window) {
+                    errors.push(
+                        CheckerError::new(
+                            "BREAK_TIME",
+                            format!(
+                                "Break visit time '{:?}' is invalid: expected is in '{:?}'",
+                                visit_time, break_time_window
+                            ),
+                        )
+                        .with_stop(stop_idx)
+                        .with_job(&to.job_id)
+                        .with_values(format!("{:?}", break_time_window), format!("{:?}", visit_time)),
+                    );
+                }
 
-                    // check location
-                    let actual_location = get_location(stop, to);
-                    match &vehicle_break.locations {
-                        Some(locations) => {
-                            let is_correct = locations.iter().any(|location| actual_location == *location);
-                            if !is_correct {
-                                return Err(format!(
-                                    "Break location '{:?}' is invalid: expected one of '{:?}'",
-                                    actual_location, locations
-                                ));
-                            }
+                // check location
+                let actual_location = get_location(stop, to);
+                match &vehicle_break.locations {
+                    Some(locations) => {
+                        let is_correct = locations.iter().any(|location| actual_location == *location);
+                        if !is_correct {
+                            errors.push(
+                                CheckerError::new(
+                                    "BREAK_LOCATION",
+                                    format!(
+                                        "Break location '{:?}' is invalid: expected one of '{:?}'",
+                                        actual_location, locations
+                                    ),
+                                )
+                                .with_stop(stop_idx)
+                                .with_job(&to.job_id)
+                                .with_values(format!("{:?}", locations), format!("{:?}", actual_location)),
+                            );
                         }
-                        None => {
-                            if *from_loc != actual_location {
-                                return Err(format!(
-                                    "Break location '{:?}' is invalid: expected previous activity location '{:?}'",
-                                    actual_location, from_loc
-                                ));
-                            }
+                    }
+                    None => {
+                        if *from_loc != actual_location {
+                            errors.push(
+                                CheckerError::new(
+                                    "BREAK_LOCATION",
+                                    format!(
+                                        "Break location '{:?}' is invalid: expected previous activity location '{:?}'",
+                                        actual_location, from_loc
+                                    ),
+                                )
+                                .with_stop(stop_idx)
+                                .with_job(&to.job_id)
+                                .with_values(format!("{:?}", from_loc), format!("{:?}", actual_location)),
+                            );
                         }
                     }
+                }
 
-                    Ok(acc + 1)
-                })
-        })?;
+                Ok(acc + 1)
+            })
+    })?;
 
-        if actual_break_count != matched_break_count {
-            return Err(format!(
-                "Cannot match all breaks, matched: '{}', actual '{}' for vehicle '{}', shift index '{}'",
-                matched_break_count, actual_break_count, tour.vehicle_id, tour.shift_index
-            ));
-        }
+    if actual_break_count != matched_break_count {
+        errors.push(
+            CheckerError::new(
+                "BREAK_MISMATCH",
+                format!(
+                    "Cannot match all breaks, matched: '{}', actual '{}' for vehicle '{}', shift index '{}'",
+                    matched_break_count, actual_break_count, tour.vehicle_id, tour.shift_index
+                ),
+            )
+            .with_values(actual_break_count, matched_break_count),
+        );
+    }
 
-        let arrival = tour
-            .stops
-            .last()
-            .map(|stop| parse_time(&stop.time.arrival))
-            .ok_or_else(|| format!("Cannot get arrival for tour '{}'", tour.vehicle_id))?;
-
-        let expected_break_count =
-            vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).fold(0, |acc, vehicle_break| {
-                let break_tw = get_break_time_window(tour, vehicle_break).expect("Cannot get break time windows");
-                if break_tw.start < arrival {
-                    acc + 1
-                } else {
-                    acc
-                }
-            });
+    let arrival = tour
+        .stops
+        .last()
+        .map(|stop| parse_time(&stop.time.arrival))
+        .ok_or_else(|| format!("Cannot get arrival for tour '{}'", tour.vehicle_id))?;
 
-        let total_break_count = actual_break_count + get_break_violation_count(&context.solution, tour);
+    let expected_break_count =
+        vehicle_shift.breaks.iter().flat_map(|breaks| breaks.iter()).try_fold(0, |acc, vehicle_break| {
+            let break_tw = get_break_time_window(tour, vehicle_break)?;
+            Ok::<_, String>(if break_tw.start < arrival { acc + 1 } else { acc })
+        })?;
 
-        if expected_break_count != total_break_count {
-            Err(format!(
-                "Amount of breaks does not match, expected: '{}', got '{}' for vehicle '{}', shift index '{}'",
-                expected_break_count, total_break_count, tour.vehicle_id, tour.shift_index
-            ))
-        } else {
-            Ok(())
-        }
-    })
+    let total_break_count = actual_break_count + get_break_violation_count(&context.solution, tour);
+
+    // NOTE break count is not checked when breaks cannot be matched as it is already reported
+    if actual_break_count == matched_break_count && expected_break_count != total_break_count {
+        errors.push(
+            CheckerError::new(
+                "BREAK_COUNT",
+                format!(
+                    "Amount of breaks does not match, expected: '{}', got '{}' for vehicle '{}', shift index '{}'",
+                    expected_break_count, total_break_count, tour.vehicle_id, tour.shift_index
+                ),
+            )
+            .with_values(expected_break_count, total_break_count),
+        );
+    }
+
+    Ok(errors)
 }
 
 fn as_leg_info_with_break<'a>(
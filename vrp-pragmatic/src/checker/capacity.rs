@@ -9,115 +9,146 @@ use vrp_core::models::common::{Load, MultiDimLoad};
 /// Checks that vehicle load is assigned correctly. The following rules are checked:
 /// * max vehicle's capacity is not violated
 /// * load change is correct
-pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), String> {
-    context.solution.tours.iter().try_for_each(|tour| {
-        let capacity = MultiDimLoad::new(context.get_vehicle(&tour.vehicle_id)?.capacity.clone());
-
-        let legs = (0_usize..)
-            .zip(tour.stops.windows(2))
-            .map(|(idx, leg)| {
-                (
-                    idx,
-                    match leg {
-                        [from, to] => (from, to),
-                        _ => panic!("Unexpected leg configuration"),
-                    },
-                )
-            })
-            .collect::<Vec<_>>();
-        let intervals: Vec<Vec<(usize, (&Stop, &Stop))>> = legs
+pub fn check_vehicle_load(context: &CheckerContext) -> Result<(), Vec<CheckerError>> {
+    check_tours(context, |tour| check_tour_load(context, tour).unwrap_or_else(|err| vec![err.into()]))
+}
+
+/// Returns all load violations of the tour or an error if the tour cannot be checked.
+fn check_tour_load(context: &CheckerContext, tour: &Tour) -> Result<Vec<CheckerError>, String> {
+    let mut errors = vec![];
+
+    let vehicle_capacity = context.get_vehicle(&tour.vehicle_id)?.capacity.clone();
+    let capacity = MultiDimLoad::new(vehicle_capacity.clone());
+
+    let legs = (0_usize..)
+        .zip(tour.stops.windows(2))
+        .map(|(idx, leg)| {
+            (
+                idx,
+                match leg {
+                    [from, to] => (from, to),
+                    _ => panic!("Unexpected leg configuration"),
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+    let intervals: Vec<Vec<(usize, (&Stop, &Stop))>> = legs
+        .iter()
+        .fold(Vec::<(usize, usize)>::default(), |mut acc, (idx, (_, to))| {
+            let last_idx = legs.len() - 1;
+            if is_reload_stop(context, to) || *idx == last_idx {
+                let start_idx = acc.last().map_or(0_usize, |item| item.1 + 2);
+                let end_idx = if *idx == last_idx { last_idx } else { *idx - 1 };
+
+                acc.push((start_idx, end_idx));
+            }
+
+            acc
+        })
+        .into_iter()
+        .map(|(start_idx, end_idx)| {
+            legs.iter().cloned().skip(start_idx).take(end_idx - start_idx + 1).collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    intervals.iter().try_fold::<_, _, Result<_, String>>(get_initial_load(context, tour), |acc, interval| {
+        let (start_delivery, end_pickup) = interval
             .iter()
-            .fold(Vec::<(usize, usize)>::default(), |mut acc, (idx, (_, to))| {
-                let last_idx = legs.len() - 1;
-                if is_reload_stop(context, to) || *idx == last_idx {
-                    let start_idx = acc.last().map_or(0_usize, |item| item.1 + 2);
-                    let end_idx = if *idx == last_idx { last_idx } else { *idx - 1 };
+            .flat_map(|(_, (from, to))| once(from).chain(once(to)))
+            .zip(0..)
+            .filter_map(|(stop, idx)| if idx == 0 || idx % 2 == 1 { Some(stop) } else { None })
+            .flat_map(|stop| {
+                stop.activities
+                    .iter()
+                    .map(move |activity| (activity.clone(), context.get_activity_type(tour, stop, activity)))
+            })
+            .try_fold::<_, _, Result<_, String>>((acc, MultiDimLoad::default()), |acc, (activity, activity_type)| {
+                let activity_type = activity_type?;
+                let demand = get_demand(context, &activity, &activity_type)?;
+                Ok(match demand {
+                    (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
+                    (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
+                    (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
+                    _ => acc,
+                })
+            })?;
+
+        let first_idx = interval.first().map_or(0, |(idx, _)| *idx);
+        let end_capacity =
+            interval.iter().try_fold::<_, _, Result<_, String>>(start_delivery, |acc, (idx, (from, to))| {
+                let from_load = MultiDimLoad::new(from.load.clone());
+                let to_load = MultiDimLoad::new(to.load.clone());
+
+                // NOTE from stop is already checked as to stop of the previous leg within the same interval
+                let is_first_leg = *idx == first_idx;
+
+                let exceeded = if is_first_leg && !capacity.can_fit(&from_load) {
+                    Some((*idx, from))
+                } else if !capacity.can_fit(&to_load) {
+                    Some((idx + 1, to))
+                } else {
+                    None
+                };
+
+                if let Some((stop_idx, stop)) = exceeded {
+                    errors.push(
+                        CheckerError::new(
+                            "CAPACITY_EXCEEDED",
+                            format!("Load exceeds capacity in tour '{}'", tour.vehicle_id),
+                        )
+                        .with_stop(stop_idx)
+                        .with_values(format!("{:?}", vehicle_capacity), format!("{:?}", stop.load)),
+                    );
+                }
 
-                    acc.push((start_idx, end_idx));
+                let change = to.activities.iter().try_fold::<_, _, Result<_, String>>(
+                    MultiDimLoad::default(),
+                    |acc, activity| {
+                        let activity_type = context.get_activity_type(tour, to, activity)?;
+                        let (demand_type, demand) =
+                            if activity.activity_type == "arrival" || activity.activity_type == "reload" {
+                                (DemandType::StaticDelivery, end_pickup)
+                            } else {
+                                get_demand(context, &activity, &activity_type)?
+                            };
+
+                        Ok(match demand_type {
+                            DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
+                            DemandType::StaticPickup | DemandType::DynamicPickup => acc + demand,
+                            DemandType::None | DemandType::StaticPickupDelivery => acc,
+                        })
+                    },
+                )?;
+
+                let is_from_valid = !is_first_leg || from_load == acc;
+                let is_to_valid = to_load == if is_from_valid { acc } else { from_load } + change;
+                let has_dispatch_at_start = *idx == 0 && has_dispatch(tour);
+
+                if (!is_from_valid || !is_to_valid) && !has_dispatch_at_start {
+                    let (message, stop_idx, expected, actual) = match (is_from_valid, is_to_valid) {
+                        (true, false) => (format!("at stop {}", idx + 1), idx + 1, acc + change, to_load),
+                        (false, true) => (format!("at stop {}", idx), *idx, acc, from_load),
+                        _ => (format!("at stops {}, {}", idx, idx + 1), *idx, acc, from_load),
+                    };
+
+                    errors.push(
+                        CheckerError::new(
+                            "LOAD_MISMATCH",
+                            format!("Load mismatch {} in tour '{}'", message, tour.vehicle_id),
+                        )
+                        .with_stop(stop_idx)
+                        .with_values(format!("{:?}", expected.as_vec()), format!("{:?}", actual.as_vec())),
+                    );
                 }
 
-                acc
-            })
-            .into_iter()
-            .map(|(start_idx, end_idx)| {
-                legs.iter().cloned().skip(start_idx).take(end_idx - start_idx + 1).collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
+                // NOTE continue with expected load to avoid reporting the same violation for the next stops
+                Ok(if has_dispatch_at_start { to_load } else { acc + change })
+            })?;
 
-        intervals
-            .iter()
-            .try_fold::<_, _, Result<_, String>>(MultiDimLoad::default(), |acc, interval| {
-                let (start_delivery, end_pickup) = interval
-                    .iter()
-                    .flat_map(|(_, (from, to))| once(from).chain(once(to)))
-                    .zip(0..)
-                    .filter_map(|(stop, idx)| if idx == 0 || idx % 2 == 1 { Some(stop) } else { None })
-                    .flat_map(|stop| {
-                        stop.activities
-                            .iter()
-                            .map(move |activity| (activity.clone(), context.get_activity_type(tour, stop, activity)))
-                    })
-                    .try_fold::<_, _, Result<_, String>>(
-                        (acc, MultiDimLoad::default()),
-                        |acc, (activity, activity_type)| {
-                            let activity_type = activity_type?;
-                            let demand = get_demand(context, &activity, &activity_type)?;
-                            Ok(match demand {
-                                (DemandType::StaticDelivery, demand) => (acc.0 + demand, acc.1),
-                                (DemandType::StaticPickup, demand) => (acc.0, acc.1 + demand),
-                                (DemandType::StaticPickupDelivery, demand) => (acc.0 + demand, acc.1 + demand),
-                                _ => acc,
-                            })
-                        },
-                    )?;
-
-                let end_capacity = interval.iter().try_fold(start_delivery, |acc, (idx, (from, to))| {
-                    let from_load = MultiDimLoad::new(from.load.clone());
-                    let to_load = MultiDimLoad::new(to.load.clone());
-
-                    if !capacity.can_fit(&from_load) || !capacity.can_fit(&to_load) {
-                        return Err(format!("Load exceeds capacity in tour '{}'", tour.vehicle_id));
-                    }
-
-                    let change = to.activities.iter().try_fold::<_, _, Result<_, String>>(
-                        MultiDimLoad::default(),
-                        |acc, activity| {
-                            let activity_type = context.get_activity_type(tour, to, activity)?;
-                            let (demand_type, demand) =
-                                if activity.activity_type == "arrival" || activity.activity_type == "reload" {
-                                    (DemandType::StaticDelivery, end_pickup)
-                                } else {
-                                    get_demand(context, &activity, &activity_type)?
-                                };
-
-                            Ok(match demand_type {
-                                DemandType::StaticDelivery | DemandType::DynamicDelivery => acc - demand,
-                                DemandType::StaticPickup | DemandType::DynamicPickup => acc + demand,
-                                DemandType::None | DemandType::StaticPickupDelivery => acc,
-                            })
-                        },
-                    )?;
-
-                    let is_from_valid = from_load == acc;
-                    let is_to_valid = to_load == from_load + change;
-
-                    if (is_from_valid && is_to_valid) || (*idx == 0 && has_dispatch(tour)) {
-                        Ok(to_load)
-                    } else {
-                        let message = match (is_from_valid, is_to_valid) {
-                            (true, false) => format!("at stop {}", idx + 1),
-                            (false, true) => format!("at stop {}", idx),
-                            _ => format!("at stops {}, {}", idx, idx + 1),
-                        };
-
-                        Err(format!("Load mismatch {} in tour '{}'", message, tour.vehicle_id))
-                    }
-                })?;
-
-                Ok(end_capacity - end_pickup)
-            })
-            .map(|_| ())
-    })
+        Ok(end_capacity - end_pickup)
+    })?;
+
+    Ok(errors)
 }
 
 enum DemandType {
@@ -138,8 +169,9 @@ fn get_demand(
         activity,
         &activity_type,
         |job, task| {
-            let is_dynamic = job.pickups.as_ref().map_or(false, |p| !p.is_empty())
-                && job.deliveries.as_ref().map_or(false, |p| !p.is_empty());
+            let is_dynamic = (job.pickups.as_ref().map_or(false, |p| !p.is_empty())
+                && job.deliveries.as_ref().map_or(false, |p| !p.is_empty()))
+                || context.loaded_jobs.contains_key(&job.id);
             let demand = task.demand.clone().map_or_else(MultiDimLoad::default, MultiDimLoad::new);
 
             (is_dynamic, demand)
@@ -159,6 +191,18 @@ fn get_demand(
     Ok((demand_type, demand))
 }
 
+/// Returns load of jobs which are already on board of the tour's vehicle.
+fn get_initial_load(context: &CheckerContext, tour: &Tour) -> MultiDimLoad {
+    context
+        .loaded_jobs
+        .iter()
+        .filter(|(_, (vehicle_id, shift_index))| *vehicle_id == tour.vehicle_id && *shift_index == tour.shift_index)
+        .filter_map(|(job_id, _)| context.job_map.get(job_id))
+        .flat_map(|job| job.deliveries.iter().flatten())
+        .filter_map(|task| task.demand.clone())
+        .fold(MultiDimLoad::default(), |acc, demand| acc + MultiDimLoad::new(demand))
+}
+
 fn is_reload_stop(context: &CheckerContext, stop: &Stop) -> bool {
     context.get_stop_activity_types(stop).first().map_or(false, |a| a == "reload")
 }
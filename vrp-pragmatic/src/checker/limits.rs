@@ -9,45 +9,83 @@ use super::*;
 /// * max distance
 ///
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
-pub fn check_limits(context: &CheckerContext) -> Result<(), String> {
-    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
-        let vehicle = context.get_vehicle(&tour.vehicle_id)?;
-
-        if let Some(ref limits) = vehicle.limits {
-            if let Some(max_distance) = limits.max_distance {
-                if tour.statistic.distance as f64 > max_distance {
-                    return Err(format!(
+pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<CheckerError>> {
+    into_result(
+        context
+            .solution
+            .tours
+            .iter()
+            .enumerate()
+            .flat_map(|(tour_idx, tour)| {
+                check_tour_limits(context, tour).into_iter().map(move |err| err.with_tour(tour_idx, tour))
+            })
+            .collect(),
+    )
+}
+
+fn check_tour_limits(context: &CheckerContext, tour: &Tour) -> Vec<CheckerError> {
+    let limits = match context.get_vehicle(&tour.vehicle_id) {
+        Ok(vehicle) => vehicle.limits.as_ref(),
+        Err(err) => return vec![err.into()],
+    };
+
+    let limits = if let Some(limits) = limits { limits } else { return vec![] };
+    let mut errors = vec![];
+
+    if let Some(max_distance) = limits.max_distance {
+        if tour.statistic.distance as f64 > max_distance {
+            errors.push(
+                CheckerError::new(
+                    "MAX_DISTANCE_LIMIT",
+                    format!(
                         "max distance limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         max_distance, tour.statistic.distance, tour.vehicle_id, tour.shift_index
-                    ));
-                }
-            }
+                    ),
+                )
+                .with_values(max_distance, tour.statistic.distance),
+            );
+        }
+    }
 
-            if let Some(shift_time) = limits.shift_time {
-                if tour.statistic.duration as f64 > shift_time {
-                    return Err(format!(
+    if let Some(shift_time) = limits.shift_time {
+        if tour.statistic.duration as f64 > shift_time {
+            errors.push(
+                CheckerError::new(
+                    "SHIFT_TIME_LIMIT",
+                    format!(
                         "shift time limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
                         shift_time, tour.statistic.duration, tour.vehicle_id, tour.shift_index
-                    ));
-                }
-            }
-
-            if let Some(tour_size_limit) = limits.tour_size {
-                let shift = context.get_vehicle_shift(tour)?;
+                    ),
+                )
+                .with_values(shift_time, tour.statistic.duration),
+            );
+        }
+    }
 
+    if let Some(tour_size_limit) = limits.tour_size {
+        match context.get_vehicle_shift(tour) {
+            Ok(shift) => {
                 let extra_activities = if shift.end.is_some() { 2 } else { 1 };
                 let tour_activities = tour.stops.iter().flat_map(|stop| stop.activities.iter()).count();
-                let tour_activities = if tour_activities > extra_activities { tour_activities - extra_activities } else { 0 };
+                let tour_activities =
+                    if tour_activities > extra_activities { tour_activities - extra_activities } else { 0 };
 
                 if tour_activities > tour_size_limit {
-                    return Err(format!(
-                        "tour size limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
-                        tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
-                    ))
+                    errors.push(
+                        CheckerError::new(
+                            "TOUR_SIZE_LIMIT",
+                            format!(
+                                "tour size limit violation, expected: not more than {}, got: {}, vehicle id '{}', shift index: {}",
+                                tour_size_limit, tour_activities, tour.vehicle_id, tour.shift_index
+                            ),
+                        )
+                        .with_values(tour_size_limit, tour_activities),
+                    );
                 }
             }
+            Err(err) => errors.push(err.into()),
         }
+    }
 
-        Ok(())
-    })
+    errors
 }
@@ -1,10 +1,15 @@
 //! This module provides functionality to automatically check that given solution is feasible
 //! which means that there is no constraint violations.
 
+#[cfg(test)]
+#[path = "../../tests/unit/checker/checker_test.rs"]
+mod checker_test;
+
 use crate::format::problem::*;
 use crate::format::solution::*;
-use crate::format::Location;
+use crate::format::{CoordIndex, Location};
 use crate::parse_time;
+use serde::Serialize;
 use vrp_core::models::common::TimeWindow;
 use vrp_core::models::Problem as CoreProblem;
 
@@ -18,9 +23,111 @@ pub struct CheckerContext {
     pub solution: Solution,
 
     job_map: HashMap<String, Job>,
+    loaded_jobs: HashMap<String, (String, usize)>,
+    coord_index: CoordIndex,
     core_problem: Arc<CoreProblem>,
 }
 
+/// Represents a constraint violation found by the checker.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckerError {
+    /// A violation code.
+    pub code: String,
+    /// A violation description.
+    pub message: String,
+    /// An index of the tour in the solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tour_index: Option<usize>,
+    /// A vehicle id of the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vehicle_id: Option<String>,
+    /// A shift index of the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// An index of the stop in the tour.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_index: Option<usize>,
+    /// A job id.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<String>,
+    /// An expected value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    /// An actual value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actual: Option<String>,
+}
+
+impl CheckerError {
+    /// Creates a new instance of `CheckerError` without any details.
+    pub fn new(code: &str, message: String) -> Self {
+        Self {
+            code: code.to_string(),
+            message,
+            tour_index: None,
+            vehicle_id: None,
+            shift_index: None,
+            stop_index: None,
+            job_id: None,
+            expected: None,
+            actual: None,
+        }
+    }
+
+    /// Sets tour information if it is not yet specified.
+    pub fn with_tour(mut self, tour_index: usize, tour: &Tour) -> Self {
+        if self.vehicle_id.is_none() {
+            self.tour_index = Some(tour_index);
+            self.vehicle_id = Some(tour.vehicle_id.clone());
+            self.shift_index = Some(tour.shift_index);
+        }
+
+        self
+    }
+
+    /// Sets stop index.
+    pub fn with_stop(mut self, stop_index: usize) -> Self {
+        self.stop_index = Some(stop_index);
+        self
+    }
+
+    /// Sets job id.
+    pub fn with_job(mut self, job_id: &str) -> Self {
+        self.job_id = Some(job_id.to_string());
+        self
+    }
+
+    /// Sets expected and actual values.
+    pub fn with_values<E: ToString, A: ToString>(mut self, expected: E, actual: A) -> Self {
+        self.expected = Some(expected.to_string());
+        self.actual = Some(actual.to_string());
+        self
+    }
+
+    /// Serializes multiple errors into json.
+    pub fn to_json(errors: &[Self]) -> Result<String, String> {
+        serde_json::to_string_pretty(errors).map_err(|err| format!("cannot serialize errors: {}", err))
+    }
+
+    /// Formats multiple errors into string.
+    pub fn format_many(errors: &[Self], separator: &str) -> String {
+        errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join(separator)
+    }
+}
+
+impl std::fmt::Display for CheckerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)
+    }
+}
+
+impl From<String> for CheckerError {
+    fn from(message: String) -> Self {
+        CheckerError::new("INVALID_DATA", message)
+    }
+}
+
 /// Represents all possible activity types.
 enum ActivityType {
     Terminal,
@@ -31,28 +138,29 @@ enum ActivityType {
 }
 
 impl CheckerContext {
-    /// Creates an instance of `CheckerContext`
+    /// Creates an instance of `CheckerContext`. If problem has vehicle states, then already served
+    /// jobs and pickups of already loaded jobs are not expected in the solution.
     pub fn new(
         core_problem: Arc<CoreProblem>,
         problem: Problem,
         matrices: Option<Vec<Matrix>>,
         solution: Solution,
     ) -> Self {
+        // NOTE coord index is created from original problem to be consistent with routing matrices
+        let coord_index = CoordIndex::new(&problem);
+        let problem = apply_vehicle_states(problem);
         let job_map = problem.plan.jobs.iter().map(|job| (job.id.clone(), job.clone())).collect();
+        let loaded_jobs = get_loaded_jobs(&problem);
 
-        Self { problem, matrices, solution, job_map, core_problem }
+        Self { problem, matrices, solution, job_map, loaded_jobs, coord_index, core_problem }
     }
 
-    /// Performs solution check.
-    pub fn check(&self) -> Result<(), String> {
-        check_vehicle_load(&self)?;
-        check_relations(&self)?;
-        check_breaks(&self)?;
-        check_assignment(&self)?;
-        check_routing(&self)?;
-        check_limits(&self)?;
-
-        Ok(())
+    /// Performs solution check and returns all found violations.
+    pub fn check(&self) -> Result<(), Vec<CheckerError>> {
+        run_checks(
+            self,
+            &[check_vehicle_load, check_relations, check_breaks, check_assignment, check_routing, check_limits],
+        )
     }
 
     /// Gets vehicle by its id.
@@ -144,8 +252,16 @@ impl CheckerContext {
             "reload" => shift
                 .reloads
                 .as_ref()
-                // TODO match reload's time windows
-                .and_then(|reload| reload.iter().find(|r| r.location == location && r.tag == activity.job_tag))
+                .and_then(|reload| {
+                    reload.iter().find(|r| {
+                        let is_in_time = match &r.times {
+                            Some(times) => times.iter().any(|tw| parse_time_window(tw).intersects(&time)),
+                            None => true,
+                        };
+
+                        r.location == location && r.tag == activity.job_tag && is_in_time
+                    })
+                })
                 .map(|r| ActivityType::Reload(r.clone()))
                 .ok_or_else(|| format!("Cannot find reload for tour '{}'", tour.vehicle_id)),
             "dispatch" => shift
@@ -198,6 +314,98 @@ impl CheckerContext {
     }
 }
 
+type CheckFn = fn(&CheckerContext) -> Result<(), Vec<CheckerError>>;
+
+/// Runs all checks and collects their errors.
+fn run_checks(context: &CheckerContext, checks: &[CheckFn]) -> Result<(), Vec<CheckerError>> {
+    into_result(checks.iter().flat_map(|check| check(context).err().unwrap_or_default()).collect())
+}
+
+/// Runs the check for each tour and collects all its errors enriched with tour information.
+fn check_tours<F>(context: &CheckerContext, check_fn: F) -> Result<(), Vec<CheckerError>>
+where
+    F: Fn(&Tour) -> Vec<CheckerError>,
+{
+    into_result(
+        context
+            .solution
+            .tours
+            .iter()
+            .enumerate()
+            .flat_map(|(tour_idx, tour)| check_fn(tour).into_iter().map(move |err| err.with_tour(tour_idx, tour)))
+            .collect(),
+    )
+}
+
+fn into_result(errors: Vec<CheckerError>) -> Result<(), Vec<CheckerError>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Checks that each job task is served exactly once using activity type and tag.
+fn check_job_tasks(job: &Job, served_tasks: &[(String, Option<String>)]) -> Option<CheckerError> {
+    let expected_tasks = get_job_task_keys(job);
+
+    // NOTE tag can be omitted in activity when job has only one task of given type
+    let served_tasks = served_tasks
+        .iter()
+        .map(|(activity_type, tag)| {
+            let same_type = expected_tasks.iter().filter(|(other, _)| other == activity_type).collect::<Vec<_>>();
+            match (tag, same_type.as_slice()) {
+                (None, [task]) => (*task).clone(),
+                _ => (activity_type.clone(), tag.clone()),
+            }
+        })
+        .collect::<Vec<_>>();
+    let served_tasks = served_tasks.as_slice();
+
+    let count_tasks = |tasks: &[(String, Option<String>)], key: &(String, Option<String>)| {
+        tasks.iter().filter(|task| *task == key).count()
+    };
+
+    served_tasks.iter().find_map(|key| {
+        let (activity_type, tag) = key;
+        let tag = tag.as_ref().map_or("<no tag>", |tag| tag.as_str());
+
+        match (count_tasks(&expected_tasks, key), count_tasks(served_tasks, key)) {
+            (0, _) => Some(
+                CheckerError::new(
+                    "JOB_TASK_UNKNOWN",
+                    format!("Cannot find {} task with tag '{}' for '{}'", activity_type, tag, job.id),
+                )
+                .with_job(&job.id),
+            ),
+            (expected, actual) if expected < actual => Some(
+                CheckerError::new(
+                    "JOB_TASK_DUPLICATED",
+                    format!("Task {} with tag '{}' is served more than once for '{}'", activity_type, tag, job.id),
+                )
+                .with_job(&job.id)
+                .with_values(expected, actual),
+            ),
+            _ => None,
+        }
+    })
+}
+
+/// Returns job task keys as pairs of activity type and tag.
+fn get_job_task_keys(job: &Job) -> Vec<(String, Option<String>)> {
+    vec![
+        ("pickup", &job.pickups),
+        ("delivery", &job.deliveries),
+        ("service", &job.services),
+        ("replacement", &job.replacements),
+    ]
+    .into_iter()
+    .flat_map(|(activity_type, tasks)| {
+        tasks.iter().flat_map(|tasks| tasks.iter()).map(move |task| (activity_type.to_string(), task.tag.clone()))
+    })
+    .collect()
+}
+
 fn job_task_size(tasks: &Option<Vec<JobTask>>) -> usize {
     tasks.as_ref().map_or(0, |p| p.len())
 }
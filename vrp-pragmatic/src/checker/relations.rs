@@ -6,106 +6,195 @@ use super::*;
 use hashbrown::HashSet;
 
 /// Checks relation rules.
-pub fn check_relations(context: &CheckerContext) -> Result<(), String> {
+pub fn check_relations(context: &CheckerContext) -> Result<(), Vec<CheckerError>> {
     let reserved_ids = vec!["departure", "arrival", "break", "dispatch", "reload"].into_iter().collect::<HashSet<_>>();
 
-    (0_usize..)
+    let errors = (0_usize..)
         .zip(context.problem.plan.relations.as_ref().map_or(vec![].iter(), |relations| relations.iter()))
-        .try_for_each(|(idx, relation)| {
+        .flat_map(|(idx, relation)| {
             let tour = get_tour_by_vehicle_id(&relation.vehicle_id, relation.shift_index, &context.solution);
             // NOTE tour can be absent for tour relation
-            let tour = if let Ok(tour) = tour {
-                tour
-            } else {
-                return match relation.type_field {
-                    RelationType::Any => Ok(()),
-                    _ => tour.map(|_| ()),
-                };
+            let (tour_idx, tour) = match (tour, &relation.type_field) {
+                (Ok(tour), _) => tour,
+                (Err(_), RelationType::Any) => return vec![],
+                (Err(err), _) => {
+                    return vec![CheckerError {
+                        vehicle_id: Some(relation.vehicle_id.clone()),
+                        shift_index: Some(relation.shift_index.unwrap_or(0)),
+                        ..CheckerError::new("RELATION_TOUR", err)
+                    }]
+                }
             };
 
-            let activity_ids = get_activity_ids(&tour);
-            let relation_ids = relation.jobs.iter().collect::<HashSet<_>>();
-
-            let expected_relation_count = relation_ids.iter().try_fold(0, |acc, job_id| {
-                if let Some(job) = context.get_job_by_id(job_id) {
-                    Ok(acc
-                        + job.pickups.as_ref().map_or(0, |t| t.len())
-                        + job.deliveries.as_ref().map_or(0, |t| t.len())
-                        + job.replacements.as_ref().map_or(0, |t| t.len())
-                        + job.services.as_ref().map_or(0, |t| t.len()))
-                } else if reserved_ids.contains(job_id.as_str()) {
-                    Ok(acc + 1)
-                } else {
-                    Err(format!("Relation has unknown job id: {}", job_id))
-                }
-            })?;
+            check_relation(context, &reserved_ids, idx, relation, &tour)
+                .into_iter()
+                .map(|err| err.with_tour(tour_idx, &tour))
+                .collect()
+        })
+        .collect();
+
+    into_result(errors)
+}
+
+fn check_relation(
+    context: &CheckerContext,
+    reserved_ids: &HashSet<&str>,
+    idx: usize,
+    relation: &Relation,
+    tour: &Tour,
+) -> Vec<CheckerError> {
+    let activities = get_activities(tour);
+    let activity_ids = activities.iter().map(|activity| activity.job_id.clone()).collect::<Vec<_>>();
+    let relation_ids = relation.jobs.iter().collect::<HashSet<_>>();
+
+    let mut job_ids = relation_ids.iter().collect::<Vec<_>>();
+    job_ids.sort();
 
-            if expected_relation_count != relation.jobs.len() {
-                return Err(format!("Relation {} contains duplicated ids: {:?}", idx, relation.jobs));
+    let unknown_jobs = job_ids
+        .iter()
+        .filter(|job_id| context.get_job_by_id(job_id).is_none() && !reserved_ids.contains(job_id.as_str()))
+        .map(|job_id| {
+            CheckerError::new("RELATION_UNKNOWN_JOB", format!("Relation has unknown job id: {}", job_id))
+                .with_job(job_id)
+        })
+        .collect::<Vec<_>>();
+
+    if !unknown_jobs.is_empty() {
+        return unknown_jobs;
+    }
+
+    let mut errors = vec![];
+
+    let expected_relation_count = relation_ids.iter().fold(0, |acc, job_id| {
+        if let Some(job) = context.get_job_by_id(job_id) {
+            acc + job.pickups.as_ref().map_or(0, |t| t.len())
+                + job.deliveries.as_ref().map_or(0, |t| t.len())
+                + job.replacements.as_ref().map_or(0, |t| t.len())
+                + job.services.as_ref().map_or(0, |t| t.len())
+        } else {
+            acc + 1
+        }
+    });
+
+    if expected_relation_count != relation.jobs.len() {
+        errors.push(
+            CheckerError::new(
+                "RELATION_DUPLICATES",
+                format!("Relation {} contains duplicated ids: {:?}", idx, relation.jobs),
+            )
+            .with_values(expected_relation_count, relation.jobs.len()),
+        );
+    }
+
+    // NOTE activities are matched to the relation in the same way as by the rules below
+    let related_activities = match relation.type_field {
+        RelationType::Strict => activities
+            .iter()
+            .skip_while(|activity| relation.jobs.first() != Some(&activity.job_id))
+            .take(relation.jobs.len())
+            .cloned()
+            .collect::<Vec<_>>(),
+        _ => activities.iter().filter(|activity| relation_ids.contains(&activity.job_id)).cloned().collect(),
+    };
+
+    let rule_error = match relation.type_field {
+        RelationType::Strict => {
+            let common = intersection(activity_ids.clone(), relation.jobs.clone());
+            if common != relation.jobs {
+                Err(CheckerError::new(
+                    "RELATION_STRICT",
+                    format!(
+                        "Relation {} does not follow strict rule: expected {:?}, got {:?}, common: {:?}",
+                        idx, relation.jobs, activity_ids, common
+                    ),
+                )
+                .with_values(format!("{:?}", relation.jobs), format!("{:?}", common)))
+            } else {
+                Ok(())
+            }
+        }
+        RelationType::Sequence => {
+            let ids = activity_ids.iter().filter(|id| relation_ids.contains(id)).cloned().collect::<Vec<_>>();
+            if ids != relation.jobs {
+                Err(CheckerError::new(
+                    "RELATION_SEQUENCE",
+                    format!(
+                        "Relation {} does not follow sequence rule: expected {:?}, got {:?}, common: {:?}",
+                        idx, relation.jobs, activity_ids, ids
+                    ),
+                )
+                .with_values(format!("{:?}", relation.jobs), format!("{:?}", ids)))
+            } else {
+                Ok(())
             }
+        }
+        RelationType::Any => {
+            let wrong_job_id = context
+                .solution
+                .tours
+                .iter()
+                .filter(|other| tour.vehicle_id != other.vehicle_id)
+                .flat_map(|tour| get_activities(tour).into_iter())
+                .find(|activity| relation_ids.contains(&activity.job_id));
 
-            match relation.type_field {
-                RelationType::Strict => {
-                    let common = intersection(activity_ids.clone(), relation.jobs.clone());
-                    if common != relation.jobs {
-                        Err(format!(
-                            "Relation {} does not follow strict rule: expected {:?}, got {:?}, common: {:?}",
-                            idx, relation.jobs, activity_ids, common
-                        ))
-                    } else {
-                        Ok(())
-                    }
-                }
-                RelationType::Sequence => {
-                    let ids = activity_ids.iter().filter(|id| relation_ids.contains(id)).cloned().collect::<Vec<_>>();
-                    if ids != relation.jobs {
-                        Err(format!(
-                            "Relation {} does not follow sequence rule: expected {:?}, got {:?}, common: {:?}",
-                            idx, relation.jobs, activity_ids, ids
-                        ))
-                    } else {
-                        Ok(())
-                    }
-                }
-                RelationType::Any => {
-                    let has_wrong_assignment = context
-                        .solution
-                        .tours
-                        .iter()
-                        .filter(|other| tour.vehicle_id != other.vehicle_id)
-                        .any(|tour| get_activity_ids(tour).iter().any(|id| relation_ids.contains(id)));
-
-                    if has_wrong_assignment {
-                        Err(format!("Relation {} has jobs assigned to another tour", idx))
-                    } else {
-                        Ok(())
-                    }
-                }
+            if let Some(activity) = wrong_job_id {
+                Err(CheckerError::new("RELATION_ANY", format!("Relation {} has jobs assigned to another tour", idx))
+                    .with_job(&activity.job_id))
+            } else {
+                Ok(())
             }
-        })?;
+        }
+    };
+
+    errors.extend(rule_error.err());
+    errors.extend(check_multi_job_tasks(context, &related_activities, &relation_ids));
 
-    Ok(())
+    errors
 }
 
-fn get_tour_by_vehicle_id(vehicle_id: &str, shift_index: Option<usize>, solution: &Solution) -> Result<Tour, String> {
+fn get_tour_by_vehicle_id(
+    vehicle_id: &str,
+    shift_index: Option<usize>,
+    solution: &Solution,
+) -> Result<(usize, Tour), String> {
     solution
         .tours
         .iter()
-        .find(|tour| tour.vehicle_id == vehicle_id && tour.shift_index == shift_index.unwrap_or(0))
-        .cloned()
+        .enumerate()
+        .find(|(_, tour)| tour.vehicle_id == vehicle_id && tour.shift_index == shift_index.unwrap_or(0))
+        .map(|(idx, tour)| (idx, tour.clone()))
         .ok_or_else(|| format!("Cannot find tour for '{}'", vehicle_id))
 }
 
-fn get_activity_ids(tour: &Tour) -> Vec<String> {
-    tour.stops
-        .iter()
-        .flat_map(|stop| {
-            // TODO consider job tags within multi jobs
-            stop.activities.iter().map(|a| a.job_id.clone())
+/// Checks that activities of multi jobs in the relation are matched to distinct job tasks by their tags.
+fn check_multi_job_tasks(
+    context: &CheckerContext,
+    activities: &[&Activity],
+    relation_ids: &HashSet<&String>,
+) -> Vec<CheckerError> {
+    let mut job_ids = relation_ids.iter().collect::<Vec<_>>();
+    job_ids.sort();
+
+    job_ids
+        .into_iter()
+        .filter_map(|job_id| context.get_job_by_id(job_id))
+        .filter(|job| get_job_task_keys(job).len() > 1)
+        .filter_map(|job| {
+            let served_tasks = activities
+                .iter()
+                .filter(|activity| activity.job_id == job.id)
+                .map(|activity| (activity.activity_type.clone(), activity.job_tag.clone()))
+                .collect::<Vec<_>>();
+
+            check_job_tasks(job, served_tasks.as_slice())
         })
         .collect()
 }
 
+fn get_activities(tour: &Tour) -> Vec<&Activity> {
+    tour.stops.iter().flat_map(|stop| stop.activities.iter()).collect()
+}
+
 fn intersection<T>(left: Vec<T>, right: Vec<T>) -> Vec<T>
 where
     T: PartialEq,
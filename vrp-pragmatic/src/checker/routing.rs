@@ -7,81 +7,128 @@ use crate::format::CoordIndex;
 use crate::format_time;
 
 /// Checks that matrix routing information is used properly.
-pub fn check_routing(context: &CheckerContext) -> Result<(), String> {
+pub fn check_routing(context: &CheckerContext) -> Result<(), Vec<CheckerError>> {
     if context.matrices.as_ref().map_or(true, |m| m.is_empty()) {
         return Ok(());
     }
-    let matrices = get_matrices(context)?;
+    let to_errors = |err: String| vec![CheckerError::new("ROUTING_PRECONDITION", err)];
+    let matrices = get_matrices(context).map_err(to_errors)?;
     let matrix_size = get_matrix_size(matrices);
-    let profile_index = get_profile_index(context, matrices)?;
-    let coord_index = CoordIndex::new(&context.problem);
-
-    context.solution.tours.iter().try_for_each::<_, Result<_, String>>(|tour| {
-        let profile = &context.get_vehicle(&tour.vehicle_id)?.profile;
-        let matrix = profile_index
-            .get(profile.as_str())
-            .and_then(|idx| matrices.get(*idx))
-            .ok_or(format!("cannot get matrix for '{}' profile", profile))?;
-        let time_offset =
-            parse_time(&tour.stops.first().ok_or_else(|| "empty tour".to_string())?.time.departure) as i64;
-
-        let (departure_time, total_distance) = tour.stops.windows(2).enumerate().try_fold::<_, _, Result<_, String>>(
-            (time_offset, 0),
-            |(time, total_distance), (leg_idx, stops)| {
-                let (from, to) = match stops {
-                    [from, to] => (from, to),
-                    _ => unreachable!(),
-                };
-
-                let from_idx = get_location_index(&from.location, &coord_index)?;
-                let to_idx = get_location_index(&to.location, &coord_index)?;
-                let matrix_idx = from_idx * matrix_size + to_idx;
-
-                let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
-                let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
-
-                let time = time + duration;
-                let total_distance = total_distance + distance;
-
-                check_stop_statistic(time, total_distance, leg_idx + 1, to, tour)?;
-
-                Ok((parse_time(&to.time.departure) as i64, to.distance))
-            },
-        )?;
-
-        check_tour_statistic(departure_time, total_distance, time_offset, tour)
-    })?;
-
-    check_solution_statistic(&context.solution)
+    let profile_index = get_profile_index(context, matrices).map_err(to_errors)?;
+
+    let tour_errors = check_tours(context, |tour| {
+        check_tour_routing(context, matrices, matrix_size, &profile_index, tour).unwrap_or_else(|err| vec![err.into()])
+    });
+
+    let errors = tour_errors
+        .err()
+        .unwrap_or_default()
+        .into_iter()
+        .chain(check_solution_statistic(&context.solution).err().into_iter())
+        .collect();
+
+    into_result(errors)
+}
+
+/// Returns all routing violations of the tour or an error if the tour cannot be checked.
+fn check_tour_routing(
+    context: &CheckerContext,
+    matrices: &[Matrix],
+    matrix_size: usize,
+    profile_index: &HashMap<&str, usize>,
+    tour: &Tour,
+) -> Result<Vec<CheckerError>, String> {
+    let coord_index = &context.coord_index;
+    let profile = &context.get_vehicle(&tour.vehicle_id)?.profile;
+    let matrix = profile_index
+        .get(profile.as_str())
+        .and_then(|idx| matrices.get(*idx))
+        .ok_or(format!("cannot get matrix for '{}' profile", profile))?;
+    let time_offset = parse_time(&tour.stops.first().ok_or_else(|| "empty tour".to_string())?.time.departure) as i64;
+
+    let mut errors = vec![];
+
+    let (departure_time, total_distance) = tour.stops.windows(2).enumerate().try_fold::<_, _, Result<_, String>>(
+        (time_offset, 0),
+        |(time, total_distance), (leg_idx, stops)| {
+            let (from, to) = match stops {
+                [from, to] => (from, to),
+                _ => unreachable!(),
+            };
+
+            let from_idx = get_location_index(&from.location, coord_index)?;
+            let to_idx = get_location_index(&to.location, coord_index)?;
+            let matrix_idx = from_idx * matrix_size + to_idx;
+
+            let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
+            let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
+
+            let time = time + duration;
+            let total_distance = total_distance + distance;
+
+            errors.extend(check_stop_statistic(time, total_distance, leg_idx + 1, to, tour));
+
+            // NOTE continue with expected distance to avoid reporting the same violation for the next stops
+            Ok((parse_time(&to.time.departure) as i64, total_distance))
+        },
+    )?;
+
+    errors.extend(check_tour_statistic(departure_time, total_distance, time_offset, tour));
+
+    Ok(errors)
 }
 
-fn check_stop_statistic(time: i64, total_distance: i64, stop_idx: usize, to: &Stop, tour: &Tour) -> Result<(), String> {
+fn check_stop_statistic(time: i64, total_distance: i64, stop_idx: usize, to: &Stop, tour: &Tour) -> Vec<CheckerError> {
+    let mut errors = vec![];
+
     if (time - parse_time(&to.time.arrival) as i64).abs() > 1 {
-        return Err(format!(
-            "arrival time mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
-            stop_idx,
-            tour.vehicle_id,
-            format_time(time as f64),
-            to.time.arrival
-        ));
+        errors.push(
+            CheckerError::new(
+                "ARRIVAL_TIME_MISMATCH",
+                format!(
+                    "arrival time mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
+                    stop_idx,
+                    tour.vehicle_id,
+                    format_time(time as f64),
+                    to.time.arrival
+                ),
+            )
+            .with_stop(stop_idx)
+            .with_values(format_time(time as f64), &to.time.arrival),
+        );
     }
 
     if (total_distance - to.distance).abs() > 1 {
-        return Err(format!(
-            "distance mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
-            stop_idx, tour.vehicle_id, total_distance, to.distance,
-        ));
+        errors.push(
+            CheckerError::new(
+                "DISTANCE_MISMATCH",
+                format!(
+                    "distance mismatch for {} stop in the tour: {}, expected: '{}', got: '{}'",
+                    stop_idx, tour.vehicle_id, total_distance, to.distance,
+                ),
+            )
+            .with_stop(stop_idx)
+            .with_values(total_distance, to.distance),
+        );
     }
 
-    Ok(())
+    errors
 }
 
-fn check_tour_statistic(departure_time: i64, total_distance: i64, time_offset: i64, tour: &Tour) -> Result<(), String> {
+fn check_tour_statistic(departure_time: i64, total_distance: i64, time_offset: i64, tour: &Tour) -> Vec<CheckerError> {
+    let mut errors = vec![];
+
     if (total_distance - tour.statistic.distance).abs() > 1 {
-        return Err(format!(
-            "distance mismatch for tour statistic: {}, expected: '{}', got: '{}'",
-            tour.vehicle_id, total_distance, tour.statistic.distance,
-        ));
+        errors.push(
+            CheckerError::new(
+                "TOUR_DISTANCE_MISMATCH",
+                format!(
+                    "distance mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                    tour.vehicle_id, total_distance, tour.statistic.distance,
+                ),
+            )
+            .with_values(total_distance, tour.statistic.distance),
+        );
     }
 
     let dispatch_at_start_correction =
@@ -103,21 +150,31 @@ fn check_tour_statistic(departure_time: i64, total_distance: i64, time_offset: i
 
     let total_duration = departure_time - time_offset + dispatch_at_start_correction;
     if (total_duration - tour.statistic.duration).abs() > 1 {
-        return Err(format!(
-            "duration mismatch for tour statistic: {}, expected: '{}', got: '{}'",
-            tour.vehicle_id, total_duration, tour.statistic.duration,
-        ));
+        errors.push(
+            CheckerError::new(
+                "TOUR_DURATION_MISMATCH",
+                format!(
+                    "duration mismatch for tour statistic: {}, expected: '{}', got: '{}'",
+                    tour.vehicle_id, total_duration, tour.statistic.duration,
+                ),
+            )
+            .with_values(total_duration, tour.statistic.duration),
+        );
     }
 
-    Ok(())
+    errors
 }
 
-fn check_solution_statistic(solution: &Solution) -> Result<(), String> {
+fn check_solution_statistic(solution: &Solution) -> Result<(), CheckerError> {
     let statistic = solution.tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
     // NOTE cost should be ignored due to floating point issues
     if statistic.duration != solution.statistic.duration || statistic.distance != solution.statistic.distance {
-        Err(format!("solution statistic mismatch, expected: '{:?}', got: '{:?}'", statistic, solution.statistic))
+        Err(CheckerError::new(
+            "SOLUTION_STATISTIC_MISMATCH",
+            format!("solution statistic mismatch, expected: '{:?}', got: '{:?}'", statistic, solution.statistic),
+        )
+        .with_values(format!("{:?}", statistic), format!("{:?}", solution.statistic)))
     } else {
         Ok(())
     }
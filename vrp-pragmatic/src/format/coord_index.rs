@@ -56,6 +56,8 @@ impl CoordIndex {
             });
         });
 
+        problem.fleet.states.iter().flatten().for_each(|state| index.add(&state.location));
+
         index
     }
 
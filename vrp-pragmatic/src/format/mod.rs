@@ -100,6 +100,11 @@ impl FormatError {
     pub fn format_many(errors: &[Self], separator: &str) -> String {
         errors.iter().map(|err| err.to_string()).collect::<Vec<_>>().join(separator)
     }
+
+    /// Serializes multiple format errors into json array.
+    pub fn format_many_to_json(errors: &[Self]) -> String {
+        serde_json::to_string_pretty(errors).expect("cannot serialize format errors")
+    }
 }
 
 impl std::fmt::Display for FormatError {
@@ -143,3 +148,9 @@ pub(crate) fn get_coord_index(problem: &CoreProblem) -> &CoordIndex {
         .and_then(|s| s.downcast_ref::<CoordIndex>())
         .unwrap_or_else(|| panic!("Cannot get coord index!"))
 }
+
+/// Checks whether job id is reserved for special activities: departure, arrival, break, dispatch
+/// or reload. Such ids are used in solution activities and in relations.
+pub fn is_reserved_job_id(job_id: &str) -> bool {
+    job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "dispatch"
+}
@@ -0,0 +1,328 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/problem/builder_test.rs"]
+mod builder_test;
+
+use crate::format::problem::*;
+use crate::format::{FormatError, Location};
+use crate::validation::ValidationContext;
+
+/// Provides the way to build a job task.
+pub struct JobTaskBuilder {
+    task: JobTask,
+}
+
+impl Default for JobTaskBuilder {
+    fn default() -> Self {
+        Self { task: JobTask { places: vec![], demand: None, tag: None } }
+    }
+}
+
+impl JobTaskBuilder {
+    /// Adds a place where task can be performed. Time windows are specified by start and end
+    /// time in RFC3339 format, empty list means no time restrictions.
+    pub fn add_place(mut self, location: Location, duration: f64, times: &[(&str, &str)]) -> Self {
+        let times = if times.is_empty() {
+            None
+        } else {
+            Some(times.iter().map(|(start, end)| vec![start.to_string(), end.to_string()]).collect())
+        };
+
+        self.task.places.push(JobPlace { location, duration, times });
+        self
+    }
+
+    /// Sets task demand.
+    pub fn with_demand(mut self, demand: Vec<i32>) -> Self {
+        self.task.demand = Some(demand);
+        self
+    }
+
+    /// Sets task tag which is propagated back within corresponding activity in solution.
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.task.tag = Some(tag.to_string());
+        self
+    }
+
+    /// Builds a job task.
+    pub fn build(self) -> JobTask {
+        self.task
+    }
+}
+
+/// Provides the way to build a job.
+pub struct JobBuilder {
+    job: Job,
+}
+
+impl JobBuilder {
+    /// Creates a new instance of `JobBuilder` for a job with given id.
+    pub fn new(id: &str) -> Self {
+        Self {
+            job: Job {
+                id: id.to_string(),
+                pickups: None,
+                deliveries: None,
+                replacements: None,
+                services: None,
+                priority: None,
+                skills: None,
+            },
+        }
+    }
+
+    /// Adds a pickup task.
+    pub fn add_pickup(mut self, task: JobTask) -> Self {
+        self.job.pickups.get_or_insert_with(Vec::new).push(task);
+        self
+    }
+
+    /// Adds a delivery task.
+    pub fn add_delivery(mut self, task: JobTask) -> Self {
+        self.job.deliveries.get_or_insert_with(Vec::new).push(task);
+        self
+    }
+
+    /// Adds a replacement task.
+    pub fn add_replacement(mut self, task: JobTask) -> Self {
+        self.job.replacements.get_or_insert_with(Vec::new).push(task);
+        self
+    }
+
+    /// Adds a service task.
+    pub fn add_service(mut self, task: JobTask) -> Self {
+        self.job.services.get_or_insert_with(Vec::new).push(task);
+        self
+    }
+
+    /// Sets job priority, bigger value - less important.
+    pub fn with_priority(mut self, priority: i32) -> Self {
+        self.job.priority = Some(priority);
+        self
+    }
+
+    /// Sets job skills.
+    pub fn with_skills(mut self, skills: JobSkills) -> Self {
+        self.job.skills = Some(skills);
+        self
+    }
+
+    /// Builds a job.
+    pub fn build(self) -> Job {
+        self.job
+    }
+}
+
+/// Provides the way to build a vehicle shift.
+pub struct VehicleShiftBuilder {
+    shift: VehicleShift,
+}
+
+impl VehicleShiftBuilder {
+    /// Creates a new instance of `VehicleShiftBuilder` for a shift which starts at given location
+    /// not earlier than given time in RFC3339 format.
+    pub fn new(location: Location, earliest: &str) -> Self {
+        Self {
+            shift: VehicleShift {
+                start: ShiftStart { earliest: earliest.to_string(), latest: None, location },
+                end: None,
+                dispatch: None,
+                breaks: None,
+                reloads: None,
+            },
+        }
+    }
+
+    /// Sets latest possible departure time in RFC3339 format.
+    pub fn with_latest_start(mut self, latest: &str) -> Self {
+        self.shift.start.latest = Some(latest.to_string());
+        self
+    }
+
+    /// Sets shift end location and latest possible arrival time in RFC3339 format. If end is
+    /// not set, a vehicle finishes its tour at the last job location.
+    pub fn with_end(mut self, location: Location, latest: &str) -> Self {
+        self.shift.end = Some(ShiftEnd { earliest: None, latest: latest.to_string(), location });
+        self
+    }
+
+    /// Adds a dispatch place.
+    pub fn add_dispatch(mut self, dispatch: VehicleDispatch) -> Self {
+        self.shift.dispatch.get_or_insert_with(Vec::new).push(dispatch);
+        self
+    }
+
+    /// Adds a vehicle break.
+    pub fn add_break(mut self, vehicle_break: VehicleBreak) -> Self {
+        self.shift.breaks.get_or_insert_with(Vec::new).push(vehicle_break);
+        self
+    }
+
+    /// Adds a vehicle reload.
+    pub fn add_reload(mut self, reload: VehicleReload) -> Self {
+        self.shift.reloads.get_or_insert_with(Vec::new).push(reload);
+        self
+    }
+
+    /// Builds a vehicle shift.
+    pub fn build(self) -> VehicleShift {
+        self.shift
+    }
+}
+
+/// Provides the way to build a vehicle type.
+pub struct VehicleTypeBuilder {
+    vehicle: VehicleType,
+}
+
+impl VehicleTypeBuilder {
+    /// Creates a new instance of `VehicleTypeBuilder` for a vehicle type with given id,
+    /// routing profile and costs.
+    pub fn new(type_id: &str, profile: &str, costs: VehicleCosts) -> Self {
+        Self {
+            vehicle: VehicleType {
+                type_id: type_id.to_string(),
+                vehicle_ids: vec![],
+                profile: profile.to_string(),
+                costs,
+                shifts: vec![],
+                capacity: vec![],
+                skills: None,
+                limits: None,
+            },
+        }
+    }
+
+    /// Adds a concrete vehicle id.
+    pub fn add_vehicle_id(mut self, vehicle_id: &str) -> Self {
+        self.vehicle.vehicle_ids.push(vehicle_id.to_string());
+        self
+    }
+
+    /// Adds a vehicle shift.
+    pub fn add_shift(mut self, shift: VehicleShift) -> Self {
+        self.vehicle.shifts.push(shift);
+        self
+    }
+
+    /// Sets vehicle capacity.
+    pub fn with_capacity(mut self, capacity: Vec<i32>) -> Self {
+        self.vehicle.capacity = capacity;
+        self
+    }
+
+    /// Sets vehicle skills.
+    pub fn with_skills(mut self, skills: Vec<String>) -> Self {
+        self.vehicle.skills = Some(skills);
+        self
+    }
+
+    /// Sets vehicle limits.
+    pub fn with_limits(mut self, limits: VehicleLimits) -> Self {
+        self.vehicle.limits = Some(limits);
+        self
+    }
+
+    /// Builds a vehicle type.
+    pub fn build(self) -> VehicleType {
+        self.vehicle
+    }
+}
+
+/// Provides the way to build a problem definition. The problem is validated on build.
+///
+/// # Examples
+///
+/// ```
+/// use vrp_pragmatic::format::{FormatError, Location};
+/// use vrp_pragmatic::format::problem::*;
+///
+/// let problem = ProblemBuilder::default()
+///     .add_job(
+///         JobBuilder::new("job1")
+///             .add_delivery(
+///                 JobTaskBuilder::default()
+///                     .add_place(Location::new_coordinate(52.52, 13.41), 300., &[])
+///                     .with_demand(vec![1])
+///                     .build(),
+///             )
+///             .build(),
+///     )
+///     .add_vehicle(
+///         VehicleTypeBuilder::new("vehicle", "car", VehicleCosts { fixed: Some(20.), distance: 0.002, time: 0.003 })
+///             .add_vehicle_id("vehicle_1")
+///             .add_shift(VehicleShiftBuilder::new(Location::new_coordinate(52.46, 13.40), "2020-07-04T09:00:00Z").build())
+///             .with_capacity(vec![10])
+///             .build(),
+///     )
+///     .add_profile(Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None })
+///     .build()
+///     .map_err(|errors| FormatError::format_many(&errors, ","))?;
+///
+/// assert_eq!(problem.plan.jobs.len(), 1);
+/// # Ok::<(), String>(())
+/// ```
+pub struct ProblemBuilder {
+    problem: Problem,
+}
+
+impl Default for ProblemBuilder {
+    fn default() -> Self {
+        Self {
+            problem: Problem {
+                plan: Plan { jobs: vec![], relations: None },
+                fleet: Fleet { vehicles: vec![], profiles: vec![], states: None },
+                objectives: None,
+            },
+        }
+    }
+}
+
+impl ProblemBuilder {
+    /// Adds a job.
+    pub fn add_job(mut self, job: Job) -> Self {
+        self.problem.plan.jobs.push(job);
+        self
+    }
+
+    /// Adds a relation between jobs and a vehicle.
+    pub fn add_relation(mut self, relation: Relation) -> Self {
+        self.problem.plan.relations.get_or_insert_with(Vec::new).push(relation);
+        self
+    }
+
+    /// Adds a vehicle type.
+    pub fn add_vehicle(mut self, vehicle: VehicleType) -> Self {
+        self.problem.fleet.vehicles.push(vehicle);
+        self
+    }
+
+    /// Adds a routing profile.
+    pub fn add_profile(mut self, profile: Profile) -> Self {
+        self.problem.fleet.profiles.push(profile);
+        self
+    }
+
+    /// Adds a state of the vehicle which has already started its tour.
+    pub fn add_vehicle_state(mut self, state: VehicleState) -> Self {
+        self.problem.fleet.states.get_or_insert_with(Vec::new).push(state);
+        self
+    }
+
+    /// Sets objectives. Default objectives are used if not set.
+    pub fn with_objectives(mut self, objectives: Objectives) -> Self {
+        self.problem.objectives = Some(objectives);
+        self
+    }
+
+    /// Builds a problem and validates it.
+    pub fn build(self) -> Result<Problem, Vec<FormatError>> {
+        self.build_with_matrices(None)
+    }
+
+    /// Builds a problem and validates it using routing matrices which will be used to solve it.
+    pub fn build_with_matrices(self, matrices: Option<&Vec<Matrix>>) -> Result<Problem, Vec<FormatError>> {
+        ValidationContext::new(&self.problem, matrices).validate()?;
+
+        Ok(self.problem)
+    }
+}
@@ -5,7 +5,7 @@ mod fleet_reader_test;
 use crate::extensions::create_typed_actor_groups;
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{ApiProblem, ProblemProperties};
-use crate::format::problem::Matrix;
+use crate::format::problem::{get_loaded_jobs, Matrix};
 use crate::parse_time;
 use hashbrown::{HashMap, HashSet};
 use std::iter::FromIterator;
@@ -78,6 +78,7 @@ pub(crate) fn create_transport_costs(
 
 pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, coord_index: &CoordIndex) -> Fleet {
     let profiles = get_profile_map(api_problem);
+    let states = get_vehicle_states(api_problem, coord_index);
     let mut vehicles: Vec<Arc<Vehicle>> = Default::default();
 
     api_problem.fleet.vehicles.iter().for_each(|vehicle| {
@@ -128,6 +129,8 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             }];
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+                let state = states.get(&(vehicle_id.clone(), shift_index));
+
                 let mut dimens: Dimensions = Default::default();
                 dimens.set_value("type_id", vehicle.type_id.clone());
                 dimens.set_value("shift_index", shift_index);
@@ -148,7 +151,29 @@ pub(crate) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                 }
                 add_vehicle_skills(&mut dimens, &vehicle.skills);
 
-                vehicles.push(Arc::new(Vehicle { profile, costs: costs.clone(), dimens, details: details.clone() }));
+                let details = if let Some((location, time, load)) = state {
+                    if props.has_multi_dimen_capacity {
+                        dimens.set_initial_load(*load);
+                    } else {
+                        dimens.set_initial_load(SingleDimLoad::new(load.load[0]));
+                    }
+
+                    // NOTE the rest of the tour starts from vehicle's current location and time
+                    details
+                        .iter()
+                        .map(|detail| VehicleDetail {
+                            start: Some(VehiclePlace {
+                                location: *location,
+                                time: TimeInterval { earliest: Some(*time), latest: None },
+                            }),
+                            end: detail.end.clone(),
+                        })
+                        .collect()
+                } else {
+                    details.clone()
+                };
+
+                vehicles.push(Arc::new(Vehicle { profile, costs: costs.clone(), dimens, details }));
             });
         }
     });
@@ -191,6 +216,40 @@ pub fn read_travel_limits(api_problem: &ApiProblem) -> Option<TravelLimitFunc> {
     }
 }
 
+/// Returns current location, time and load of vehicles which have already started their tours.
+fn get_vehicle_states(
+    api_problem: &ApiProblem,
+    coord_index: &CoordIndex,
+) -> HashMap<(String, usize), (Location, Timestamp, MultiDimLoad)> {
+    let loaded_jobs = get_loaded_jobs(api_problem);
+    let get_load = |vehicle_id: &String, shift_index: usize| {
+        api_problem
+            .plan
+            .jobs
+            .iter()
+            .filter(|job| {
+                loaded_jobs.get(&job.id).map_or(false, |(id, index)| id == vehicle_id && *index == shift_index)
+            })
+            .flat_map(|job| job.deliveries.iter().flatten())
+            .filter_map(|task| task.demand.clone())
+            .fold(MultiDimLoad::new(vec![0]), |acc, demand| acc + MultiDimLoad::new(demand))
+    };
+
+    api_problem
+        .fleet
+        .states
+        .iter()
+        .flatten()
+        .map(|state| {
+            let shift_index = state.shift_index.unwrap_or(0);
+            let location = coord_index.get_by_loc(&state.location).unwrap();
+            let time = parse_time(&state.time);
+
+            ((state.vehicle_id.clone(), shift_index), (location, time, get_load(&state.vehicle_id, shift_index)))
+        })
+        .collect()
+}
+
 fn get_profile_map(api_problem: &ApiProblem) -> HashMap<String, i32> {
     api_problem.fleet.profiles.iter().fold(Default::default(), |mut acc, profile| {
         if acc.get(&profile.name) == None {
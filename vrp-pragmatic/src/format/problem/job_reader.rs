@@ -1,7 +1,7 @@
 use crate::format::coord_index::CoordIndex;
 use crate::format::problem::reader::{parse_time_window, ApiProblem, ProblemProperties};
 use crate::format::problem::{
-    JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleDispatch, VehicleReload, VehicleType,
+    get_loaded_jobs, JobTask, RelationType, VehicleBreak, VehicleBreakTime, VehicleDispatch, VehicleReload, VehicleType,
 };
 use crate::format::{JobIndex, Location};
 use crate::utils::VariableJobPermutation;
@@ -131,10 +131,13 @@ fn read_required_jobs(
         get_single_with_extras(places, demand, &task.tag, activity_type, has_multi_dimens, &coord_index)
     };
 
+    // NOTE deliveries of already loaded jobs keep dynamic demand as vehicle has its goods on board
+    let loaded_jobs = get_loaded_jobs(api_problem);
+
     api_problem.plan.jobs.iter().for_each(|job| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
-        let is_static_demand = pickups == 0 || deliveries == 0;
+        let is_static_demand = (pickups == 0 || deliveries == 0) && !loaded_jobs.contains_key(&job.id);
 
         let singles =
             job.pickups
@@ -174,7 +177,27 @@ fn read_required_jobs(
         jobs.push(problem_job);
     });
 
-    (jobs, vec![])
+    (jobs, read_loaded_locks(&loaded_jobs, job_index))
+}
+
+/// Creates locks which keep already loaded jobs within vehicles which have them on board.
+fn read_loaded_locks(loaded_jobs: &HashMap<String, (String, usize)>, job_index: &JobIndex) -> Vec<Arc<Lock>> {
+    let vehicles = loaded_jobs.iter().fold(HashMap::<_, Vec<_>>::new(), |mut acc, (job_id, vehicle)| {
+        if let Some(job) = job_index.get(job_id) {
+            acc.entry(vehicle.clone()).or_default().push(job.clone());
+        }
+        acc
+    });
+
+    vehicles
+        .into_iter()
+        .map(|((vehicle_id, shift_index), jobs)| {
+            let condition = create_condition(vehicle_id, shift_index);
+            let details = vec![LockDetail::new(LockOrder::Any, LockPosition::Any, jobs)];
+
+            Arc::new(Lock::new(condition, details, false))
+        })
+        .collect()
 }
 
 fn read_conditional_jobs(
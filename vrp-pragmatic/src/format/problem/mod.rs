@@ -4,5 +4,11 @@
 mod model;
 pub use self::model::*;
 
+mod builder;
+pub use self::builder::*;
+
 mod reader;
 pub use self::reader::PragmaticProblem;
+
+mod vehicle_state;
+pub(crate) use self::vehicle_state::*;
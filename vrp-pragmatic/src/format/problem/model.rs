@@ -347,6 +347,33 @@ pub struct Profile {
     pub speed: Option<f64>,
 }
 
+/// Specifies state of the vehicle which has already started its tour, e.g. when
+/// replanning is performed during the day.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleState {
+    /// Vehicle id.
+    pub vehicle_id: String,
+
+    /// Vehicle shift index. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+
+    /// Current vehicle location. The rest of the tour starts from it.
+    pub location: Location,
+
+    /// Current time. The rest of the tour cannot start earlier.
+    pub time: String,
+
+    /// Ids of jobs which are already served by the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub served: Option<Vec<String>>,
+
+    /// Ids of pickup and delivery jobs which are picked up, but not yet delivered by the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loaded: Option<Vec<String>>,
+}
+
 /// Specifies fleet.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Fleet {
@@ -354,6 +381,9 @@ pub struct Fleet {
     pub vehicles: Vec<VehicleType>,
     /// Routing profiles.
     pub profiles: Vec<Profile>,
+    /// Current states of vehicles which have already started their tours.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub states: Option<Vec<VehicleState>>,
 }
 
 // endregion
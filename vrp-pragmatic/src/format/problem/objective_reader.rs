@@ -78,6 +78,29 @@ pub fn create_objective(
     })
 }
 
+/// Returns objective names in the same order as core objectives are created, primary first.
+pub fn get_objective_names(api_problem: &ApiProblem) -> Vec<String> {
+    let get_name = |objective: &_| {
+        match objective {
+            MinimizeCost => "minimize-cost",
+            MinimizeTours => "minimize-tours",
+            MaximizeTours => "maximize-tours",
+            MinimizeUnassignedJobs { .. } => "minimize-unassigned",
+            BalanceMaxLoad { .. } => "balance-max-load",
+            BalanceActivities { .. } => "balance-activities",
+            BalanceDistance { .. } => "balance-distance",
+            BalanceDuration { .. } => "balance-duration",
+        }
+        .to_string()
+    };
+
+    if let Some(objectives) = &api_problem.objectives {
+        objectives.primary.iter().chain(objectives.secondary.iter().flatten()).map(get_name).collect()
+    } else {
+        vec!["minimize-unassigned".to_string(), "minimize-tours".to_string(), "minimize-cost".to_string()]
+    }
+}
+
 fn unwrap_options(options: &Option<BalanceOptions>) -> (Option<f64>, Option<f64>) {
     (options.as_ref().and_then(|o| o.threshold), options.as_ref().and_then(|o| o.tolerance))
 }
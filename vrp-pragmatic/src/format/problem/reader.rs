@@ -13,11 +13,11 @@ mod objective_reader;
 
 use self::fleet_reader::{create_transport_costs, read_fleet, read_travel_limits};
 use self::job_reader::{read_jobs_with_extra_locks, read_locks};
-use self::objective_reader::create_objective;
+use self::objective_reader::{create_objective, get_objective_names};
 use crate::constraints::*;
 use crate::extensions::{get_route_modifier, OnlyVehicleActivityCost};
 use crate::format::coord_index::CoordIndex;
-use crate::format::problem::{deserialize_matrix, deserialize_problem, Matrix};
+use crate::format::problem::{apply_vehicle_states, deserialize_matrix, deserialize_problem, Matrix};
 use crate::format::*;
 use crate::utils::get_approx_transportation;
 use crate::validation::ValidationContext;
@@ -169,6 +169,8 @@ fn map_to_problem(
 ) -> Result<Problem, Vec<FormatError>> {
     ValidationContext::new(&api_problem, Some(&matrices)).validate()?;
 
+    let api_problem = apply_vehicle_states(api_problem);
+
     let problem_props = get_problem_properties(&api_problem, &matrices);
 
     let coord_index = Arc::new(coord_index);
@@ -209,7 +211,8 @@ fn map_to_problem(
 
     let objective = create_objective(&api_problem, &mut constraint, &problem_props);
     let constraint = Arc::new(constraint);
-    let extras = Arc::new(create_extras(constraint.clone(), &problem_props, job_index, coord_index));
+    let objective_names = get_objective_names(&api_problem);
+    let extras = Arc::new(create_extras(constraint.clone(), &problem_props, job_index, coord_index, objective_names));
 
     Ok(Problem {
         fleet: Arc::new(fleet),
@@ -324,6 +327,7 @@ fn create_extras(
     props: &ProblemProperties,
     job_index: JobIndex,
     coord_index: Arc<CoordIndex>,
+    objective_names: Vec<String>,
 ) -> Extras {
     let mut extras = Extras::default();
     extras.insert(
@@ -332,6 +336,7 @@ fn create_extras(
     );
     extras.insert("coord_index".to_owned(), coord_index);
     extras.insert("job_index".to_owned(), Arc::new(job_index.clone()));
+    extras.insert("objective_names".to_owned(), Arc::new(objective_names));
 
     if props.has_dispatch {
         extras.insert("route_modifier".to_owned(), Arc::new(get_route_modifier(constraint, job_index)));
@@ -0,0 +1,70 @@
+//! Contains helper logic to apply states of vehicles which have already started their tours.
+
+use crate::format::problem::Problem;
+use hashbrown::{HashMap, HashSet};
+
+/// Returns ids of jobs which are already served by vehicles.
+pub(crate) fn get_served_jobs(problem: &Problem) -> HashSet<String> {
+    problem.fleet.states.iter().flatten().flat_map(|state| state.served.iter().flatten()).cloned().collect()
+}
+
+/// Returns ids of jobs which are already loaded to vehicles together with vehicle id and shift index.
+pub(crate) fn get_loaded_jobs(problem: &Problem) -> HashMap<String, (String, usize)> {
+    problem
+        .fleet
+        .states
+        .iter()
+        .flatten()
+        .flat_map(|state| {
+            state
+                .loaded
+                .iter()
+                .flatten()
+                .map(move |job_id| (job_id.clone(), (state.vehicle_id.clone(), state.shift_index.unwrap_or(0))))
+        })
+        .collect()
+}
+
+/// Prepares the problem to plan only the rest of the tours: removes already served jobs from
+/// the plan and pickups of already loaded jobs.
+pub(crate) fn apply_vehicle_states(problem: Problem) -> Problem {
+    if problem.fleet.states.as_ref().map_or(true, |states| states.is_empty()) {
+        return problem;
+    }
+
+    let served_jobs = get_served_jobs(&problem);
+    let loaded_jobs = get_loaded_jobs(&problem);
+
+    let mut problem = problem;
+
+    problem.plan.jobs.retain(|job| !served_jobs.contains(&job.id));
+
+    let deliveries = problem
+        .plan
+        .jobs
+        .iter_mut()
+        .filter(|job| loaded_jobs.contains_key(&job.id))
+        .map(|job| {
+            job.pickups = None;
+            (job.id.clone(), job.deliveries.as_ref().map_or(0, |deliveries| deliveries.len()))
+        })
+        .collect::<HashMap<_, _>>();
+
+    if let Some(relations) = problem.plan.relations.as_mut() {
+        relations.iter_mut().for_each(|relation| {
+            relation.jobs.retain(|job_id| !served_jobs.contains(job_id));
+
+            // NOTE pickups precede deliveries, so remove first occurrences of loaded jobs
+            deliveries.iter().for_each(|(job_id, &total)| {
+                let occurrences = relation.jobs.iter().filter(|id| *id == job_id).count();
+                (0..occurrences.saturating_sub(total)).for_each(|_| {
+                    let idx = relation.jobs.iter().position(|id| id == job_id).unwrap();
+                    relation.jobs.remove(idx);
+                });
+            });
+        });
+        relations.retain(|relation| !relation.jobs.is_empty());
+    }
+
+    problem
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/format/solution/csv_serializer_test.rs"]
+mod csv_serializer_test;
+
+use super::{create_solution, Solution};
+use std::io::{BufWriter, Error, Write};
+use vrp_core::models::{Problem as DomainProblem, Solution as DomainSolution};
+
+const TOURS_HEADER: &[&str] =
+    &["vehicle_id", "shift_index", "stop_index", "job_id", "activity_type", "arrival", "departure", "load", "distance"];
+
+const UNASSIGNED_HEADER: &[&str] = &["job_id", "code", "description"];
+
+/// Writes solution tours and, optionally, unassigned jobs in csv format.
+pub fn write_csv_solution<W: Write>(
+    problem: &DomainProblem,
+    solution: &DomainSolution,
+    tours_writer: BufWriter<W>,
+    unassigned_writer: Option<BufWriter<W>>,
+) -> Result<(), String> {
+    let solution = create_solution(problem, solution, None);
+
+    serialize_tours_as_csv(tours_writer, &solution).map_err(|err| err.to_string())?;
+
+    if let Some(unassigned_writer) = unassigned_writer {
+        serialize_unassigned_as_csv(unassigned_writer, &solution).map_err(|err| err.to_string())?;
+    }
+
+    Ok(())
+}
+
+/// Serializes solution tours into csv format: each activity is written as a separate row.
+/// Multi dimensional load is written as a list of values separated by semicolon.
+pub fn serialize_tours_as_csv<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    let mut writer = writer;
+
+    write_row(&mut writer, TOURS_HEADER.iter().map(|field| field.to_string()))?;
+
+    solution.tours.iter().try_for_each(|tour| {
+        tour.stops.iter().enumerate().try_for_each(|(stop_idx, stop)| {
+            stop.activities.iter().try_for_each(|activity| {
+                let (arrival, departure) = activity
+                    .time
+                    .as_ref()
+                    .map_or((&stop.time.arrival, &stop.time.departure), |time| (&time.start, &time.end));
+
+                write_row(
+                    &mut writer,
+                    vec![
+                        tour.vehicle_id.clone(),
+                        tour.shift_index.to_string(),
+                        stop_idx.to_string(),
+                        activity.job_id.clone(),
+                        activity.activity_type.clone(),
+                        arrival.clone(),
+                        departure.clone(),
+                        stop.load.iter().map(|value| value.to_string()).collect::<Vec<_>>().join(";"),
+                        stop.distance.to_string(),
+                    ]
+                    .into_iter(),
+                )
+            })
+        })
+    })?;
+
+    writer.flush()
+}
+
+/// Serializes unassigned jobs into csv format: each reason is written as a separate row.
+pub fn serialize_unassigned_as_csv<W: Write>(writer: BufWriter<W>, solution: &Solution) -> Result<(), Error> {
+    let mut writer = writer;
+
+    write_row(&mut writer, UNASSIGNED_HEADER.iter().map(|field| field.to_string()))?;
+
+    solution.unassigned.iter().flat_map(|unassigned| unassigned.iter()).try_for_each(|job| {
+        job.reasons.iter().try_for_each(|reason| {
+            write_row(
+                &mut writer,
+                vec![job.job_id.clone(), reason.code.clone(), reason.description.clone()].into_iter(),
+            )
+        })
+    })?;
+
+    writer.flush()
+}
+
+fn write_row<W: Write>(writer: &mut BufWriter<W>, fields: impl Iterator<Item = String>) -> Result<(), Error> {
+    let row = fields.map(|field| escape_field(field.as_str())).collect::<Vec<_>>().join(",");
+
+    writer.write_all(row.as_bytes())?;
+    writer.write_all(b"\n")
+}
+
+fn escape_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -5,6 +5,9 @@ pub use self::model::*;
 
 pub(crate) mod activity_matcher;
 
+mod csv_serializer;
+pub use self::csv_serializer::{serialize_tours_as_csv, serialize_unassigned_as_csv, write_csv_solution};
+
 mod geo_serializer;
 pub use self::geo_serializer::serialize_solution_as_geojson;
 
@@ -15,11 +18,14 @@ mod extensions;
 
 mod writer;
 pub use self::writer::create_solution;
+pub use self::writer::create_solution_with_objectives;
+pub use self::writer::create_solution_with_unassigned_details;
 pub use self::writer::PragmaticSolution;
 
 use super::*;
 
-fn map_code_reason(code: i32) -> (&'static str, &'static str) {
+/// Maps constraint violation code to its reason code and description used in unassigned jobs.
+pub fn map_code_reason(code: i32) -> (&'static str, &'static str) {
     match code {
         SKILL_CONSTRAINT_CODE => ("SKILL_CONSTRAINT", "cannot serve required skill"),
         TIME_CONSTRAINT_CODE => ("TIME_WINDOW_CONSTRAINT", "cannot be visited within time window"),
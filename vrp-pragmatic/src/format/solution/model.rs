@@ -105,6 +105,31 @@ pub struct UnassignedJobReason {
     pub code: String,
     /// Description.
     pub description: String,
+    /// Vehicle shifts which cannot serve the job due to this reason.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub details: Option<Vec<UnassignedJobDetail>>,
+}
+
+impl UnassignedJobReason {
+    /// Creates a new instance of `UnassignedJobReason` without details.
+    pub fn new(code: String, description: String) -> Self {
+        Self { code, description, details: None }
+    }
+
+    /// Creates a new instance of `UnassignedJobReason` with vehicle shifts which cannot serve the job.
+    pub fn new_with_details(code: String, description: String, details: Vec<UnassignedJobDetail>) -> Self {
+        Self { code, description, details: Some(details) }
+    }
+}
+
+/// Unassigned job reason detail.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct UnassignedJobDetail {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index.
+    pub shift_index: usize,
 }
 
 /// Unassigned job.
@@ -195,6 +220,21 @@ pub struct Extras {
     /// A telemetry metrics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub metrics: Option<Metrics>,
+
+    /// Objective values of the solution which is a part of pareto front.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub objectives: Option<Vec<ObjectiveValue>>,
+}
+
+/// Specifies objective value of the solution.
+#[derive(Clone, Deserialize, Serialize, PartialEq, Debug)]
+pub struct ObjectiveValue {
+    /// An objective type as specified in problem definition, e.g. `minimize-cost`.
+    #[serde(rename = "type")]
+    pub objective_type: String,
+
+    /// An objective fitness value.
+    pub value: f64,
 }
 
 /// A VRP solution.
@@ -8,12 +8,15 @@ use crate::format::solution::*;
 use crate::format::*;
 use crate::format_time;
 use std::io::{BufWriter, Write};
+use std::sync::Arc;
 use vrp_core::construction::constraints::route_intervals;
+use vrp_core::construction::heuristics::{evaluate_job_constraint_per_actor, InsertionContext};
 use vrp_core::models::common::*;
-use vrp_core::models::problem::Multi;
+use vrp_core::models::problem::{Job, Multi};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::models::{Problem, Solution};
 use vrp_core::solver::Metrics;
+use vrp_core::utils::DefaultRandom;
 
 type ApiActivity = crate::format::solution::model::Activity;
 type ApiSolution = crate::format::solution::model::Solution;
@@ -43,7 +46,7 @@ impl<W: Write> PragmaticSolution<W> for Solution {
     }
 
     fn write_geo_json(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
-        let solution = create_solution(problem, &self, None);
+        let solution = create_solution(problem, self, None);
         serialize_solution_as_geojson(writer, &solution).map_err(|err| err.to_string())?;
         Ok(())
     }
@@ -79,16 +82,51 @@ impl Leg {
 
 /// Creates solution.
 pub fn create_solution(problem: &Problem, solution: &Solution, metrics: Option<&Metrics>) -> ApiSolution {
+    create_solution_with_context(problem, solution, metrics, None, None)
+}
+
+/// Creates solution which is a part of pareto front: objective fitness values are written into
+/// its extras together with objective types as specified in the problem definition.
+pub fn create_solution_with_objectives(problem: &Problem, solution: &Solution, fitness: &[f64]) -> ApiSolution {
+    create_solution_with_context(problem, solution, None, None, Some(fitness))
+}
+
+/// Creates solution with detailed reasons of unassigned jobs: insertion of each unassigned job is
+/// re-evaluated against every vehicle shift and violated constraints are reported per shift.
+pub fn create_solution_with_unassigned_details(
+    problem: Arc<Problem>,
+    solution: &Solution,
+    metrics: Option<&Metrics>,
+) -> ApiSolution {
+    let solution_copy = Solution {
+        registry: solution.registry.deep_copy(),
+        routes: solution.routes.iter().map(|route| route.deep_copy()).collect(),
+        unassigned: solution.unassigned.clone(),
+        extras: solution.extras.clone(),
+    };
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem.clone(), (solution_copy, None), Arc::new(DefaultRandom::default()));
+
+    create_solution_with_context(problem.as_ref(), solution, metrics, Some(&insertion_ctx), None)
+}
+
+fn create_solution_with_context(
+    problem: &Problem,
+    solution: &Solution,
+    metrics: Option<&Metrics>,
+    insertion_ctx: Option<&InsertionContext>,
+    fitness: Option<&[f64]>,
+) -> ApiSolution {
     let coord_index = get_coord_index(problem);
 
     let tours = solution.routes.iter().map(|r| create_tour(problem, r, coord_index)).collect::<Vec<Tour>>();
 
     let statistic = tours.iter().fold(Statistic::default(), |acc, tour| acc + tour.statistic.clone());
 
-    let unassigned = create_unassigned(solution);
+    let unassigned = create_unassigned(solution, insertion_ctx);
     let violations = create_violations(solution);
 
-    let extras = create_extras(solution, metrics);
+    let extras = create_extras(problem, metrics, fitness);
 
     ApiSolution { statistic, tours, unassigned, violations, extras }
 }
@@ -108,10 +146,11 @@ fn create_tour(problem: &Problem, route: &Route, coord_index: &CoordIndex) -> To
     };
 
     let intervals = route_intervals(route, Box::new(|a| get_activity_type(a).map_or(false, |t| t == "reload")));
+    let initial_load = get_initial_load(&vehicle.dimens, is_multi_dimen).unwrap_or_default();
 
     let mut leg = intervals.into_iter().fold(Leg::empty(), |leg, (start_idx, end_idx)| {
         let (start_delivery, end_pickup) = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            (leg.load.unwrap_or_else(MultiDimLoad::default), MultiDimLoad::default()),
+            (leg.load.unwrap_or(initial_load), MultiDimLoad::default()),
             |acc, activity| {
                 let (delivery, pickup) = activity
                     .job
@@ -289,17 +328,29 @@ fn calculate_load(current: MultiDimLoad, act: &Activity, is_multi_dimen: bool) -
     current - demand.delivery.0 - demand.delivery.1 + demand.pickup.0 + demand.pickup.1
 }
 
-fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
+fn create_unassigned(solution: &Solution, insertion_ctx: Option<&InsertionContext>) -> Option<Vec<UnassignedJob>> {
+    let create_reason = |code: i32, details: Option<Vec<UnassignedJobDetail>>| {
+        let (code, reason) = map_code_reason(code);
+        match details {
+            Some(details) => UnassignedJobReason::new_with_details(code.to_string(), reason.to_string(), details),
+            None => UnassignedJobReason::new(code.to_string(), reason.to_string()),
+        }
+    };
+
     let unassigned = solution
         .unassigned
         .iter()
         .filter(|(job, _)| job.dimens().get_value::<String>("vehicle_id").is_none())
         .map(|(job, code)| {
-            let (code, reason) = map_code_reason(*code);
-            UnassignedJob {
-                job_id: job.dimens().get_id().expect("job id expected").clone(),
-                reasons: vec![UnassignedJobReason { code: code.to_string(), description: reason.to_string() }],
-            }
+            let details = insertion_ctx.map(|ctx| get_unassigned_details(job, *code, ctx)).unwrap_or_default();
+
+            let reasons = if details.is_empty() {
+                vec![create_reason(*code, None)]
+            } else {
+                details.into_iter().map(|(code, details)| create_reason(code, Some(details))).collect()
+            };
+
+            UnassignedJob { job_id: job.dimens().get_id().expect("job id expected").clone(), reasons }
         })
         .collect::<Vec<_>>();
 
@@ -310,6 +361,34 @@ fn create_unassigned(solution: &Solution) -> Option<Vec<UnassignedJob>> {
     }
 }
 
+/// Returns violated constraint codes with vehicle shifts which cannot serve the job. The code
+/// reported by the solver goes first.
+fn get_unassigned_details(job: &Job, code: i32, ctx: &InsertionContext) -> Vec<(i32, Vec<UnassignedJobDetail>)> {
+    let mut details = evaluate_job_constraint_per_actor(job, ctx).into_iter().fold(
+        Vec::<(i32, Vec<UnassignedJobDetail>)>::new(),
+        |mut acc, (actor, actor_code)| {
+            let detail = UnassignedJobDetail {
+                vehicle_id: actor.vehicle.dimens.get_id().expect("vehicle id expected").clone(),
+                shift_index: *actor.vehicle.dimens.get_value::<usize>("shift_index").expect("shift index expected"),
+            };
+
+            match acc.iter_mut().find(|(code, _)| *code == actor_code) {
+                Some((_, details)) => details.push(detail),
+                None => acc.push((actor_code, vec![detail])),
+            }
+
+            acc
+        },
+    );
+
+    details.iter_mut().for_each(|(_, details)| {
+        details.sort_by(|a, b| a.vehicle_id.cmp(&b.vehicle_id).then(a.shift_index.cmp(&b.shift_index)))
+    });
+    details.sort_by_key(|(actor_code, _)| (*actor_code != code, map_code_reason(*actor_code).0));
+
+    details
+}
+
 fn create_violations(solution: &Solution) -> Option<Vec<Violation>> {
     // NOTE at the moment only break violation is mapped
     let violations = solution
@@ -352,6 +431,14 @@ fn get_capacity(dimens: &Dimensions, is_multi_dimen: bool) -> Option<Demand<Mult
     }
 }
 
+fn get_initial_load(dimens: &Dimensions, is_multi_dimen: bool) -> Option<MultiDimLoad> {
+    if is_multi_dimen {
+        dimens.get_initial_load().cloned()
+    } else {
+        dimens.get_initial_load().map(|load: &SingleDimLoad| MultiDimLoad::new(vec![load.value]))
+    }
+}
+
 fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     let capacity_type = extras
         .get("capacity_type")
@@ -364,41 +451,54 @@ fn has_multi_dimensional_capacity(extras: &DomainExtras) -> bool {
     }
 }
 
-fn create_extras(_solution: &Solution, metrics: Option<&Metrics>) -> Option<Extras> {
-    if let Some(metrics) = metrics {
-        Some(Extras {
-            metrics: Some(ApiMetrics {
-                duration: metrics.duration,
-                generations: metrics.generations,
-                speed: metrics.speed,
-                evolution: metrics
-                    .evolution
-                    .iter()
-                    .map(|g| ApiGeneration {
-                        number: g.number,
-                        timestamp: g.timestamp,
-                        i_all_ratio: g.i_all_ratio,
-                        i_1000_ratio: g.i_1000_ratio,
-                        is_improvement: g.is_improvement,
-                        population: AppPopulation {
-                            individuals: g
-                                .population
-                                .individuals
-                                .iter()
-                                .map(|i| ApiIndividual {
-                                    tours: i.tours,
-                                    unassigned: i.unassigned,
-                                    cost: i.cost,
-                                    improvement: i.improvement,
-                                    fitness: i.fitness.clone(),
-                                })
-                                .collect(),
-                        },
-                    })
-                    .collect(),
-            }),
-        })
-    } else {
+fn create_extras(problem: &Problem, metrics: Option<&Metrics>, fitness: Option<&[f64]>) -> Option<Extras> {
+    let metrics = metrics.map(|metrics| ApiMetrics {
+        duration: metrics.duration,
+        generations: metrics.generations,
+        speed: metrics.speed,
+        evolution: metrics
+            .evolution
+            .iter()
+            .map(|g| ApiGeneration {
+                number: g.number,
+                timestamp: g.timestamp,
+                i_all_ratio: g.i_all_ratio,
+                i_1000_ratio: g.i_1000_ratio,
+                is_improvement: g.is_improvement,
+                population: AppPopulation {
+                    individuals: g
+                        .population
+                        .individuals
+                        .iter()
+                        .map(|i| ApiIndividual {
+                            tours: i.tours,
+                            unassigned: i.unassigned,
+                            cost: i.cost,
+                            improvement: i.improvement,
+                            fitness: i.fitness.clone(),
+                        })
+                        .collect(),
+                },
+            })
+            .collect(),
+    });
+
+    let objectives = fitness.map(|fitness| {
+        let names = problem.extras.get("objective_names").and_then(|s| s.downcast_ref::<Vec<String>>());
+
+        fitness
+            .iter()
+            .enumerate()
+            .map(|(idx, value)| ObjectiveValue {
+                objective_type: names.and_then(|names| names.get(idx)).cloned().unwrap_or_else(|| idx.to_string()),
+                value: *value,
+            })
+            .collect()
+    });
+
+    if metrics.is_none() && objectives.is_none() {
         None
+    } else {
+        Some(Extras { metrics, objectives })
     }
 }
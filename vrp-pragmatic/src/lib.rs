@@ -47,6 +47,7 @@ fn parse_time(time: &str) -> f64 {
     parse_time_safe(time).unwrap()
 }
 
-fn parse_time_safe(time: &str) -> Result<f64, ParseError> {
+/// Parses time specified in RFC3339 format into unix timestamp.
+pub fn parse_time_safe(time: &str) -> Result<f64, ParseError> {
     DateTime::parse_from_rfc3339(time).map(|time| time.timestamp() as f64)
 }
@@ -1,7 +1,7 @@
 //! This module provides functionality to validate problem definition for logical correctness.
 
 use crate::format::problem::*;
-use crate::format::{CoordIndex, FormatError};
+use crate::format::{is_reserved_job_id, CoordIndex, FormatError};
 
 /// A validation context which keeps essential information.
 pub struct ValidationContext<'a> {
@@ -95,7 +95,3 @@ fn combine_error_results(results: &[Result<(), FormatError>]) -> Result<(), Vec<
         Err(errors)
     }
 }
-
-fn is_reserved_job_id(job_id: &str) -> bool {
-    job_id == "departure" || job_id == "arrival" || job_id == "break" || job_id == "reload" || job_id == "dispatch"
-}
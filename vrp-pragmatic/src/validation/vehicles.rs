@@ -3,8 +3,8 @@
 mod vehicles_test;
 
 use super::*;
-use crate::parse_time;
 use crate::validation::common::get_time_windows;
+use crate::{parse_time, parse_time_safe};
 use hashbrown::HashSet;
 use std::cmp::Ordering;
 use std::ops::Deref;
@@ -207,6 +207,61 @@ fn check_e1306_vehicle_dispatch_is_correct(ctx: &ValidationContext) -> Result<()
     }
 }
 
+/// Checks that vehicle states are correct.
+fn check_e1307_vehicle_state_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let states = ctx.problem.fleet.states.iter().flatten().collect::<Vec<_>>();
+    let all_jobs = states.iter().flat_map(|state| state.served.iter().chain(state.loaded.iter()).flatten());
+
+    let has_duplicates = get_duplicates(all_jobs).is_some()
+        || states.iter().map(|state| (&state.vehicle_id, state.shift_index.unwrap_or(0))).collect::<HashSet<_>>().len()
+            != states.len();
+
+    let vehicle_ids = states
+        .iter()
+        .filter(|state| {
+            let shift = ctx
+                .vehicles()
+                .find(|vehicle| vehicle.vehicle_ids.contains(&state.vehicle_id))
+                .and_then(|vehicle| vehicle.shifts.get(state.shift_index.unwrap_or(0)));
+
+            let is_valid_shift = shift.map_or(false, |shift| {
+                shift.dispatch.is_none()
+                    && parse_time_safe(&state.time).ok().map_or(false, |time| {
+                        get_shift_time_window(shift).map_or(false, |tw| tw.intersects(&TimeWindow::new(time, time)))
+                    })
+            });
+
+            let is_valid_served = state.served.iter().flatten().all(|job_id| ctx.job_index.contains_key(job_id));
+
+            let is_valid_loaded = state.loaded.iter().flatten().all(|job_id| {
+                ctx.job_index.get(job_id).map_or(false, |job| {
+                    job.pickups.as_ref().map_or(false, |pickups| !pickups.is_empty())
+                        && job.deliveries.as_ref().map_or(false, |deliveries| !deliveries.is_empty())
+                        && job.replacements.is_none()
+                        && job.services.is_none()
+                })
+            });
+
+            !(is_valid_shift && is_valid_served && is_valid_loaded)
+        })
+        .map(|state| state.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() && !has_duplicates {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1307".to_string(),
+            "invalid vehicle state".to_string(),
+            format!(
+                "ensure that vehicle states refer to known vehicles, shifts and jobs without duplicates, \
+                 vehicle ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
 fn get_invalid_type_ids(
     ctx: &ValidationContext,
     check_shift: Box<dyn Fn(&VehicleType, &VehicleShift, Option<TimeWindow>) -> bool>,
@@ -254,5 +309,6 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), Vec<FormatError>
         check_e1304_vehicle_reload_time_is_correct(ctx),
         check_e1305_vehicle_limit_area_is_correct(ctx),
         check_e1306_vehicle_dispatch_is_correct(ctx),
+        check_e1307_vehicle_state_is_correct(ctx),
     ])
 }
@@ -24,6 +24,7 @@ fn can_assign_break_between_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
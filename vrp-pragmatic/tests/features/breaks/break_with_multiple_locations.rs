@@ -29,6 +29,7 @@ fn can_assign_break_using_second_location() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
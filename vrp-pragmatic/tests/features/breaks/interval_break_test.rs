@@ -23,6 +23,7 @@ fn can_assign_interval_break_between_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -154,6 +155,7 @@ fn can_assign_interval_break_with_reload() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -290,6 +292,7 @@ fn can_consider_departure_rescheduling() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
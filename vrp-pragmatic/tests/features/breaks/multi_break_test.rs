@@ -35,6 +35,7 @@ fn can_use_two_breaks() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
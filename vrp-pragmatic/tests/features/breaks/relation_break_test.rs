@@ -42,6 +42,7 @@ fn get_solution(
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
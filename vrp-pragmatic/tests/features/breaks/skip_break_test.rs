@@ -37,6 +37,7 @@ fn can_skip_break_when_vehicle_not_used() {
                 create_default_vehicle("vehicle_without_break"),
             ],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -123,6 +124,7 @@ fn can_skip_break_when_jobs_completed() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -213,6 +215,7 @@ fn can_skip_second_break_when_jobs_completed() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
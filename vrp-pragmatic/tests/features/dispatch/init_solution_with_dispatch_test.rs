@@ -34,6 +34,7 @@ fn can_use_init_solution_with_dispatch() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -31,6 +31,7 @@ fn can_use_dispatch_in_relation() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -43,6 +43,7 @@ fn can_use_multiple_times_from_vehicle_and_job() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
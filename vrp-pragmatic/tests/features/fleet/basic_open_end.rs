@@ -12,6 +12,7 @@ fn can_use_vehicle_with_open_end() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
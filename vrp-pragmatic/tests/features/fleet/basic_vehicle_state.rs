@@ -0,0 +1,93 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+#[test]
+fn can_plan_rest_of_tour_from_vehicle_state() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_pickup_delivery_job("job2", vec![2., 0.], vec![8., 0.]),
+                create_delivery_job("job3", vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            states: Some(vec![VehicleState {
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+                location: vec![5., 0.].to_loc(),
+                time: format_time(10.),
+                served: Some(vec!["job1".to_string()]),
+                loaded: Some(vec!["job2".to_string()]),
+            }]),
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution,
+        Solution {
+            statistic: Statistic {
+                cost: 34.,
+                distance: 11,
+                duration: 13,
+                times: Timing { driving: 11, serving: 2, waiting: 0, break_time: 0 },
+            },
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                shift_index: 0,
+                stops: vec![
+                    create_stop_with_activity(
+                        "departure",
+                        "departure",
+                        (5., 0.),
+                        2,
+                        ("1970-01-01T00:00:10Z", "1970-01-01T00:00:10Z"),
+                        0
+                    ),
+                    create_stop_with_activity_with_tag(
+                        "job2",
+                        "delivery",
+                        (8., 0.),
+                        1,
+                        ("1970-01-01T00:00:13Z", "1970-01-01T00:00:14Z"),
+                        3,
+                        "d1"
+                    ),
+                    create_stop_with_activity(
+                        "job3",
+                        "delivery",
+                        (3., 0.),
+                        0,
+                        ("1970-01-01T00:00:19Z", "1970-01-01T00:00:20Z"),
+                        8
+                    ),
+                    create_stop_with_activity(
+                        "arrival",
+                        "arrival",
+                        (0., 0.),
+                        0,
+                        ("1970-01-01T00:00:23Z", "1970-01-01T00:00:23Z"),
+                        11
+                    )
+                ],
+                statistic: Statistic {
+                    cost: 34.,
+                    distance: 11,
+                    duration: 13,
+                    times: Timing { driving: 11, serving: 2, waiting: 0, break_time: 0 },
+                },
+            }],
+            ..create_empty_solution()
+        }
+    );
+}
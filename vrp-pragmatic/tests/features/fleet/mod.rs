@@ -1,4 +1,5 @@
 mod basic_multi_shift;
 mod basic_open_end;
+mod basic_vehicle_state;
 mod multi_dimens;
 mod unreachable_jobs;
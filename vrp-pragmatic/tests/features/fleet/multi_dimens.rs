@@ -19,6 +19,7 @@ fn can_use_two_dimensions() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -88,6 +89,7 @@ fn can_unassign_due_to_dimension_mismatch() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -107,10 +109,10 @@ fn can_unassign_due_to_dimension_mismatch() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "CAPACITY_CONSTRAINT".to_string(),
-                    description: "does not fit into any vehicle due to capacity".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "CAPACITY_CONSTRAINT".to_string(),
+                    "does not fit into any vehicle due to capacity".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
@@ -6,7 +6,11 @@ use crate::helpers::*;
 fn can_use_vehicle_with_open_end() {
     let problem = Problem {
         plan: Plan { jobs: vec![create_delivery_job("job1", vec![1., 0.])], relations: Option::None },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = Matrix {
@@ -31,10 +35,10 @@ fn can_use_vehicle_with_open_end() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "REACHABLE_CONSTRAINT".to_string(),
-                    description: "location unreachable".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "REACHABLE_CONSTRAINT".to_string(),
+                    "location unreachable".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
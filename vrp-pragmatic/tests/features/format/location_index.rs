@@ -23,6 +23,7 @@ fn create_test_problem() -> Problem {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     }
@@ -26,6 +26,7 @@ fn can_limit_by_area() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -45,10 +46,10 @@ fn can_limit_by_area() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "AREA_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to area constraint".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "AREA_CONSTRAINT".to_string(),
+                    "cannot be assigned due to area constraint".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
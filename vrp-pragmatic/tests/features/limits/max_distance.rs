@@ -17,6 +17,7 @@ fn can_limit_by_max_distance() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -42,10 +43,10 @@ fn can_limit_by_max_distance() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "MAX_DISTANCE_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to max distance constraint of vehicle".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "MAX_DISTANCE_CONSTRAINT".to_string(),
+                    "cannot be assigned due to max distance constraint of vehicle".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
@@ -21,6 +21,7 @@ fn can_limit_one_job_by_shift_time() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(99.)],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -46,10 +47,10 @@ fn can_limit_one_job_by_shift_time() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "SHIFT_TIME_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "SHIFT_TIME_CONSTRAINT".to_string(),
+                    "cannot be assigned due to shift time constraint of vehicle".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
@@ -72,6 +73,7 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(40.)],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -144,17 +146,17 @@ fn can_skip_job_from_multiple_because_of_shift_time() {
             unassigned: Some(vec![
                 UnassignedJob {
                     job_id: "job4".to_string(),
-                    reasons: vec![UnassignedJobReason {
-                        code: "SHIFT_TIME_CONSTRAINT".to_string(),
-                        description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                    }]
+                    reasons: vec![UnassignedJobReason::new(
+                        "SHIFT_TIME_CONSTRAINT".to_string(),
+                        "cannot be assigned due to shift time constraint of vehicle".to_string()
+                    )]
                 },
                 UnassignedJob {
                     job_id: "job5".to_string(),
-                    reasons: vec![UnassignedJobReason {
-                        code: "SHIFT_TIME_CONSTRAINT".to_string(),
-                        description: "cannot be assigned due to shift time constraint of vehicle".to_string()
-                    }]
+                    reasons: vec![UnassignedJobReason::new(
+                        "SHIFT_TIME_CONSTRAINT".to_string(),
+                        "cannot be assigned due to shift time constraint of vehicle".to_string()
+                    )]
                 }
             ]),
             ..create_empty_solution()
@@ -171,7 +173,7 @@ fn can_serve_job_when_it_starts_late() {
         },
         fleet: Fleet {
             vehicles: vec![create_vehicle_type_with_shift_time_limit(50.)],
-            profiles: create_default_profiles(),
+            profiles: create_default_profiles(), states: None,
         },
         ..create_empty_problem()
     };
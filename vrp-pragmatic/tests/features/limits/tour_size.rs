@@ -25,6 +25,7 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -80,10 +81,10 @@ fn can_skip_job_from_multiple_because_of_tour_size() {
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job3".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "TOUR_SIZE_CONSTRAINT".to_string(),
-                    description: "cannot be assigned due to tour size constraint of vehicle".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "TOUR_SIZE_CONSTRAINT".to_string(),
+                    "cannot be assigned due to tour size constraint of vehicle".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
@@ -19,6 +19,7 @@ fn can_assign_multi_and_single_job_as_pickups_specified() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -118,6 +119,7 @@ fn can_assign_multi_job_in_pickup_effective_way() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
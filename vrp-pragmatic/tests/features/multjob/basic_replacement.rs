@@ -27,6 +27,7 @@ fn can_assign_replacement_job() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
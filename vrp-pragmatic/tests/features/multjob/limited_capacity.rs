@@ -23,6 +23,7 @@ fn can_handle_limited_capacity() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
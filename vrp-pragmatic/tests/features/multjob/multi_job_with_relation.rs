@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_use_multi_job_with_relation, relation_type, {
+    can_use_multi_job_with_relation_impl(relation_type);
+}}
+
+can_use_multi_job_with_relation! {
+    case_01_strict: RelationType::Strict,
+    case_02_sequence: RelationType::Sequence,
+    case_03_any: RelationType::Any,
+}
+
+fn can_use_multi_job_with_relation_impl(relation_type: RelationType) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("simple", vec![1., 0.]),
+                create_multi_job(
+                    "multi",
+                    vec![((2., 0.), 1., vec![1]), ((8., 0.), 1., vec![1])],
+                    vec![((6., 0.), 1., vec![2])],
+                ),
+            ],
+            relations: Some(vec![Relation {
+                type_field: relation_type,
+                jobs: vec!["multi".to_string(), "multi".to_string(), "multi".to_string()],
+                vehicle_id: "my_vehicle_1".to_string(),
+                shift_index: None,
+            }]),
+        },
+        fleet: create_fleet_with_vehicles(vec![create_vehicle_with_capacity("my_vehicle", vec![2])]),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        solution.tours[0]
+            .stops
+            .iter()
+            .flat_map(|stop| stop.activities.iter())
+            .filter(|activity| activity.job_id == "multi")
+            .filter_map(|activity| activity.job_tag.clone())
+            .collect::<std::collections::HashSet<_>>()
+            .len(),
+        3
+    );
+}
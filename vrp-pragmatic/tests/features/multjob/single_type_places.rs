@@ -16,6 +16,7 @@ fn can_use_only_deliveries_as_static_demand() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -96,6 +97,7 @@ fn can_use_only_pickups_as_static_demand() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -16,6 +16,7 @@ fn can_unassign_multi_job_due_to_capacity() {
         fleet: Fleet {
             vehicles: vec![create_vehicle_with_capacity("my_vehicle", vec![2])],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -35,10 +36,10 @@ fn can_unassign_multi_job_due_to_capacity() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "multi".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "CAPACITY_CONSTRAINT".to_string(),
-                    description: "does not fit into any vehicle due to capacity".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "CAPACITY_CONSTRAINT".to_string(),
+                    "does not fit into any vehicle due to capacity".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
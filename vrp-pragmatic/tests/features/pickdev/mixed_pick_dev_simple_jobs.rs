@@ -13,7 +13,11 @@ fn can_use_one_pickup_delivery_and_two_deliveries_with_one_vehicle() {
             ],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -23,6 +23,7 @@ fn can_use_two_pickup_delivery_jobs_and_relation_with_one_vehicle() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -19,6 +19,7 @@ fn can_follow_priorities() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
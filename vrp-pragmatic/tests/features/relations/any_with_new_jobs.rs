@@ -25,6 +25,7 @@ fn can_use_any_relation_with_new_job_for_one_vehicle_with_open_end() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -30,7 +30,11 @@ fn can_use_strict_and_any_relation_for_one_vehicle() {
                 },
             ]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
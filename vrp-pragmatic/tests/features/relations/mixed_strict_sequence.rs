@@ -30,7 +30,11 @@ fn can_use_strict_and_sequence_relation_for_one_vehicle() {
                 },
             ]),
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -185,6 +189,7 @@ fn can_use_strict_and_sequence_relation_for_two_vehicles() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
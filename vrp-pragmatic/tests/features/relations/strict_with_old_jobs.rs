@@ -38,6 +38,7 @@ fn can_use_two_strict_relations_with_two_vehicles_without_new_jobs() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
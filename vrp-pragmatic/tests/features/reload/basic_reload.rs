@@ -20,10 +20,7 @@ can_use_vehicle_with_two_tours_and_two_jobs! {
              Some(vec![
                UnassignedJob {
                     job_id: "job3".to_string(),
-                    reasons: vec![UnassignedJobReason {
-                        code: "CAPACITY_CONSTRAINT".to_string(),
-                        description: "does not fit into any vehicle due to capacity".to_string()
-                    }]
+                    reasons: vec![UnassignedJobReason::new("CAPACITY_CONSTRAINT".to_string(), "does not fit into any vehicle due to capacity".to_string())]
                 }
              ])),
 }
@@ -53,6 +50,7 @@ fn can_use_vehicle_with_two_tours_and_two_jobs_impl(jobs: Vec<Job>, unassigned:
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -45,6 +45,7 @@ fn can_use_reloads_with_different_locations() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
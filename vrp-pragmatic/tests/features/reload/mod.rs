@@ -5,3 +5,4 @@ mod multi_dim_reload;
 mod multi_job_reload;
 mod multi_vehicle_reload;
 mod picks_devs_reload;
+mod reload_time_windows;
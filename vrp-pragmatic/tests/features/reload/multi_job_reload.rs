@@ -42,6 +42,7 @@ fn can_serve_multi_job_and_delivery_with_reload() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -190,6 +191,7 @@ fn can_properly_handle_load_without_capacity_violation() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -33,6 +33,7 @@ fn can_use_one_vehicle_with_reload_instead_of_two() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
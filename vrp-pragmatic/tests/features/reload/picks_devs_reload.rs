@@ -38,6 +38,7 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -125,10 +126,10 @@ fn can_use_vehicle_with_pickups_and_deliveries() {
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "d3".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "CAPACITY_CONSTRAINT".to_string(),
-                    description: "does not fit into any vehicle due to capacity".to_string()
-                }],
+                reasons: vec![UnassignedJobReason::new(
+                    "CAPACITY_CONSTRAINT".to_string(),
+                    "does not fit into any vehicle due to capacity".to_string()
+                )],
             }]),
             ..create_empty_solution()
         }
@@ -0,0 +1,51 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+#[test]
+fn can_use_reload_within_its_time_window() {
+    let reload_tw = (10., 20.);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        times: Some(vec![vec![format_time(reload_tw.0), format_time(reload_tw.1)]]),
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.0,
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                capacity: vec![1],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            states: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let reload_stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities.iter().any(|activity| activity.activity_type == "reload"))
+        .expect("reload should be used");
+    let reload_time = reload_stop
+        .activities
+        .iter()
+        .find(|activity| activity.activity_type == "reload")
+        .and_then(|activity| activity.time.as_ref())
+        .map_or_else(|| reload_stop.time.departure.clone(), |time| time.end.clone());
+    let reload_start = parse_time(&reload_time) - 2.;
+    assert!(reload_start >= reload_tw.0 && reload_start <= reload_tw.1);
+}
@@ -25,6 +25,7 @@ fn can_wait_for_job_start() {
                 },
             ],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -1,5 +1,6 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -16,6 +17,7 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
         fleet: Fleet {
             vehicles: vec![create_default_vehicle("vehicle_without_skill")],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -35,12 +37,67 @@ fn can_have_unassigned_due_to_missing_vehicle_skill() {
             tours: vec![],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job1".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "SKILL_CONSTRAINT".to_string(),
-                    description: "cannot serve required skill".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "SKILL_CONSTRAINT".to_string(),
+                    "cannot serve required skill".to_string()
+                )]
             }]),
             ..create_empty_solution()
         }
     );
 }
+
+#[test]
+fn can_explain_unassigned_job_per_vehicle_shift() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                skills: Some(all_of_skills(vec!["unique_skill".to_string()])),
+                ..create_delivery_job_with_times("job1", vec![1., 0.], vec![(0, 10)], 1.)
+            }],
+            relations: Option::None,
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle_without_skill"),
+                VehicleType {
+                    skills: Some(vec!["unique_skill".to_string()]),
+                    shifts: vec![VehicleShift {
+                        start: ShiftStart {
+                            earliest: format_time(100.),
+                            latest: None,
+                            location: vec![0., 0.].to_loc(),
+                        },
+                        ..create_default_vehicle_shift()
+                    }],
+                    ..create_default_vehicle("late_vehicle_with_skill")
+                },
+            ],
+            profiles: create_default_profiles(),
+            states: None,
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_unassigned_details(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.expect("no unassigned jobs");
+    assert_eq!(unassigned.len(), 1);
+    let mut reasons = unassigned[0]
+        .reasons
+        .iter()
+        .map(|reason| {
+            let details = reason.details.as_ref().expect("no details");
+            (reason.code.as_str(), details.iter().map(|d| (d.vehicle_id.as_str(), d.shift_index)).collect::<Vec<_>>())
+        })
+        .collect::<Vec<_>>();
+    reasons.sort();
+    assert_eq!(
+        reasons,
+        vec![
+            ("SKILL_CONSTRAINT", vec![("vehicle_without_skill_1", 0)]),
+            ("TIME_WINDOW_CONSTRAINT", vec![("late_vehicle_with_skill_1", 0)]),
+        ]
+    );
+}
@@ -15,7 +15,11 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
             ],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -94,10 +98,10 @@ fn can_have_unassigned_jobs_because_of_strict_times() {
             }],
             unassigned: Some(vec![UnassignedJob {
                 job_id: "job5".to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "TIME_WINDOW_CONSTRAINT".to_string(),
-                    description: "cannot be visited within time window".to_string()
-                }]
+                reasons: vec![UnassignedJobReason::new(
+                    "TIME_WINDOW_CONSTRAINT".to_string(),
+                    "cannot be visited within time window".to_string()
+                )]
             }]),
             ..create_empty_solution()
         },
@@ -21,6 +21,7 @@ fn can_split_into_two_tours_because_of_strict_times() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -50,6 +50,7 @@ fn can_balance_activities_with_threshold_impl(threshold: Option<f64>, expected_l
                 },
             ],
             profiles: create_default_profiles(),
+            states: None,
         },
         objectives: Some(Objectives {
             primary: vec![BalanceActivities { options: Some(BalanceOptions { threshold, tolerance: None }) }],
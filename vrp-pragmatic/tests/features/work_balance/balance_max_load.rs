@@ -24,6 +24,7 @@ fn can_balance_max_load() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         objectives: Some(Objectives {
             primary: vec![BalanceMaxLoad { options: None }],
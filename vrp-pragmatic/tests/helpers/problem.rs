@@ -209,10 +209,18 @@ pub fn create_default_profiles() -> Vec<Profile> {
     vec![Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None }]
 }
 
+pub fn create_default_fleet() -> Fleet {
+    create_fleet_with_vehicles(vec![create_default_vehicle("my_vehicle")])
+}
+
+pub fn create_fleet_with_vehicles(vehicles: Vec<VehicleType>) -> Fleet {
+    Fleet { vehicles, profiles: create_default_profiles(), states: None }
+}
+
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: Plan { jobs: vec![], relations: None },
-        fleet: Fleet { vehicles: vec![], profiles: vec![] },
+        fleet: Fleet { vehicles: vec![], profiles: vec![], states: None },
         objectives: None,
     }
 }
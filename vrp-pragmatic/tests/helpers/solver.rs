@@ -1,6 +1,6 @@
-use crate::checker::CheckerContext;
+use crate::checker::{CheckerContext, CheckerError};
 use crate::format::problem::{Matrix, PragmaticProblem, Problem};
-use crate::format::solution::{create_solution, Solution};
+use crate::format::solution::{create_solution, create_solution_with_unassigned_details, Solution};
 use std::cmp::Ordering::Less;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::InsertionContext;
@@ -26,6 +26,19 @@ pub fn solve_with_cheapest_insertion(problem: Problem, matrices: Option<Vec<Matr
     })
 }
 
+/// Runs solver with default metaheuristic and returns solution with detailed unassigned job reasons.
+pub fn solve_with_metaheuristic_and_unassigned_details(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
+    let core_problem = get_core_problem(problem, matrices);
+    let (core_solution, _, _) = Builder::new(core_problem.clone())
+        .with_max_generations(Some(200))
+        .build()
+        .unwrap_or_else(|err| panic!("cannot build solver: {}", err))
+        .solve()
+        .unwrap_or_else(|err| panic!("cannot solve the problem: {}", err));
+
+    sort_all_data(create_solution_with_unassigned_details(core_problem, &core_solution, None))
+}
+
 /// Runs solver with default metaheuristic and default amount of generations.
 pub fn solve_with_metaheuristic(problem: Problem, matrices: Option<Vec<Matrix>>) -> Solution {
     solve_with_metaheuristic_and_iterations(problem, matrices, 200)
@@ -90,7 +103,12 @@ fn get_core_solution<F: Fn(Arc<CoreProblem>) -> CoreSolution>(
                 .check()
                 .err()
         {
-            panic!("check failed: '{}', problem: {:?}, solution: {:?}", err, format_problem, format_solution);
+            panic!(
+                "check failed: '{}', problem: {:?}, solution: {:?}",
+                CheckerError::format_many(&err, ", "),
+                format_problem,
+                format_solution
+            );
         }
     }
 
@@ -109,3 +127,8 @@ fn sort_all_data(solution: Solution) -> Solution {
 
     solution
 }
+
+/// Converts checker result into a result with error messages separated by new line.
+pub fn to_checker_messages(result: Result<(), Vec<CheckerError>>) -> Result<(), String> {
+    result.map_err(|errors| errors.into_iter().map(|err| err.message).collect::<Vec<_>>().join("\n"))
+}
@@ -22,6 +22,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -113,6 +114,12 @@ check_jobs! {
         vec![],
         Ok(())
     ),
+    case_11: (
+        vec![("job1", vec!["pickup", "pickup", "delivery"])],
+        vec![("my_vehicle_1", 0, vec![("job1", "pickup"), ("job1", "pickup"), ("job1", "delivery")])],
+        vec![],
+        Err("Cannot find pickup task with tag '<no tag>' for 'job1'".to_string())
+    ),
 }
 
 fn check_jobs_impl(
@@ -151,7 +158,11 @@ fn check_jobs_impl(
                 .collect(),
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -172,7 +183,63 @@ fn check_jobs_impl(
         ..create_empty_solution()
     };
 
-    let result = check_jobs_presence(&CheckerContext::new(create_example_problem(), problem, None, solution));
+    let result = to_checker_messages(check_jobs_presence(&CheckerContext::new(
+        create_example_problem(),
+        problem,
+        None,
+        solution,
+    )));
+
+    assert_eq!(result, expected_result);
+}
+
+parameterized_test! {can_check_job_task_served_once, (tags, expected_result), {
+    can_check_job_task_served_once_impl(tags, expected_result);
+}}
+
+can_check_job_task_served_once! {
+    case_01: (vec!["p1", "p2", "d1"], Ok(())),
+    case_02: (vec!["p2", "p1", "d1"], Ok(())),
+    case_03: (vec!["p1", "p1", "d1"], Err("Task pickup with tag 'p1' is served more than once for 'job1'".to_string())),
+    case_04: (vec!["p1", "p3", "d1"], Err("Cannot find pickup task with tag 'p3' for 'job1'".to_string())),
+}
+
+fn can_check_job_task_served_once_impl(tags: Vec<&str>, expected_result: Result<(), String>) {
+    let create_task = |tag: &str| JobTask { places: vec![], demand: Some(vec![1]), tag: Some(tag.to_string()) };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                pickups: Some(vec![create_task("p1"), create_task("p2")]),
+                deliveries: Some(vec![create_task("d1")]),
+                ..create_job("job1")
+            }],
+            relations: None,
+        },
+        fleet: create_fleet_with_vehicles(vec![create_default_vehicle_type()]),
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            stops: vec!["pickup", "pickup", "delivery"]
+                .into_iter()
+                .zip(tags.into_iter())
+                .map(|(activity_type, tag)| {
+                    create_stop_with_activity_with_tag("job1", activity_type, (0., 0.), 0, ("", ""), 0, tag)
+                })
+                .collect(),
+            ..create_empty_tour()
+        }],
+        ..create_empty_solution()
+    };
+
+    let result = to_checker_messages(check_jobs_presence(&CheckerContext::new(
+        create_example_problem(),
+        problem,
+        None,
+        solution,
+    )));
 
     assert_eq!(result, expected_result);
 }
@@ -184,7 +251,11 @@ fn can_detect_time_window_violation() {
             jobs: vec![create_delivery_job_with_times("job1", vec![1., 0.], vec![(1, 2)], 1.)],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let solution = Solution {
@@ -235,9 +306,9 @@ fn can_detect_time_window_violation() {
     };
     let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
 
-    let result = check_assignment(&CheckerContext::new(core_problem, problem, None, solution));
+    let result = to_checker_messages(check_assignment(&CheckerContext::new(core_problem, problem, None, solution)));
 
-    assert_eq!(result, Err("cannot match activities to jobs: job1:<no tag>".to_owned()));
+    assert_eq!(result, Err("cannot match activity to job: job1:<no tag>".to_owned()));
 }
 
 #[test]
@@ -257,6 +328,7 @@ fn can_detect_dispatch_violations() {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -296,7 +368,7 @@ fn can_detect_dispatch_violations() {
     };
     let core_problem = Arc::new(problem.clone().read_pragmatic().unwrap());
 
-    let result = check_dispatch(&CheckerContext::new(core_problem, problem, None, solution));
+    let result = to_checker_messages(check_dispatch(&CheckerContext::new(core_problem, problem, None, solution)));
 
     assert_eq!(result, Err("tour should have dispatch, but none is found: 'my_vehicle_1'".to_owned()));
 }
@@ -96,6 +96,7 @@ fn can_check_breaks_impl(
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -175,7 +176,8 @@ fn can_check_breaks_impl(
         ..create_empty_solution()
     };
 
-    let result = check_breaks(&CheckerContext::new(create_example_problem(), problem, None, solution));
+    let result =
+        to_checker_messages(check_breaks(&CheckerContext::new(create_example_problem(), problem, None, solution)));
 
     assert_eq!(result, expected_result);
 }
@@ -16,9 +16,14 @@ can_check_load! {
     case04: ( vec![1, 1, 3, 1, 1, 1, 0], Err("Load mismatch at stop 4 in tour 'my_vehicle_1'".to_owned())),
     case05: ( vec![1, 1, 3, 1, 2, 2, 0], Err("Load mismatch at stop 5 in tour 'my_vehicle_1'".to_owned())),
 
-    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
-    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
-    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0], Err("Load exceeds capacity in tour 'my_vehicle_1'".to_owned())),
+    case06_1: ( vec![10, 1, 3, 1, 2, 1, 0],
+                Err("Load exceeds capacity in tour 'my_vehicle_1'\nLoad mismatch at stops 0, 1 in tour 'my_vehicle_1'".to_owned())),
+    case06_2: ( vec![1, 1, 30, 1, 2, 1, 0],
+                Err("Load exceeds capacity in tour 'my_vehicle_1'\nLoad mismatch at stops 2, 3 in tour 'my_vehicle_1'".to_owned())),
+    case06_3: ( vec![1, 1, 3, 1, 20, 1, 0],
+                Err("Load exceeds capacity in tour 'my_vehicle_1'\nLoad mismatch at stop 4 in tour 'my_vehicle_1'".to_owned())),
+    case07: ( vec![1, 2, 3, 1, 1, 1, 0],
+              Err("Load mismatch at stop 1 in tour 'my_vehicle_1'\nLoad mismatch at stop 4 in tour 'my_vehicle_1'".to_owned())),
 }
 
 fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>) {
@@ -55,6 +60,7 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -186,7 +192,12 @@ fn can_check_load_impl(stop_loads: Vec<i32>, expected_result: Result<(), String>
         ..create_empty_solution()
     };
 
-    let result = check_vehicle_load(&CheckerContext::new(create_example_problem(), problem, None, solution));
+    let result = to_checker_messages(check_vehicle_load(&CheckerContext::new(
+        create_example_problem(),
+        problem,
+        None,
+        solution,
+    )));
 
     assert_eq!(result, expected_result);
 }
@@ -0,0 +1,131 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+use vrp_core::models::examples::create_example_problem;
+
+#[test]
+fn can_serialize_errors_to_json() {
+    let tour = Tour { vehicle_id: "my_vehicle_1".to_string(), shift_index: 1, ..create_empty_tour() };
+    let errors = vec![
+        CheckerError::new("LOAD_MISMATCH", "Load mismatch at stop 1 in tour 'my_vehicle_1'".to_string())
+            .with_tour(0, &tour)
+            .with_stop(1)
+            .with_job("job1")
+            .with_values("[1]", "[2]"),
+        CheckerError::from("some error".to_string()),
+    ];
+
+    let result = CheckerError::to_json(&errors).expect("cannot serialize errors");
+
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(result.as_str()).unwrap(),
+        serde_json::json!([
+            {
+                "code": "LOAD_MISMATCH",
+                "message": "Load mismatch at stop 1 in tour 'my_vehicle_1'",
+                "tourIndex": 0,
+                "vehicleId": "my_vehicle_1",
+                "shiftIndex": 1,
+                "stopIndex": 1,
+                "jobId": "job1",
+                "expected": "[1]",
+                "actual": "[2]"
+            },
+            {
+                "code": "INVALID_DATA",
+                "message": "some error"
+            }
+        ])
+    );
+}
+
+#[test]
+fn can_keep_tour_info_when_it_is_already_set() {
+    let tour = Tour { vehicle_id: "my_vehicle_1".to_string(), ..create_empty_tour() };
+    let other = Tour { vehicle_id: "my_vehicle_2".to_string(), ..create_empty_tour() };
+
+    let error = CheckerError::new("CODE", "message".to_string()).with_tour(0, &tour).with_tour(1, &other);
+
+    assert_eq!(error.tour_index, Some(0));
+    assert_eq!(error.vehicle_id.as_deref(), Some("my_vehicle_1"));
+    assert_eq!(error.to_string(), "CODE: message");
+}
+
+parameterized_test! {can_match_reload_time_windows, (times, reload_time, expected_result), {
+    can_match_reload_time_windows_impl(times, reload_time, expected_result);
+}}
+
+can_match_reload_time_windows! {
+    case_01: (None, (5., 7.), Ok(())),
+    case_02: (Some(vec![(10., 20.)]), (5., 7.), Err("Cannot find reload for tour 'my_vehicle_1'".to_string())),
+    case_03: (Some(vec![(10., 20.)]), (10., 12.), Ok(())),
+    case_04: (Some(vec![(10., 20.)]), (19., 21.), Ok(())),
+    case_05: (Some(vec![(10., 20.)]), (21., 23.), Err("Cannot find reload for tour 'my_vehicle_1'".to_string())),
+    case_06: (Some(vec![(0., 4.), (21., 30.)]), (21., 23.), Ok(())),
+}
+
+fn can_match_reload_time_windows_impl(
+    times: Option<Vec<(f64, f64)>>,
+    reload_time: (f64, f64),
+    expected_result: Result<(), String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        location: vec![0., 0.].to_loc(),
+                        duration: 2.,
+                        times: times.map(|times| {
+                            times.into_iter().map(|(start, end)| vec![format_time(start), format_time(end)]).collect()
+                        }),
+                        tag: None,
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            profiles: create_default_profiles(),
+            states: None,
+        },
+        ..create_empty_problem()
+    };
+    let tour = Tour {
+        vehicle_id: "my_vehicle_1".to_string(),
+        type_id: "my_vehicle".to_string(),
+        stops: vec![
+            create_stop_with_activity(
+                "departure",
+                "departure",
+                (0., 0.),
+                0,
+                (format_time(0.).as_str(), format_time(0.).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "reload",
+                "reload",
+                (0., 0.),
+                0,
+                (format_time(reload_time.0).as_str(), format_time(reload_time.1).as_str()),
+                0,
+            ),
+            create_stop_with_activity(
+                "arrival",
+                "arrival",
+                (0., 0.),
+                0,
+                (format_time(100.).as_str(), format_time(100.).as_str()),
+                0,
+            ),
+        ],
+        ..create_empty_tour()
+    };
+    let solution = Solution { tours: vec![tour.clone()], ..create_empty_solution() };
+    let context = CheckerContext::new(create_example_problem(), problem, None, solution);
+    let reload_stop = tour.stops.get(1).unwrap();
+
+    let result = context.get_activity_type(&tour, reload_stop, reload_stop.activities.first().unwrap()).map(|_| ());
+
+    assert_eq!(result, expected_result);
+}
@@ -12,6 +12,7 @@ fn create_test_problem(limits: Option<VehicleLimits>) -> Problem {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     }
@@ -66,7 +67,8 @@ pub fn can_check_shift_and_distance_limit_impl(
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
 
-    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+    let result =
+        to_checker_messages(check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution)));
 
     assert_eq!(result, expected);
 }
@@ -125,7 +127,8 @@ pub fn can_check_tour_size_limit() {
         ],
     );
 
-    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution));
+    let result =
+        to_checker_messages(check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution)));
 
     assert_eq!(
         result,
@@ -133,3 +136,32 @@ pub fn can_check_tour_size_limit() {
             .to_string())
     );
 }
+
+#[test]
+pub fn can_collect_all_limit_violations() {
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: Some(10.),
+        shift_time: Some(20.),
+        tour_size: None,
+        allowed_areas: None,
+    }));
+    let solution = create_test_solution(Statistic { distance: 11, duration: 21, ..Statistic::default() }, vec![]);
+
+    let result = check_limits(&CheckerContext::new(create_example_problem(), problem, None, solution))
+        .err()
+        .expect("limit violations are expected");
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(
+        result
+            .iter()
+            .map(|err| (err.code.as_str(), err.expected.as_deref(), err.actual.as_deref()))
+            .collect::<Vec<_>>(),
+        vec![("MAX_DISTANCE_LIMIT", Some("10"), Some("11")), ("SHIFT_TIME_LIMIT", Some("20"), Some("21"))]
+    );
+    result.iter().for_each(|err| {
+        assert_eq!(err.tour_index, Some(0));
+        assert_eq!(err.vehicle_id.as_deref(), Some("some_real_vehicle"));
+        assert_eq!(err.shift_index, Some(0));
+    });
+}
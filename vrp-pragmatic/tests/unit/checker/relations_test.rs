@@ -110,6 +110,7 @@ mod single {
                     limits: None,
                 }],
                 profiles: create_default_profiles(),
+                states: None,
             },
             ..create_empty_problem()
         };
@@ -232,3 +233,75 @@ mod single {
         assert_eq!(result, expected_result);
     }
 }
+
+mod multi {
+    use super::*;
+    use vrp_core::models::examples::create_example_problem;
+    use RelationType::{Any, Sequence, Strict};
+
+    parameterized_test! {can_check_multi_job_relations, (relation_type, tags, expected_result), {
+        can_check_multi_job_relations_impl(relation_type, tags, expected_result);
+    }}
+
+    can_check_multi_job_relations! {
+        case_01: (Strict, vec!["p1", "p2", "d1"], Ok(())),
+        case_02: (Strict, vec!["p1", "p1", "d1"], Err("Task pickup with tag 'p1' is served more than once for 'job1'".to_string())),
+        case_03: (Sequence, vec!["p2", "p1", "d1"], Ok(())),
+        case_04: (Sequence, vec!["p2", "p3", "d1"], Err("Cannot find pickup task with tag 'p3' for 'job1'".to_string())),
+        case_05: (Any, vec!["p2", "p1", "d1"], Ok(())),
+        case_06: (Any, vec!["p1", "p1", "d1"], Err("Task pickup with tag 'p1' is served more than once for 'job1'".to_string())),
+    }
+
+    fn can_check_multi_job_relations_impl(
+        relation_type: RelationType,
+        tags: Vec<&str>,
+        expected_result: Result<(), String>,
+    ) {
+        let create_task = |tag: &str, location: Vec<f64>| JobTask {
+            places: vec![JobPlace { location: location.to_loc(), duration: 1., times: None }],
+            demand: Some(vec![1]),
+            tag: Some(tag.to_string()),
+        };
+        let problem = Problem {
+            plan: Plan {
+                jobs: vec![Job {
+                    pickups: Some(vec![create_task("p1", vec![1., 0.]), create_task("p2", vec![2., 0.])]),
+                    deliveries: Some(vec![create_task("d1", vec![3., 0.])]),
+                    ..create_job("job1")
+                }],
+                relations: Some(vec![Relation {
+                    type_field: relation_type,
+                    jobs: vec!["job1".to_string(), "job1".to_string(), "job1".to_string()],
+                    vehicle_id: "my_vehicle_1".to_string(),
+                    shift_index: None,
+                }]),
+            },
+            fleet: create_fleet_with_vehicles(vec![create_default_vehicle_type()]),
+            ..create_empty_problem()
+        };
+        let solution = Solution {
+            tours: vec![Tour {
+                vehicle_id: "my_vehicle_1".to_string(),
+                type_id: "my_vehicle".to_string(),
+                stops: vec!["pickup", "pickup", "delivery"]
+                    .into_iter()
+                    .zip(tags.into_iter())
+                    .map(|(activity_type, tag)| {
+                        create_stop_with_activity_with_tag("job1", activity_type, (0., 0.), 0, ("", ""), 0, tag)
+                    })
+                    .collect(),
+                ..create_empty_tour()
+            }],
+            ..create_empty_solution()
+        };
+
+        let result = to_checker_messages(check_relations(&CheckerContext::new(
+            create_example_problem(),
+            problem,
+            None,
+            solution,
+        )));
+
+        assert_eq!(result, expected_result);
+    }
+}
@@ -9,7 +9,11 @@ fn create_test_problem() -> Problem {
             jobs: vec![create_delivery_job("job1", vec![1., 0.]), create_delivery_job("job2", vec![2., 0.])],
             relations: None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle_type()], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     }
 }
@@ -114,6 +118,8 @@ can_check_stop! {
     case_07: (&[(1., 3), (3., 2), (6., 4)], Err(distance_error_msg(1, 3, 1))),
     case_08: (&[(1., 1), (3., 0), (6., 4)], Err(distance_error_msg(2, 0, 2))),
     case_09: (&[(1., 1), (3., 2), (6., 6)], Err(distance_error_msg(3, 6, 4))),
+
+    case_10: (&[(3., 1), (3., 2), (6., 6)], Err(format!("{}\n{}", duration_error_msg(1, 3, 1), distance_error_msg(3, 6, 4)))),
 }
 
 fn can_check_stop_impl(stop_data: &[(f64, i64); 3], expected_result: Result<(), String>) {
@@ -121,7 +127,12 @@ fn can_check_stop_impl(stop_data: &[(f64, i64); 3], expected_result: Result<(),
     let matrix = create_matrix_from_problem(&problem);
     let solution = create_test_solution(create_test_statistic(), stop_data);
 
-    let result = check_routing(&CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution));
+    let result = to_checker_messages(check_routing(&CheckerContext::new(
+        create_example_problem(),
+        problem,
+        Some(vec![matrix]),
+        solution,
+    )));
 
     assert_eq!(result, expected_result);
 }
@@ -149,7 +160,12 @@ fn can_check_tour_statistic_impl(statistic: Statistic, expected_result: Result<(
     let matrix = create_matrix_from_problem(&problem);
     let solution = create_test_solution(statistic, &[(1., 1), (3., 2), (6., 4)]);
 
-    let result = check_routing(&CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution));
+    let result = to_checker_messages(check_routing(&CheckerContext::new(
+        create_example_problem(),
+        problem,
+        Some(vec![matrix]),
+        solution,
+    )));
 
     assert_eq!(result, expected_result);
 }
@@ -162,7 +178,12 @@ fn can_check_solution_statistic() {
     let wrong_statistic = Statistic { duration: 1, ..create_test_statistic() };
     let solution = Solution { statistic: wrong_statistic.clone(), ..solution };
 
-    let result = check_routing(&CheckerContext::new(create_example_problem(), problem, Some(vec![matrix]), solution));
+    let result = to_checker_messages(check_routing(&CheckerContext::new(
+        create_example_problem(),
+        problem,
+        Some(vec![matrix]),
+        solution,
+    )));
 
     assert_eq!(
         result,
@@ -0,0 +1,162 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_vehicle_costs() -> VehicleCosts {
+    VehicleCosts { fixed: Some(10.), distance: 1., time: 1. }
+}
+
+fn create_vehicle(type_id: &str) -> VehicleType {
+    VehicleTypeBuilder::new(type_id, "car", create_vehicle_costs())
+        .add_vehicle_id(format!("{}_1", type_id).as_str())
+        .add_shift(
+            VehicleShiftBuilder::new(Location::new_coordinate(0., 0.), format_time(0.).as_str())
+                .with_end(Location::new_coordinate(0., 0.), format_time(1000.).as_str())
+                .build(),
+        )
+        .with_capacity(vec![10])
+        .build()
+}
+
+fn create_delivery(id: &str, lat: f64, lng: f64) -> Job {
+    JobBuilder::new(id)
+        .add_delivery(
+            JobTaskBuilder::default()
+                .add_place(Location::new_coordinate(lat, lng), 1., &[])
+                .with_demand(vec![1])
+                .build(),
+        )
+        .build()
+}
+
+#[test]
+fn can_build_job_with_tasks() {
+    let job = JobBuilder::new("job1")
+        .add_pickup(
+            JobTaskBuilder::default()
+                .add_place(Location::new_coordinate(1., 0.), 10., &[("2020-07-04T08:00:00Z", "2020-07-04T12:00:00Z")])
+                .with_demand(vec![2])
+                .with_tag("p1")
+                .build(),
+        )
+        .add_delivery(
+            JobTaskBuilder::default()
+                .add_place(Location::new_coordinate(2., 0.), 5., &[])
+                .add_place(Location::new_coordinate(3., 0.), 5., &[])
+                .with_demand(vec![2])
+                .build(),
+        )
+        .with_priority(2)
+        .with_skills(all_of_skills(to_strings(vec!["fridge"])))
+        .build();
+
+    assert_eq!(job.id, "job1");
+    let pickups = job.pickups.expect("no pickups");
+    assert_eq!(pickups.len(), 1);
+    assert_eq!(pickups[0].tag, Some("p1".to_string()));
+    assert_eq!(pickups[0].places[0].duration, 10.);
+    assert_eq!(
+        pickups[0].places[0].times,
+        Some(vec![vec!["2020-07-04T08:00:00Z".to_string(), "2020-07-04T12:00:00Z".to_string()]])
+    );
+    let deliveries = job.deliveries.expect("no deliveries");
+    assert_eq!(deliveries[0].places.len(), 2);
+    assert!(deliveries[0].places[1].times.is_none());
+    assert!(job.replacements.is_none());
+    assert!(job.services.is_none());
+    assert_eq!(job.priority, Some(2));
+    assert!(job.skills.is_some());
+}
+
+#[test]
+fn can_build_vehicle_type() {
+    let vehicle = VehicleTypeBuilder::new("type1", "truck", create_vehicle_costs())
+        .add_vehicle_id("v1")
+        .add_vehicle_id("v2")
+        .add_shift(
+            VehicleShiftBuilder::new(Location::new_coordinate(0., 0.), format_time(0.).as_str())
+                .with_latest_start(format_time(10.).as_str())
+                .add_break(VehicleBreak {
+                    time: VehicleBreakTime::TimeOffset(vec![10., 20.]),
+                    duration: 5.,
+                    locations: None,
+                })
+                .build(),
+        )
+        .with_capacity(vec![5, 2])
+        .with_skills(to_strings(vec!["fridge"]))
+        .build();
+
+    assert_eq!(vehicle.type_id, "type1");
+    assert_eq!(vehicle.profile, "truck");
+    assert_eq!(vehicle.vehicle_ids, to_strings(vec!["v1", "v2"]));
+    assert_eq!(vehicle.capacity, vec![5, 2]);
+    assert_eq!(vehicle.shifts.len(), 1);
+    assert_eq!(vehicle.shifts[0].start.latest, Some(format_time(10.)));
+    assert!(vehicle.shifts[0].end.is_none());
+    assert_eq!(vehicle.shifts[0].breaks.as_ref().map(|breaks| breaks.len()), Some(1));
+    assert!(vehicle.limits.is_none());
+}
+
+#[test]
+fn can_build_and_solve_problem() {
+    let problem = ProblemBuilder::default()
+        .add_job(create_delivery("job1", 1., 0.))
+        .add_job(create_delivery("job2", 2., 0.))
+        .add_vehicle(create_vehicle("my_vehicle"))
+        .add_profile(Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None })
+        .build()
+        .expect("cannot build problem");
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.len(), 1);
+    assert!(solution.unassigned.is_none());
+}
+
+#[test]
+fn can_validate_problem_on_build() {
+    let result = ProblemBuilder::default()
+        .add_job(create_delivery("job1", 1., 0.))
+        .add_job(create_delivery("job1", 2., 0.))
+        .add_vehicle(create_vehicle("my_vehicle"))
+        .add_profile(Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None })
+        .build();
+
+    let errors = result.err().expect("problem should be invalid");
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "E1100");
+}
+
+#[test]
+fn can_validate_problem_with_matrices_on_build() {
+    let create_builder = || {
+        ProblemBuilder::default()
+            .add_job(
+                JobBuilder::new("job1")
+                    .add_delivery(
+                        JobTaskBuilder::default()
+                            .add_place(Location::new_reference(1), 1., &[])
+                            .with_demand(vec![1])
+                            .build(),
+                    )
+                    .build(),
+            )
+            .add_vehicle(
+                VehicleTypeBuilder::new("my_vehicle", "car", create_vehicle_costs())
+                    .add_vehicle_id("my_vehicle_1")
+                    .add_shift(VehicleShiftBuilder::new(Location::new_reference(0), format_time(0.).as_str()).build())
+                    .with_capacity(vec![10])
+                    .build(),
+            )
+            .add_profile(Profile { name: "car".to_string(), profile_type: "car".to_string(), speed: None })
+    };
+    let matrices = vec![create_matrix(vec![0, 1, 1, 0])];
+
+    let errors = create_builder().build().err().expect("problem should be invalid");
+    assert_eq!(errors.iter().map(|err| err.code.as_str()).collect::<Vec<_>>(), vec!["E1503"]);
+
+    let result = create_builder().build_with_matrices(Some(&matrices));
+    assert!(result.is_ok(), "{:?}", result.err());
+}
@@ -32,6 +32,7 @@ fn create_problem(profiles: &[&str]) -> Problem {
                 .iter()
                 .map(|p| Profile { name: p.to_string(), profile_type: "car".to_string(), speed: None })
                 .collect(),
+            states: None,
         },
         ..create_empty_problem()
     }
@@ -182,6 +182,7 @@ fn can_read_complex_problem() {
                 }),
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         objectives: None,
     };
@@ -308,6 +309,7 @@ fn can_create_approximation_matrices() {
                 FormatProfile { name: "car3".to_string(), profile_type: "car".to_string(), speed: Some(5.) },
                 FormatProfile { name: "car4".to_string(), profile_type: "car".to_string(), speed: None },
             ],
+            states: None,
         },
         ..create_empty_problem()
     };
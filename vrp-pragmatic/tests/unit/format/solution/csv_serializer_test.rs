@@ -0,0 +1,99 @@
+use super::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn serialize_to_string<F>(serialize: F, solution: &Solution) -> String
+where
+    F: Fn(BufWriter<&mut Vec<u8>>, &Solution) -> Result<(), Error>,
+{
+    let mut buffer = Vec::new();
+    serialize(BufWriter::new(&mut buffer), solution).unwrap();
+
+    String::from_utf8(buffer).unwrap()
+}
+
+fn create_test_solution() -> Solution {
+    let mut break_stop =
+        create_stop_with_activity("break", "break", (2., 0.), 1, ("1970-01-01T00:00:05Z", "1970-01-01T00:00:09Z"), 2);
+    break_stop.activities.insert(
+        0,
+        Activity {
+            job_id: "job,2".to_string(),
+            activity_type: "delivery".to_string(),
+            location: None,
+            time: Some(Interval { start: "1970-01-01T00:00:05Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
+            job_tag: None,
+        },
+    );
+
+    Solution {
+        tours: vec![Tour {
+            vehicle_id: "my_vehicle_1".to_string(),
+            type_id: "my_vehicle".to_string(),
+            shift_index: 0,
+            stops: vec![
+                create_stop_with_activity_md(
+                    "departure",
+                    "departure",
+                    (0., 0.),
+                    vec![2, 1],
+                    ("1970-01-01T00:00:00Z", "1970-01-01T00:00:00Z"),
+                    0,
+                ),
+                create_stop_with_activity(
+                    "job1",
+                    "delivery",
+                    (1., 0.),
+                    1,
+                    ("1970-01-01T00:00:01Z", "1970-01-01T00:00:02Z"),
+                    1,
+                ),
+                break_stop,
+            ],
+            statistic: Default::default(),
+        }],
+        unassigned: Some(vec![UnassignedJob {
+            job_id: "job3".to_string(),
+            reasons: vec![UnassignedJobReason::new(
+                "CAPACITY_CONSTRAINT".to_string(),
+                "does not fit into any vehicle due to capacity".to_string(),
+            )],
+        }]),
+        ..create_empty_solution()
+    }
+}
+
+#[test]
+fn can_serialize_tours_as_csv() {
+    let result =
+        serialize_to_string(|writer, solution| serialize_tours_as_csv(writer, solution), &create_test_solution());
+
+    assert_eq!(
+        result,
+        "vehicle_id,shift_index,stop_index,job_id,activity_type,arrival,departure,load,distance\n\
+         my_vehicle_1,0,0,departure,departure,1970-01-01T00:00:00Z,1970-01-01T00:00:00Z,2;1,0\n\
+         my_vehicle_1,0,1,job1,delivery,1970-01-01T00:00:01Z,1970-01-01T00:00:02Z,1,1\n\
+         my_vehicle_1,0,2,\"job,2\",delivery,1970-01-01T00:00:05Z,1970-01-01T00:00:06Z,1,2\n\
+         my_vehicle_1,0,2,break,break,1970-01-01T00:00:05Z,1970-01-01T00:00:09Z,1,2\n"
+    );
+}
+
+#[test]
+fn can_serialize_unassigned_as_csv() {
+    let result =
+        serialize_to_string(|writer, solution| serialize_unassigned_as_csv(writer, solution), &create_test_solution());
+
+    assert_eq!(
+        result,
+        "job_id,code,description\n\
+         job3,CAPACITY_CONSTRAINT,does not fit into any vehicle due to capacity\n"
+    );
+}
+
+#[test]
+fn can_serialize_empty_unassigned_as_csv() {
+    let result =
+        serialize_to_string(|writer, solution| serialize_unassigned_as_csv(writer, solution), &create_empty_solution());
+
+    assert_eq!(result, "job_id,code,description\n");
+}
@@ -28,6 +28,7 @@ fn create_basic_problem(breaks: Option<Vec<VehicleBreak>>) -> Problem {
                 ..create_default_vehicle_type()
             }],
             profiles: create_default_profiles(),
+            states: None,
         },
         ..create_empty_problem()
     }
@@ -47,10 +48,7 @@ fn create_unassigned_jobs(job_ids: &[&str]) -> Option<Vec<UnassignedJob>> {
             .iter()
             .map(|job_id| UnassignedJob {
                 job_id: job_id.to_string(),
-                reasons: vec![UnassignedJobReason {
-                    code: "NO_REASON_FOUND".to_string(),
-                    description: "unknown".to_string(),
-                }],
+                reasons: vec![UnassignedJobReason::new("NO_REASON_FOUND".to_string(), "unknown".to_string())],
             })
             .collect(),
     )
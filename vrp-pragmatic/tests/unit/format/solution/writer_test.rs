@@ -1,6 +1,8 @@
 use crate::format::problem::*;
 use crate::format::solution::*;
 use crate::helpers::*;
+use std::sync::Arc;
+use vrp_core::solver::Builder;
 
 #[test]
 fn can_create_solution() {
@@ -9,7 +11,11 @@ fn can_create_solution() {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -82,7 +88,11 @@ fn can_merge_activities_in_one_stop() {
             jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![5., 0.])],
             relations: Option::None,
         },
-        fleet: Fleet { vehicles: vec![create_default_vehicle("my_vehicle")], profiles: create_default_profiles() },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle("my_vehicle")],
+            profiles: create_default_profiles(),
+            states: None,
+        },
         ..create_empty_problem()
     };
     let matrix = create_matrix_from_problem(&problem);
@@ -102,3 +112,51 @@ fn can_merge_activities_in_one_stop() {
     assert_eq!(solution.tours.first().unwrap().stops.len(), 3);
     assert_eq!(solution.tours.first().unwrap().stops.get(1).unwrap().activities.len(), 2);
 }
+
+#[test]
+fn can_create_solution_with_objective_values() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", vec![5., 0.]), create_delivery_job("job2", vec![10., 0.])],
+            relations: Option::None,
+        },
+        fleet: create_default_fleet(),
+        objectives: Some(Objectives {
+            primary: vec![Objective::MinimizeCost, Objective::BalanceDistance { options: None }],
+            secondary: None,
+        }),
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let core_problem = Arc::new((problem, vec![matrix]).read_pragmatic().unwrap());
+    let (front, _) = Builder::new(core_problem.clone())
+        .with_max_generations(Some(10))
+        .build()
+        .and_then(|solver| solver.solve_pareto_front())
+        .unwrap();
+    let (solution, cost, fitness) = front.first().unwrap();
+
+    let solution = create_solution_with_objectives(core_problem.as_ref(), solution, fitness.as_slice());
+
+    let objectives = solution.extras.and_then(|extras| extras.objectives).expect("no objective values");
+    assert_eq!(
+        objectives.iter().map(|objective| objective.objective_type.as_str()).collect::<Vec<_>>(),
+        vec!["minimize-cost", "balance-distance"]
+    );
+    assert_eq!(objectives[0].value, *cost);
+}
+
+#[test]
+fn can_deserialize_unassigned_reason_without_details() {
+    let reason = r#"{"code": "CAPACITY_CONSTRAINT", "description": "does not fit into any vehicle due to capacity"}"#;
+
+    let reason: UnassignedJobReason = serde_json::from_str(reason).expect("cannot deserialize reason");
+
+    assert_eq!(
+        reason,
+        UnassignedJobReason::new(
+            "CAPACITY_CONSTRAINT".to_string(),
+            "does not fit into any vehicle due to capacity".to_string()
+        )
+    );
+    assert_eq!(serde_json::to_string(&reason).unwrap().contains("details"), false);
+}
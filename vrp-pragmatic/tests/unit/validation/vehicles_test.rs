@@ -38,6 +38,7 @@ fn can_detect_invalid_area_impl(allowed_shapes: Option<Vec<Vec<Location>>>, expe
                 ..create_default_vehicle_type()
             }],
             profiles: vec![],
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -81,6 +82,7 @@ fn can_detect_invalid_dispatch_impl(dispatch: &[(f64, (f64, f64))], expected: Op
                 ..create_default_vehicle_type()
             }],
             profiles: vec![],
+            states: None,
         },
         ..create_empty_problem()
     };
@@ -89,3 +91,53 @@ fn can_detect_invalid_dispatch_impl(dispatch: &[(f64, (f64, f64))], expected: Op
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_detect_invalid_vehicle_state, (vehicle_id, shift_index, time, served, loaded, expected), {
+    can_detect_invalid_vehicle_state_impl(vehicle_id, shift_index, time, served, loaded, expected);
+}}
+
+can_detect_invalid_vehicle_state! {
+    case01: ("my_vehicle_1", None, 10., vec!["job1"], vec!["job2"], None),
+    case02: ("my_vehicle_2", None, 10., vec!["job1"], vec!["job2"], Some("E1307".to_string())),
+    case03: ("my_vehicle_1", Some(1), 10., vec!["job1"], vec!["job2"], Some("E1307".to_string())),
+    case04: ("my_vehicle_1", None, 1001., vec!["job1"], vec!["job2"], Some("E1307".to_string())),
+    case05: ("my_vehicle_1", None, 10., vec!["job3"], vec!["job2"], Some("E1307".to_string())),
+    case06: ("my_vehicle_1", None, 10., vec!["job1"], vec!["job1"], Some("E1307".to_string())),
+    case07: ("my_vehicle_1", None, 10., vec!["job2"], vec!["job2"], Some("E1307".to_string())),
+}
+
+fn can_detect_invalid_vehicle_state_impl(
+    vehicle_id: &str,
+    shift_index: Option<usize>,
+    time: f64,
+    served: Vec<&str>,
+    loaded: Vec<&str>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", vec![1., 0.]),
+                create_pickup_delivery_job("job2", vec![2., 0.], vec![3., 0.]),
+            ],
+            relations: None,
+        },
+        fleet: Fleet {
+            vehicles: vec![create_default_vehicle_type()],
+            profiles: vec![],
+            states: Some(vec![VehicleState {
+                vehicle_id: vehicle_id.to_string(),
+                shift_index,
+                location: vec![1., 0.].to_loc(),
+                time: format_time(time),
+                served: Some(to_strings(served)),
+                loaded: Some(to_strings(loaded)),
+            }]),
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1307_vehicle_state_is_correct(&ValidationContext::new(&problem, None));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
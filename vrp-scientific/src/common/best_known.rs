@@ -0,0 +1,75 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/best_known_test.rs"]
+mod best_known_test;
+
+use std::fmt::{Display, Formatter};
+use vrp_core::models::{Problem, Solution};
+
+/// Specifies a gap between solution and best known solution in tour count and total distance.
+#[derive(Clone, Debug)]
+pub struct BestKnownGap {
+    /// Amount of tours in solution and in best known solution.
+    pub tours: (usize, usize),
+    /// Total distance of solution and of best known solution.
+    pub distance: (f64, f64),
+}
+
+impl BestKnownGap {
+    /// Creates a new instance of `BestKnownGap` comparing solution with best known one.
+    pub fn new(problem: &Problem, solution: &Solution, best_known: &Solution) -> Self {
+        Self {
+            tours: (solution.routes.len(), best_known.routes.len()),
+            distance: (get_total_distance(problem, solution), get_total_distance(problem, best_known)),
+        }
+    }
+
+    /// Returns difference in tour count: positive value means that solution has more tours.
+    pub fn tours_gap(&self) -> i64 {
+        self.tours.0 as i64 - self.tours.1 as i64
+    }
+
+    /// Returns difference in total distance as a percentage of best known distance:
+    /// positive value means that solution is longer.
+    pub fn distance_gap(&self) -> f64 {
+        if self.distance.1 == 0. {
+            0.
+        } else {
+            (self.distance.0 - self.distance.1) / self.distance.1 * 100.
+        }
+    }
+}
+
+impl Display for BestKnownGap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "tours: {} (best known: {}, gap: {}), distance: {:.2} (best known: {:.2}, gap: {:.2}%)",
+            self.tours.0,
+            self.tours.1,
+            self.tours_gap(),
+            self.distance.0,
+            self.distance.1,
+            self.distance_gap()
+        )
+    }
+}
+
+/// Calculates total distance of all tours in the solution.
+pub fn get_total_distance(problem: &Problem, solution: &Solution) -> f64 {
+    solution
+        .routes
+        .iter()
+        .map(|route| {
+            let profile = route.actor.vehicle.profile;
+            let activities = route.tour.all_activities().collect::<Vec<_>>();
+
+            activities
+                .windows(2)
+                .map(|leg| {
+                    let (from, to) = (leg[0], leg[1]);
+                    problem.transport.distance(profile, from.place.location, to.place.location, from.schedule.departure)
+                })
+                .sum::<f64>()
+        })
+        .sum()
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+#[path = "../../tests/unit/common/initial_reader_test.rs"]
+mod initial_reader_test;
+
+use crate::common::read_line;
+use std::collections::{HashMap, HashSet};
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::solution::{Activity, Registry, Route, Tour};
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::Random;
+
+/// Reads solution from a buffer in text format. Each route is specified on a separate line as
+/// `Route <number>: <customer ids>`, other lines (e.g. header of best known solution file) are ignored.
+/// Customer id is resolved to a single job with id `<prefix><customer id>`. Jobs which are not
+/// present in routes are added to unassigned list.
+/// NOTE: Solution feasibility is not checked.
+pub(crate) fn read_text_init_solution<R: Read>(
+    mut reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+    prefix: &str,
+) -> Result<Solution, String> {
+    let mut buffer = String::new();
+
+    let mut solution = Solution {
+        registry: Registry::new(&problem.fleet, random),
+        routes: vec![],
+        unassigned: Default::default(),
+        extras: problem.extras.clone(),
+    };
+
+    let id_map = problem.jobs.all().fold(HashMap::<String, Arc<Single>>::new(), |mut acc, job| {
+        let singles = match &job {
+            Job::Single(single) => vec![single.clone()],
+            Job::Multi(multi) => multi.jobs.clone(),
+        };
+
+        singles.into_iter().for_each(|single| {
+            if let Some(id) = single.dimens.get_id().cloned() {
+                acc.insert(id, single);
+            }
+        });
+
+        acc
+    });
+
+    loop {
+        match read_line(&mut reader, &mut buffer) {
+            Ok(read) if read > 0 => {
+                if !buffer.trim_start().starts_with("Route") {
+                    continue;
+                }
+
+                let customers = buffer
+                    .find(':')
+                    .map(|idx| &buffer[idx + 1..])
+                    .ok_or_else(|| format!("cannot parse route: '{}'", buffer.trim()))?
+                    .split_whitespace()
+                    .map(|id| {
+                        id_map
+                            .get(&[prefix, id].concat())
+                            .cloned()
+                            .ok_or_else(|| format!("cannot find customer with id: '{}'", id))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                if customers.is_empty() {
+                    continue;
+                }
+
+                let actor = solution.registry.next().next().ok_or_else(|| "not enough vehicles".to_string())?;
+                let mut tour = Tour::new(&actor);
+
+                customers.into_iter().for_each(|single| {
+                    let place = single.places.first().unwrap();
+                    tour.insert_last(Activity {
+                        place: vrp_core::models::solution::Place {
+                            location: place.location.unwrap(),
+                            duration: place.duration,
+                            time: place.times.first().and_then(|span| span.as_time_window()).unwrap(),
+                        },
+                        schedule: Schedule::new(0.0, 0.0),
+                        job: Some(single),
+                    });
+                });
+
+                solution.registry.use_actor(&actor);
+                solution.routes.push(Route { actor, tour });
+            }
+            Ok(_) => break,
+            Err(error) => {
+                if buffer.is_empty() {
+                    break;
+                } else {
+                    return Err(error);
+                }
+            }
+        }
+    }
+
+    let assigned = solution.routes.iter().flat_map(|route| route.tour.jobs()).collect::<HashSet<_>>();
+    solution.unassigned = problem.jobs.all().filter(|job| !assigned.contains(job)).map(|job| (job, 0)).collect();
+
+    Ok(solution)
+}
@@ -6,6 +6,12 @@ pub(crate) use self::text_reader::*;
 mod text_writer;
 pub(crate) use self::text_writer::*;
 
+mod initial_reader;
+pub(crate) use self::initial_reader::*;
+
+mod best_known;
+pub use self::best_known::*;
+
 /// A trait to get tuple from collection items.
 /// See https://stackoverflow.com/questions/38863781/how-to-create-a-tuple-from-a-vector
 pub(crate) trait TryCollect<T> {
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::io::prelude::*;
 use std::io::{BufReader, Read};
 use std::sync::Arc;
@@ -18,10 +19,10 @@ pub(crate) trait TextReader {
             fleet: Arc::new(fleet),
             jobs: Arc::new(jobs),
             locks: vec![],
-            constraint: Arc::new(create_constraint(activity.clone(), transport.clone())),
+            constraint: Arc::new(self.create_constraint_pipeline(activity.clone(), transport.clone())),
             activity,
             transport,
-            objective: Arc::new(ObjectiveCost::default()),
+            objective: Arc::new(self.create_objective()),
             extras: Arc::new(self.create_extras()),
         })
     }
@@ -33,6 +34,18 @@ pub(crate) trait TextReader {
     fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String>;
 
     fn create_extras(&self) -> Extras;
+
+    fn create_objective(&self) -> ObjectiveCost {
+        ObjectiveCost::default()
+    }
+
+    fn create_constraint_pipeline(
+        &self,
+        activity: Arc<SimpleActivityCost>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> ConstraintPipeline {
+        create_constraint(activity, transport)
+    }
 }
 
 pub(crate) fn create_fleet_with_distance_costs(
@@ -41,33 +54,13 @@ pub(crate) fn create_fleet_with_distance_costs(
     location: Location,
     time: TimeWindow,
 ) -> Fleet {
-    Fleet::new(
-        vec![Arc::new(Driver {
-            costs: Costs {
-                fixed: 0.0,
-                per_distance: 0.0,
-                per_driving_time: 0.0,
-                per_waiting_time: 0.0,
-                per_service_time: 0.0,
-            },
-            dimens: create_dimens_with_id("driver", 0),
-            details: Default::default(),
-        })],
+    create_fleet_with_vehicles(
         (0..number)
             .map(|i| {
-                let mut dimens = create_dimens_with_id("v", i);
-                dimens.set_capacity(SingleDimLoad::new(capacity as i32));
-                Arc::new(Vehicle {
-                    profile: 0,
-                    costs: Costs {
-                        fixed: 0.0,
-                        per_distance: 1.0,
-                        per_driving_time: 0.0,
-                        per_waiting_time: 0.0,
-                        per_service_time: 0.0,
-                    },
-                    dimens,
-                    details: vec![VehicleDetail {
+                create_vehicle_with_distance_costs(
+                    i,
+                    capacity,
+                    vec![VehicleDetail {
                         start: Some(VehiclePlace {
                             location,
                             time: TimeInterval { earliest: Some(time.start), latest: None },
@@ -77,13 +70,61 @@ pub(crate) fn create_fleet_with_distance_costs(
                             time: TimeInterval { earliest: None, latest: Some(time.end) },
                         }),
                     }],
-                })
+                )
             })
             .collect(),
-        Box::new(|_| Box::new(|_| 0)),
     )
 }
 
+pub(crate) fn create_fleet_with_vehicles(vehicles: Vec<Vehicle>) -> Fleet {
+    Fleet::new(
+        vec![Arc::new(Driver {
+            costs: Costs {
+                fixed: 0.0,
+                per_distance: 0.0,
+                per_driving_time: 0.0,
+                per_waiting_time: 0.0,
+                per_service_time: 0.0,
+            },
+            dimens: create_dimens_with_id("driver", 0),
+            details: Default::default(),
+        })],
+        vehicles.into_iter().map(Arc::new).collect(),
+        Box::new(|actors| {
+            // NOTE actors with the same start, end and time are considered as the same type
+            let groups = actors.iter().fold(HashMap::new(), |mut acc, actor| {
+                let next_key = acc.len();
+                acc.entry(actor.detail.clone()).or_insert(next_key);
+                acc
+            });
+
+            Box::new(move |actor| *groups.get(&actor.detail).unwrap())
+        }),
+    )
+}
+
+pub(crate) fn create_vehicle_with_distance_costs(
+    index: usize,
+    capacity: usize,
+    details: Vec<VehicleDetail>,
+) -> Vehicle {
+    let mut dimens = create_dimens_with_id("v", index);
+    dimens.set_capacity(SingleDimLoad::new(capacity as i32));
+
+    Vehicle {
+        profile: 0,
+        costs: Costs {
+            fixed: 0.0,
+            per_distance: 1.0,
+            per_driving_time: 0.0,
+            per_waiting_time: 0.0,
+            per_service_time: 0.0,
+        },
+        dimens,
+        details,
+    }
+}
+
 pub(crate) fn create_dimens_with_id(prefix: &str, id: usize) -> Dimensions {
     let mut dimens = Dimensions::new();
     dimens.set_id([prefix.to_string(), id.to_string()].concat().as_str());
@@ -0,0 +1,9 @@
+//! Contains functionality to read cordeau (multi depot and periodic) problem and write its solution.
+
+mod reader;
+pub use self::reader::CordeauProblem;
+
+mod visit_pattern;
+
+mod writer;
+pub use self::writer::CordeauSolution;
@@ -0,0 +1,385 @@
+#[cfg(test)]
+#[path = "../../tests/unit/cordeau/reader_test.rs"]
+mod reader_test;
+
+use super::visit_pattern::*;
+use crate::common::*;
+use crate::utils::{create_euclidean_matrix, create_matrix_transport};
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::{Extras, Problem};
+use vrp_core::solver::objectives::{TotalTransportCost, TotalUnassignedJobs};
+
+/// A day length used for periodic problems when neither route duration nor depot time window is limited.
+const DEFAULT_DAY_LENGTH: f64 = 1_000_000.;
+
+/// A constraint code of visit pattern module.
+const VISIT_PATTERN_CONSTRAINT_CODE: i32 = 5;
+
+/// A trait to read cordeau problem.
+pub trait CordeauProblem {
+    /// Reads cordeau problem.
+    fn read_cordeau(self) -> Result<Problem, String>;
+}
+
+impl<R: Read> CordeauProblem for BufReader<R> {
+    fn read_cordeau(self) -> Result<Problem, String> {
+        CordeauReader { buffer: String::new(), reader: self, specification: None }.read_problem()
+    }
+}
+
+impl CordeauProblem for String {
+    fn read_cordeau(self) -> Result<Problem, String> {
+        BufReader::new(self.as_bytes()).read_cordeau()
+    }
+}
+
+/// Specifies a problem family.
+#[derive(Clone, Copy, PartialEq)]
+enum ProblemKind {
+    /// Multi-depot problem: each depot has its own vehicles.
+    MultiDepot,
+    /// Periodic problem: customers are visited several times during planning horizon.
+    Periodic,
+}
+
+/// Keeps customer or depot data.
+struct LocationLine {
+    id: usize,
+    coordinate: (f64, f64),
+    service: f64,
+    demand: i32,
+    frequency: usize,
+    combinations: Vec<usize>,
+    tw: Option<TimeWindow>,
+}
+
+/// Keeps route duration and vehicle capacity limits of a depot or a day.
+struct LimitLine {
+    duration: f64,
+    capacity: usize,
+}
+
+/// Keeps problem data read from cordeau file.
+struct Specification {
+    kind: ProblemKind,
+    vehicles: usize,
+    limits: Vec<LimitLine>,
+    customers: Vec<LocationLine>,
+    depots: Vec<LocationLine>,
+}
+
+struct CordeauReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    specification: Option<Specification>,
+}
+
+impl<R: Read> TextReader for CordeauReader<R> {
+    fn read_fleet(&mut self) -> Result<Fleet, String> {
+        self.read_specification()?;
+
+        let specification = self.specification.as_ref().unwrap();
+        let depot_location = |depot_idx: usize| specification.customers.len() + depot_idx;
+
+        let vehicles = match specification.kind {
+            ProblemKind::MultiDepot => specification
+                .depots
+                .iter()
+                .zip(specification.limits.iter())
+                .enumerate()
+                .flat_map(|(depot_idx, (depot, limit))| {
+                    (0..specification.vehicles).map(move |vehicle_idx| {
+                        let detail =
+                            create_vehicle_detail(depot_location(depot_idx), depot, limit, TimeWindow::max(), 0.);
+                        let mut vehicle = create_vehicle_with_distance_costs(
+                            depot_idx * specification.vehicles + vehicle_idx,
+                            limit.capacity,
+                            vec![detail],
+                        );
+                        vehicle.dimens.set_value("depot", depot_idx);
+
+                        vehicle
+                    })
+                })
+                .collect(),
+            ProblemKind::Periodic => {
+                let depot = specification.depots.first().unwrap();
+                let capacity = specification.limits.first().map_or(0, |limit| limit.capacity);
+                if specification.limits.iter().any(|limit| limit.capacity != capacity) {
+                    return Err("different vehicle capacities per day are not supported".to_string());
+                }
+
+                let day_length = specification.day_length();
+
+                (0..specification.vehicles)
+                    .map(|vehicle_idx| {
+                        let details = specification
+                            .limits
+                            .iter()
+                            .enumerate()
+                            .map(|(day, limit)| {
+                                let default_tw = TimeWindow::new(0., day_length);
+                                create_vehicle_detail(
+                                    depot_location(0),
+                                    depot,
+                                    limit,
+                                    default_tw,
+                                    day as f64 * day_length,
+                                )
+                            })
+                            .collect();
+
+                        create_vehicle_with_distance_costs(vehicle_idx, capacity, details)
+                    })
+                    .collect()
+            }
+        };
+
+        Ok(create_fleet_with_vehicles(vehicles))
+    }
+
+    fn read_jobs(&mut self) -> Result<Vec<Job>, String> {
+        let specification = self.specification.as_ref().unwrap();
+        let day_length = specification.day_length();
+        let days = specification.limits.len();
+
+        specification.customers.iter().enumerate().try_fold(vec![], |mut acc, (location, customer)| {
+            let create_single = |times: Vec<TimeWindow>| {
+                let mut dimens = create_dimens_with_id("", customer.id);
+                dimens.set_demand(Demand::<SingleDimLoad> {
+                    pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+                    delivery: (SingleDimLoad::new(customer.demand), SingleDimLoad::default()),
+                });
+
+                Single {
+                    places: vec![Place {
+                        location: Some(location),
+                        duration: customer.service,
+                        times: times.into_iter().map(TimeSpan::Window).collect(),
+                    }],
+                    dimens,
+                }
+            };
+
+            match specification.kind {
+                ProblemKind::MultiDepot => {
+                    let tw = customer.tw.clone().unwrap_or_else(TimeWindow::max);
+                    acc.push(Job::Single(Arc::new(create_single(vec![tw]))));
+                }
+                ProblemKind::Periodic => {
+                    let combinations = customer
+                        .combinations
+                        .iter()
+                        .map(|&combination| decode_combination(combination, days))
+                        .filter(|combination| combination.len() >= customer.frequency)
+                        .collect::<Vec<_>>();
+
+                    if combinations.is_empty() {
+                        return Err(format!("customer {} has no valid visit combination", customer.id));
+                    }
+
+                    let mut visit_days = combinations.iter().flatten().cloned().collect::<Vec<_>>();
+                    visit_days.sort_unstable();
+                    visit_days.dedup();
+
+                    let tw = customer.tw.clone().unwrap_or_else(|| TimeWindow::new(0., day_length));
+                    let times = visit_days
+                        .iter()
+                        .map(|&day| {
+                            let offset = day as f64 * day_length;
+                            TimeWindow::new(offset + tw.start, offset + tw.end)
+                        })
+                        .collect::<Vec<_>>();
+                    let pattern = VisitPattern { customer: customer.id, combinations };
+
+                    acc.extend((0..customer.frequency).map(|_| {
+                        let mut single = create_single(times.clone());
+                        single.dimens.set_value(VISIT_PATTERN_KEY, pattern.clone());
+
+                        Job::Single(Arc::new(single))
+                    }));
+                }
+            }
+
+            Ok(acc)
+        })
+    }
+
+    fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        let specification = self.specification.as_ref().unwrap();
+        let coordinates = specification
+            .customers
+            .iter()
+            .chain(specification.depots.iter())
+            .map(|line| line.coordinate)
+            .collect::<Vec<_>>();
+
+        create_matrix_transport(create_euclidean_matrix(coordinates.as_slice(), false))
+    }
+
+    fn create_extras(&self) -> Extras {
+        Extras::default()
+    }
+
+    fn create_objective(&self) -> ObjectiveCost {
+        // NOTE cordeau instances are evaluated by total distance only
+        ObjectiveCost::new(
+            vec![Box::new(TotalUnassignedJobs::default())],
+            vec![Box::new(TotalTransportCost::default())],
+        )
+    }
+
+    fn create_constraint_pipeline(
+        &self,
+        activity: Arc<SimpleActivityCost>,
+        transport: Arc<dyn TransportCost + Send + Sync>,
+    ) -> ConstraintPipeline {
+        // NOTE fleet size is fixed in cordeau instances, so amount of used vehicles is not minimized
+        let mut constraint = ConstraintPipeline::default();
+
+        // NOTE visit pattern module goes first as it can remove jobs from routes which then
+        // have to be rescheduled by transport and capacity modules
+        if matches!(self.specification.as_ref(), Some(specification) if specification.kind == ProblemKind::Periodic) {
+            constraint.add_module(Box::new(VisitPatternModule::new(VISIT_PATTERN_CONSTRAINT_CODE)));
+        }
+
+        constraint.add_module(Box::new(TransportConstraintModule::new(
+            activity,
+            transport,
+            Arc::new(|_| (None, None)),
+            1,
+            2,
+            3,
+        )));
+        constraint.add_module(Box::new(CapacityConstraintModule::<SingleDimLoad>::new(4)));
+
+        constraint
+    }
+}
+
+impl<R: Read> CordeauReader<R> {
+    fn read_specification(&mut self) -> Result<(), String> {
+        let (problem_type, vehicles, customers, count) = match self.read_values::<usize>()?.as_slice() {
+            [problem_type, vehicles, customers, count] => (*problem_type, *vehicles, *customers, *count),
+            _ => return Err("cannot parse problem type line".to_string()),
+        };
+
+        let (kind, has_time_windows) = match problem_type {
+            1 => (ProblemKind::Periodic, false),
+            2 => (ProblemKind::MultiDepot, false),
+            5 => (ProblemKind::Periodic, true),
+            6 => (ProblemKind::MultiDepot, true),
+            _ => return Err(format!("unsupported problem type: {}", problem_type)),
+        };
+
+        let limits = (0..count)
+            .map(|_| match self.read_values::<f64>()?.as_slice() {
+                [duration, capacity] => Ok(LimitLine { duration: *duration, capacity: *capacity as usize }),
+                _ => Err("cannot parse route duration and vehicle capacity".to_string()),
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        let mut locations = vec![];
+        loop {
+            let values = self.read_values::<f64>()?;
+            if values.is_empty() {
+                break;
+            }
+
+            locations.push(parse_location_line(values.as_slice(), has_time_windows)?);
+        }
+
+        // NOTE in periodic instances, the depot is specified before customers, in multi depot - after
+        let depots = match kind {
+            ProblemKind::Periodic if locations.len() == customers + 1 => locations.drain(0..1).collect(),
+            ProblemKind::MultiDepot if locations.len() == customers + count => locations.split_off(customers),
+            _ => return Err(format!("unexpected amount of customers and depots: {}", locations.len())),
+        };
+
+        self.specification = Some(Specification { kind, vehicles, limits, customers: locations, depots });
+
+        Ok(())
+    }
+
+    /// Reads the next non empty line as a list of values, returns an empty list at the end of file.
+    fn read_values<T: std::str::FromStr>(&mut self) -> Result<Vec<T>, String> {
+        loop {
+            if read_line(&mut self.reader, &mut self.buffer)? == 0 {
+                return Ok(vec![]);
+            }
+
+            if !self.buffer.trim().is_empty() {
+                return self
+                    .buffer
+                    .split_whitespace()
+                    .map(|value| value.parse::<T>().map_err(|_| format!("cannot parse value: '{}'", value)))
+                    .collect();
+            }
+        }
+    }
+}
+
+impl Specification {
+    /// Returns a length of one day in planning horizon.
+    fn day_length(&self) -> f64 {
+        let depot_end = self.depots.iter().filter_map(|depot| depot.tw.as_ref()).map(|tw| tw.end).fold(0., f64::max);
+        let max_duration = self.limits.iter().map(|limit| limit.duration).fold(0., f64::max);
+        let day_length = depot_end.max(max_duration);
+
+        if day_length > 0. {
+            day_length
+        } else {
+            DEFAULT_DAY_LENGTH
+        }
+    }
+}
+
+fn parse_location_line(values: &[f64], has_time_windows: bool) -> Result<LocationLine, String> {
+    let get_value = |idx: usize| values.get(idx).cloned().ok_or_else(|| "cannot parse location line".to_string());
+
+    let combinations_size = get_value(6)? as usize;
+    let combinations =
+        (0..combinations_size).map(|idx| get_value(7 + idx).map(|c| c as usize)).collect::<Result<_, _>>()?;
+    let tw = if has_time_windows {
+        let tw_idx = 7 + combinations_size;
+        Some(TimeWindow::new(get_value(tw_idx)?, get_value(tw_idx + 1)?))
+    } else {
+        None
+    };
+
+    Ok(LocationLine {
+        id: get_value(0)? as usize,
+        coordinate: (get_value(1)?, get_value(2)?),
+        service: get_value(3)?,
+        demand: get_value(4)? as i32,
+        frequency: get_value(5)? as usize,
+        combinations,
+        tw,
+    })
+}
+
+/// Decodes visit combination: a binary representation of the number specifies visit days where
+/// the most significant bit corresponds to the first day.
+fn decode_combination(combination: usize, days: usize) -> Vec<usize> {
+    (0..days).filter(|day| combination & (1 << (days - 1 - day)) != 0).collect()
+}
+
+fn create_vehicle_detail(
+    location: Location,
+    depot: &LocationLine,
+    limit: &LimitLine,
+    default_tw: TimeWindow,
+    offset: f64,
+) -> VehicleDetail {
+    let tw = depot.tw.clone().unwrap_or(default_tw);
+    let latest = if limit.duration > 0. { tw.end.min(tw.start + limit.duration) } else { tw.end };
+
+    VehicleDetail {
+        start: Some(VehiclePlace { location, time: TimeInterval { earliest: Some(offset + tw.start), latest: None } }),
+        end: Some(VehiclePlace { location, time: TimeInterval { earliest: None, latest: Some(offset + latest) } }),
+    }
+}
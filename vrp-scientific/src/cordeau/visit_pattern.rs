@@ -0,0 +1,175 @@
+#[cfg(test)]
+#[path = "../../tests/unit/cordeau/visit_pattern_test.rs"]
+mod visit_pattern_test;
+
+use std::collections::{HashMap, HashSet};
+use std::slice::Iter;
+use std::sync::Arc;
+use vrp_core::construction::constraints::*;
+use vrp_core::construction::heuristics::{RouteContext, SolutionContext};
+use vrp_core::models::common::ValueDimension;
+use vrp_core::models::problem::{Actor, Job};
+
+/// A job dimension key for visit pattern.
+pub(crate) const VISIT_PATTERN_KEY: &str = "visit_pattern";
+
+/// A solution state key which keeps days of assigned customer visits.
+const VISIT_DAYS_KEY: i32 = 30;
+
+/// Specifies allowed visit days of a periodic customer. Each customer visit is represented by
+/// a separate job, so all visits of the same customer share the same pattern and they are either
+/// all assigned or all unassigned.
+#[derive(Clone)]
+pub(crate) struct VisitPattern {
+    /// Customer id.
+    pub customer: usize,
+    /// Alternative combinations of days, one of them has to be used for all customer visits.
+    pub combinations: Vec<Vec<usize>>,
+}
+
+impl VisitPattern {
+    /// Checks whether given visit days fit into at least one of the combinations.
+    fn is_allowed(&self, days: &[usize]) -> bool {
+        let mut sorted = days.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        sorted.len() == days.len()
+            && self.combinations.iter().any(|combination| sorted.iter().all(|day| combination.contains(day)))
+    }
+}
+
+/// A module which keeps visits of periodic customers consistent with one of their visit patterns:
+/// a vehicle shift is considered as a day.
+pub(crate) struct VisitPatternModule {
+    code: i32,
+    constraints: Vec<ConstraintVariant>,
+    keys: Vec<i32>,
+}
+
+impl VisitPatternModule {
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            constraints: vec![ConstraintVariant::HardRoute(Arc::new(VisitPatternHardRouteConstraint { code }))],
+            keys: vec![VISIT_DAYS_KEY],
+        }
+    }
+
+    /// Unassigns visits of customers which have at least one unassigned visit.
+    fn unassign_partial_visits(&self, ctx: &mut SolutionContext) {
+        let customers = ctx.unassigned.keys().filter_map(get_customer).collect::<HashSet<_>>();
+        if customers.is_empty() {
+            return;
+        }
+
+        let jobs = ctx
+            .routes
+            .iter()
+            .flat_map(|route_ctx| route_ctx.route.tour.jobs())
+            .filter(|job| matches!(get_customer(job), Some(customer) if customers.contains(&customer)))
+            .collect::<Vec<_>>();
+        if jobs.is_empty() {
+            return;
+        }
+
+        ctx.routes.iter_mut().for_each(|route_ctx| {
+            jobs.iter().for_each(|job| {
+                if route_ctx.route.tour.contains(job) {
+                    route_ctx.route_mut().tour.remove(job);
+                }
+            });
+        });
+
+        let registry = &mut ctx.registry;
+        ctx.routes.retain(|route_ctx| {
+            if route_ctx.route.tour.has_jobs() {
+                true
+            } else {
+                registry.free_route(route_ctx);
+                false
+            }
+        });
+
+        ctx.unassigned.extend(jobs.into_iter().map(|job| (job, self.code)));
+    }
+}
+
+impl ConstraintModule for VisitPatternModule {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, _route_index: usize, job: &Job) {
+        if job.dimens().get_value::<VisitPattern>(VISIT_PATTERN_KEY).is_some() {
+            self.accept_solution_state(solution_ctx);
+        }
+    }
+
+    fn accept_route_state(&self, _ctx: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, ctx: &mut SolutionContext) {
+        // NOTE partial visits are allowed only while there are jobs left to insert
+        if ctx.required.is_empty() {
+            self.unassign_partial_visits(ctx);
+        }
+
+        let visit_days = ctx.routes.iter().fold(HashMap::<usize, Vec<usize>>::new(), |mut acc, route_ctx| {
+            let day = get_day(&route_ctx.route.actor);
+
+            route_ctx
+                .route
+                .tour
+                .jobs()
+                .filter_map(|job| get_customer(&job))
+                .for_each(|customer| acc.entry(customer).or_insert_with(Vec::new).push(day));
+
+            acc
+        });
+
+        ctx.state.insert(VISIT_DAYS_KEY, Arc::new(visit_days));
+    }
+
+    fn state_keys(&self) -> Iter<i32> {
+        self.keys.iter()
+    }
+
+    fn get_constraints(&self) -> Iter<ConstraintVariant> {
+        self.constraints.iter()
+    }
+}
+
+struct VisitPatternHardRouteConstraint {
+    code: i32,
+}
+
+impl HardRouteConstraint for VisitPatternHardRouteConstraint {
+    fn evaluate_job(
+        &self,
+        solution_ctx: &SolutionContext,
+        ctx: &RouteContext,
+        job: &Job,
+    ) -> Option<RouteConstraintViolation> {
+        job.dimens().get_value::<VisitPattern>(VISIT_PATTERN_KEY).and_then(|pattern| {
+            let mut days = solution_ctx
+                .state
+                .get(&VISIT_DAYS_KEY)
+                .and_then(|state| state.downcast_ref::<HashMap<usize, Vec<usize>>>())
+                .and_then(|visit_days| visit_days.get(&pattern.customer))
+                .cloned()
+                .unwrap_or_default();
+            days.push(get_day(&ctx.route.actor));
+
+            if pattern.is_allowed(days.as_slice()) {
+                None
+            } else {
+                Some(RouteConstraintViolation { code: self.code })
+            }
+        })
+    }
+}
+
+fn get_customer(job: &Job) -> Option<usize> {
+    job.dimens().get_value::<VisitPattern>(VISIT_PATTERN_KEY).map(|pattern| pattern.customer)
+}
+
+/// Returns a day of actor's shift which is an index of its detail in vehicle details.
+pub(crate) fn get_day(actor: &Actor) -> usize {
+    actor.vehicle.details.iter().position(|detail| detail.start == actor.detail.start).unwrap_or(0)
+}
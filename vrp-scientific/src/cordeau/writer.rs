@@ -0,0 +1,70 @@
+#[cfg(test)]
+#[path = "../../tests/unit/cordeau/writer_test.rs"]
+mod writer_test;
+
+use super::visit_pattern::get_day;
+use crate::common::get_total_distance;
+use std::io::{BufWriter, Write};
+use vrp_core::models::common::{Demand, DemandDimension, IdDimension, SingleDimLoad, ValueDimension};
+use vrp_core::models::{Problem, Solution};
+
+/// A trait to write cordeau solution.
+pub trait CordeauSolution<W: Write> {
+    /// Writes cordeau solution: the first line contains total distance, then each route is written
+    /// on a separate line as `<depot or day> <vehicle> <duration> <load> 0 <customer ids> 0`.
+    fn write_cordeau(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+}
+
+impl<W: Write> CordeauSolution<W> for Solution {
+    fn write_cordeau(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let mut writer = writer;
+
+        if !self.unassigned.is_empty() {
+            return Err("cannot write cordeau solution with unassigned jobs".to_string());
+        }
+
+        let mut routes = self
+            .routes
+            .iter()
+            .map(|route| {
+                let vehicle = &route.actor.vehicle;
+                let group =
+                    vehicle.dimens.get_value::<usize>("depot").cloned().unwrap_or_else(|| get_day(&route.actor));
+
+                (group, route)
+            })
+            .collect::<Vec<_>>();
+        routes.sort_by_key(|(group, _)| *group);
+
+        writer
+            .write_all(format!("{:.2}\n", get_total_distance(problem, self)).as_bytes())
+            .map_err(|err| err.to_string())?;
+
+        routes.iter().enumerate().try_for_each(|(idx, (group, route))| {
+            let vehicle = routes.iter().take(idx).filter(|(other, _)| other == group).count() + 1;
+
+            let start = route.tour.start().map_or(0., |activity| activity.schedule.departure);
+            let end = route.tour.end().map_or(start, |activity| activity.schedule.arrival);
+
+            let singles = route.tour.all_activities().filter_map(|activity| activity.job.as_ref()).collect::<Vec<_>>();
+            let load = singles
+                .iter()
+                .filter_map(|single| single.dimens.get_demand())
+                .map(|demand: &Demand<SingleDimLoad>| demand.delivery.0.value)
+                .sum::<i32>();
+            let customers = singles
+                .iter()
+                .map(|single| single.dimens.get_id().cloned().ok_or_else(|| "cannot get job id".to_string()))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(" ");
+
+            writer
+                .write_all(
+                    format!("{} {} {:.2} {} 0 {} 0\n", group + 1, vehicle, end - start, load, customers).as_bytes(),
+                )
+                .map_err(|err| err.to_string())
+        })?;
+
+        Ok(())
+    }
+}
@@ -6,6 +6,10 @@
 //!
 //! - **solomon**: see [Solomon benchmark](https://www.sintef.no/projectweb/top/vrptw/solomon-benchmark)
 //! - **lilim**: see [Li&Lim benchmark](https://www.sintef.no/projectweb/top/pdptw/li-lim-benchmark)
+//! - **cordeau**: see [Cordeau instances](http://neumann.hec.ca/chairedistributique/data/), only multi depot
+//!   and periodic problems (with or without time windows) are supported
+//! - **tsplib**: see [CVRPLIB](http://vrp.galgos.inf.puc-rio.br/index.php/en/), only capacitated
+//!   problems with single depot are supported
 
 #![warn(missing_docs)]
 
@@ -21,6 +25,8 @@ mod known_problems_test;
 pub use vrp_core as core;
 
 pub mod common;
+pub mod cordeau;
 pub mod lilim;
 pub mod solomon;
+pub mod tsplib;
 mod utils;
@@ -123,7 +123,7 @@ impl<R: Read> LilimReader<R> {
         } else {
             Demand::<SingleDimLoad> {
                 pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
-                delivery: (SingleDimLoad::default(), SingleDimLoad::new(customer.demand as i32)),
+                delivery: (SingleDimLoad::default(), SingleDimLoad::new(-customer.demand as i32)),
             }
         });
 
@@ -133,7 +133,7 @@ impl<R: Read> LilimReader<R> {
                 duration: customer.service as f64,
                 times: vec![TimeSpan::Window(customer.tw.clone())],
             }],
-            dimens: Default::default(),
+            dimens,
         })
     }
 
@@ -0,0 +1,15 @@
+use crate::common::read_text_init_solution;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::utils::Random;
+
+/// Reads initial solution in CVRPLIB format from a buffer.
+/// NOTE: Solution feasibility is not checked.
+pub fn read_init_solution<R: Read>(
+    reader: BufReader<R>,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random + Send + Sync>,
+) -> Result<Solution, String> {
+    read_text_init_solution(reader, problem, random, "")
+}
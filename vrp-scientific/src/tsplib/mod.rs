@@ -0,0 +1,10 @@
+//! Contains functionality to read tsplib (CVRPLIB) problem and write its solution.
+
+mod initial_reader;
+pub use self::initial_reader::read_init_solution;
+
+mod reader;
+pub use self::reader::TsplibProblem;
+
+mod writer;
+pub use self::writer::TsplibSolution;
@@ -0,0 +1,271 @@
+#[cfg(test)]
+#[path = "../../tests/unit/tsplib/reader_test.rs"]
+mod reader_test;
+
+use crate::common::*;
+use crate::utils::{create_euclidean_matrix, create_matrix_transport};
+use std::collections::HashMap;
+use std::io::{BufReader, Read};
+use std::sync::Arc;
+use vrp_core::models::common::*;
+use vrp_core::models::problem::*;
+use vrp_core::models::{Extras, Problem};
+use vrp_core::solver::objectives::{TotalTransportCost, TotalUnassignedJobs};
+
+/// A trait to read tsplib problem.
+pub trait TsplibProblem {
+    /// Reads tsplib problem.
+    fn read_tsplib(self) -> Result<Problem, String>;
+}
+
+impl<R: Read> TsplibProblem for BufReader<R> {
+    fn read_tsplib(self) -> Result<Problem, String> {
+        TsplibReader { buffer: String::new(), reader: self, specification: None }.read_problem()
+    }
+}
+
+impl TsplibProblem for String {
+    fn read_tsplib(self) -> Result<Problem, String> {
+        BufReader::new(self.as_bytes()).read_tsplib()
+    }
+}
+
+/// Keeps problem data read from tsplib file.
+struct Specification {
+    header: HashMap<String, String>,
+    coordinates: Vec<(f64, f64)>,
+    weights: Vec<f64>,
+    demands: Vec<i32>,
+    depots: Vec<usize>,
+}
+
+struct TsplibReader<R: Read> {
+    buffer: String,
+    reader: BufReader<R>,
+    specification: Option<Specification>,
+}
+
+impl<R: Read> TextReader for TsplibReader<R> {
+    fn read_fleet(&mut self) -> Result<Fleet, String> {
+        self.read_specification()?;
+
+        let specification = self.specification.as_ref().unwrap();
+        let dimension = specification.dimension()?;
+
+        let depot = specification.depot()?;
+        let capacity = specification.get_header_value::<usize>("CAPACITY")?;
+        let vehicles = specification
+            .header
+            .get("VEHICLES")
+            .map(|vehicles| vehicles.parse::<usize>().map_err(|_| "cannot parse VEHICLES".to_string()))
+            .unwrap_or_else(|| Ok(dimension - 1))?;
+
+        Ok(create_fleet_with_distance_costs(vehicles, capacity, depot, TimeWindow::max()))
+    }
+
+    fn read_jobs(&mut self) -> Result<Vec<Job>, String> {
+        let specification = self.specification.as_ref().unwrap();
+        let dimension = specification.dimension()?;
+        let depot = specification.depot()?;
+
+        if specification.demands.len() != dimension {
+            return Err(format!("expected {} demands, got {}", dimension, specification.demands.len()));
+        }
+
+        Ok((0..dimension)
+            .filter(|&idx| idx != depot)
+            .map(|idx| {
+                // NOTE CVRPLIB solutions use node index as customer id, so the first node has zero id
+                let mut dimens = create_dimens_with_id("", idx);
+                dimens.set_demand(Demand::<SingleDimLoad> {
+                    pickup: (SingleDimLoad::default(), SingleDimLoad::default()),
+                    delivery: (SingleDimLoad::new(specification.demands[idx]), SingleDimLoad::default()),
+                });
+
+                Job::Single(Arc::new(Single {
+                    places: vec![Place {
+                        location: Some(idx),
+                        duration: 0.,
+                        times: vec![TimeSpan::Window(TimeWindow::max())],
+                    }],
+                    dimens,
+                }))
+            })
+            .collect())
+    }
+
+    fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+        let specification = self.specification.as_ref().unwrap();
+        let dimension = specification.dimension()?;
+
+        let edge_weight_type = specification.get_header_value::<String>("EDGE_WEIGHT_TYPE")?;
+        let matrix_values = match edge_weight_type.as_str() {
+            "EUC_2D" => {
+                if specification.coordinates.len() != dimension {
+                    return Err(format!(
+                        "expected {} node coordinates, got {}",
+                        dimension,
+                        specification.coordinates.len()
+                    ));
+                }
+
+                create_euclidean_matrix(specification.coordinates.as_slice(), true)
+            }
+            "EXPLICIT" => {
+                let format = specification.get_header_value::<String>("EDGE_WEIGHT_FORMAT")?;
+                create_explicit_matrix(format.as_str(), specification.weights.as_slice(), dimension)?
+            }
+            _ => return Err(format!("unsupported edge weight type: '{}'", edge_weight_type)),
+        };
+
+        create_matrix_transport(matrix_values)
+    }
+
+    fn create_extras(&self) -> Extras {
+        Extras::default()
+    }
+
+    fn create_objective(&self) -> ObjectiveCost {
+        // NOTE CVRPLIB instances are evaluated by total distance only
+        ObjectiveCost::new(
+            vec![Box::new(TotalUnassignedJobs::default())],
+            vec![Box::new(TotalTransportCost::default())],
+        )
+    }
+}
+
+impl<R: Read> TsplibReader<R> {
+    fn read_specification(&mut self) -> Result<(), String> {
+        let mut specification = Specification {
+            header: Default::default(),
+            coordinates: vec![],
+            weights: vec![],
+            demands: vec![],
+            depots: vec![],
+        };
+
+        let mut section: Option<String> = None;
+
+        loop {
+            let read = read_line(&mut self.reader, &mut self.buffer)?;
+            if read == 0 {
+                break;
+            }
+
+            let line = self.buffer.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if line == "EOF" {
+                break;
+            }
+
+            if line.ends_with("_SECTION") {
+                section = Some(line.to_string());
+                continue;
+            }
+
+            if line.contains(':') {
+                let mut key_value = line.splitn(2, ':');
+                let key = key_value.next().unwrap().trim().to_string();
+                let value = key_value.next().unwrap().trim().to_string();
+
+                section = None;
+                specification.header.insert(key, value);
+                continue;
+            }
+
+            let values = line.split_whitespace().collect::<Vec<_>>();
+
+            match section.as_deref() {
+                Some("NODE_COORD_SECTION") => {
+                    let (x, y) = match values.as_slice() {
+                        [_, x, y] => (parse_value::<f64>(x)?, parse_value::<f64>(y)?),
+                        _ => return Err(format!("cannot parse node coordinates: '{}'", line)),
+                    };
+                    specification.coordinates.push((x, y));
+                }
+                Some("DEMAND_SECTION") => {
+                    let demand = match values.as_slice() {
+                        [_, demand] => parse_value::<i32>(demand)?,
+                        _ => return Err(format!("cannot parse demand: '{}'", line)),
+                    };
+                    specification.demands.push(demand);
+                }
+                Some("DEPOT_SECTION") => {
+                    values.iter().try_for_each(|value| {
+                        let depot = parse_value::<i64>(value)?;
+                        if depot > 0 {
+                            specification.depots.push(depot as usize - 1);
+                        }
+                        Ok::<_, String>(())
+                    })?;
+                }
+                Some("EDGE_WEIGHT_SECTION") => {
+                    values.iter().try_for_each(|value| {
+                        specification.weights.push(parse_value::<f64>(value)?);
+                        Ok::<_, String>(())
+                    })?;
+                }
+                // NOTE other sections, such as DISPLAY_DATA_SECTION, are not needed
+                Some(_) => {}
+                None => return Err(format!("unexpected line: '{}'", line)),
+            }
+        }
+
+        self.specification = Some(specification);
+
+        Ok(())
+    }
+}
+
+impl Specification {
+    fn dimension(&self) -> Result<usize, String> {
+        self.get_header_value::<usize>("DIMENSION")
+    }
+
+    fn depot(&self) -> Result<usize, String> {
+        match self.depots.as_slice() {
+            [] => Ok(0),
+            [depot] => Ok(*depot),
+            _ => Err("multiple depots are not supported".to_string()),
+        }
+    }
+
+    fn get_header_value<T: std::str::FromStr>(&self, key: &str) -> Result<T, String> {
+        self.header.get(key).ok_or_else(|| format!("cannot find {}", key)).and_then(|value| parse_value(value))
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str) -> Result<T, String> {
+    value.parse::<T>().map_err(|_| format!("cannot parse value: '{}'", value))
+}
+
+/// Creates a full matrix from explicit edge weights of given format.
+fn create_explicit_matrix(format: &str, weights: &[f64], dimension: usize) -> Result<Vec<f64>, String> {
+    let indices: Box<dyn Iterator<Item = (usize, usize)>> = match format {
+        "FULL_MATRIX" => Box::new((0..dimension).flat_map(|i| (0..dimension).map(move |j| (i, j)))),
+        "UPPER_ROW" => Box::new((0..dimension).flat_map(|i| (i + 1..dimension).map(move |j| (i, j)))),
+        "UPPER_DIAG_ROW" => Box::new((0..dimension).flat_map(|i| (i..dimension).map(move |j| (i, j)))),
+        "LOWER_ROW" => Box::new((0..dimension).flat_map(|i| (0..i).map(move |j| (i, j)))),
+        "LOWER_DIAG_ROW" => Box::new((0..dimension).flat_map(|i| (0..=i).map(move |j| (i, j)))),
+        _ => return Err(format!("unsupported edge weight format: '{}'", format)),
+    };
+    let indices = indices.collect::<Vec<_>>();
+
+    if indices.len() != weights.len() {
+        return Err(format!("expected {} edge weights, got {}", indices.len(), weights.len()));
+    }
+
+    let is_symmetric = format != "FULL_MATRIX";
+    let mut matrix = vec![0.; dimension * dimension];
+    indices.into_iter().zip(weights.iter()).for_each(|((i, j), &weight)| {
+        matrix[i * dimension + j] = weight;
+        if is_symmetric {
+            matrix[j * dimension + i] = weight;
+        }
+    });
+
+    Ok(matrix)
+}
@@ -0,0 +1,42 @@
+#[cfg(test)]
+#[path = "../../tests/unit/tsplib/writer_test.rs"]
+mod writer_test;
+
+use crate::common::get_total_distance;
+use std::io::{BufWriter, Write};
+use vrp_core::models::common::IdDimension;
+use vrp_core::models::{Problem, Solution};
+
+/// A trait to write tsplib solution in CVRPLIB format.
+pub trait TsplibSolution<W: Write> {
+    /// Writes tsplib solution.
+    fn write_tsplib(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String>;
+}
+
+impl<W: Write> TsplibSolution<W> for Solution {
+    fn write_tsplib(&self, problem: &Problem, writer: BufWriter<W>) -> Result<(), String> {
+        let mut writer = writer;
+
+        if !self.unassigned.is_empty() {
+            return Err("cannot write tsplib solution with unassigned jobs".to_string());
+        }
+
+        self.routes.iter().zip(1..).try_for_each(|(route, idx)| {
+            let customers = route
+                .tour
+                .all_activities()
+                .filter_map(|activity| activity.retrieve_job())
+                .map(|job| job.dimens().get_id().cloned().ok_or_else(|| "cannot get job id".to_string()))
+                .collect::<Result<Vec<_>, _>>()?
+                .join(" ");
+
+            writer.write_all(format!("Route #{}: {}\n", idx, customers).as_bytes()).map_err(|err| err.to_string())
+        })?;
+
+        writer
+            .write_all(format!("Cost {}\n", get_total_distance(problem, self)).as_bytes())
+            .map_err(|err| err.to_string())?;
+
+        Ok(())
+    }
+}
@@ -1,2 +1,2 @@
 mod routing;
-pub(crate) use self::routing::CoordIndex;
+pub(crate) use self::routing::*;
@@ -25,20 +25,35 @@ impl CoordIndex {
     }
 
     pub fn create_transport(&self) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
-        let matrix_values = self
-            .locations
-            .iter()
-            .flat_map(|&(x1, y1)| {
-                self.locations.iter().map(move |&(x2, y2)| {
-                    let x = x1 as f64 - x2 as f64;
-                    let y = y1 as f64 - y2 as f64;
-                    (x * x + y * y).sqrt()
-                })
+        let coordinates = self.locations.iter().map(|&(x, y)| (x as f64, y as f64)).collect::<Vec<_>>();
+
+        create_matrix_transport(create_euclidean_matrix(coordinates.as_slice(), false))
+    }
+}
+
+/// Creates a full matrix with euclidean distances between given coordinates.
+pub(crate) fn create_euclidean_matrix(coordinates: &[(f64, f64)], is_rounded: bool) -> Vec<f64> {
+    coordinates
+        .iter()
+        .flat_map(|&(x1, y1)| {
+            coordinates.iter().map(move |&(x2, y2)| {
+                let x = x1 - x2;
+                let y = y1 - y2;
+                let distance = (x * x + y * y).sqrt();
+
+                if is_rounded {
+                    distance.round()
+                } else {
+                    distance
+                }
             })
-            .collect::<Vec<f64>>();
+        })
+        .collect()
+}
 
-        let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
+/// Creates transport costs from a full matrix which is used for both distances and durations.
+pub(crate) fn create_matrix_transport(matrix_values: Vec<f64>) -> Result<Arc<dyn TransportCost + Send + Sync>, String> {
+    let matrix_data = MatrixData::new(0, None, matrix_values.clone(), matrix_values);
 
-        create_matrix_transport_cost(vec![matrix_data])
-    }
+    create_matrix_transport_cost(vec![matrix_data])
 }
@@ -1,15 +1,17 @@
+use crate::common::BestKnownGap;
 use crate::core::construction::heuristics::*;
 use crate::core::models::common::IdDimension;
 use crate::core::models::problem::Job;
 use crate::helpers::*;
+use std::io::BufReader;
 use std::sync::Arc;
 use vrp_core::algorithms::nsga2::Objective;
 use vrp_core::construction::heuristics::InsertionContext;
-use vrp_core::models::Problem;
+use vrp_core::models::{Problem, Solution};
 use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
 use vrp_core::solver::population::Elitism;
-use vrp_core::solver::RefinementContext;
-use vrp_core::utils::DefaultRandom;
+use vrp_core::solver::{Builder, RefinementContext};
+use vrp_core::utils::{DefaultRandom, Random};
 
 struct StableJobSelector {}
 
@@ -95,3 +97,60 @@ fn can_solve_problem_with_cheapest_insertion_heuristic_impl(
     assert_eq!(result_cost.round(), cost.round());
     assert_eq!(get_customer_ids_from_routes_sorted(&insertion_ctx), expected);
 }
+
+/// A function type which reads best known solution for given problem.
+type BestKnownReader =
+    fn(BufReader<std::fs::File>, Arc<Problem>, Arc<dyn Random + Send + Sync>) -> Result<Solution, String>;
+
+/// Solves the problem with limited amount of generations and compares result with best known solution.
+fn run_benchmark(
+    problem: Arc<Problem>,
+    best_known_path: &str,
+    reader: BestKnownReader,
+    generations: usize,
+) -> BestKnownGap {
+    let best_known = reader(
+        BufReader::new(get_test_resource(best_known_path).unwrap()),
+        problem.clone(),
+        Arc::new(DefaultRandom::default()),
+    )
+    .unwrap();
+
+    let (solution, _, _) = Builder::new(problem.clone())
+        .with_max_generations(Some(generations))
+        .build()
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    BestKnownGap::new(problem.as_ref(), &solution, &best_known)
+}
+
+parameterized_test! {can_compare_solution_with_best_known, (problem, best_known_path, reader, generations, expected), {
+    can_compare_solution_with_best_known_impl(Arc::new(problem), best_known_path, reader, generations, expected);
+}}
+
+can_compare_solution_with_best_known! {
+    case1: (
+        create_c101_100_problem(),
+        "../../examples/data/scientific/solomon/C101.100.best.txt",
+        crate::solomon::read_init_solution,
+        20,
+        (10, 828.94)
+    ),
+}
+
+fn can_compare_solution_with_best_known_impl(
+    problem: Arc<Problem>,
+    best_known_path: &str,
+    reader: BestKnownReader,
+    generations: usize,
+    expected: (usize, f64),
+) {
+    let gap = run_benchmark(problem, best_known_path, reader, generations);
+
+    assert_eq!(gap.tours.1, expected.0);
+    assert!((gap.distance.1 - expected.1).abs() < 0.01, "{}", gap);
+    assert!(gap.tours.0 > 0, "{}", gap);
+    assert!(gap.distance.0 > 0., "{}", gap);
+    assert_eq!(gap.tours_gap(), gap.tours.0 as i64 - gap.tours.1 as i64);
+}
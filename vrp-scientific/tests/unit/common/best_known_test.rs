@@ -0,0 +1,34 @@
+use super::*;
+use crate::helpers::*;
+use crate::solomon::{read_init_solution, SolomonProblem};
+use std::io::BufReader;
+use std::sync::Arc;
+use vrp_core::utils::DefaultRandom;
+
+fn read_solution(problem: Arc<Problem>, content: &str) -> Solution {
+    read_init_solution(BufReader::new(content.as_bytes()), problem, Arc::new(DefaultRandom::default())).unwrap()
+}
+
+#[test]
+fn can_calculate_gap_with_best_known_solution() {
+    let problem = Arc::new(
+        SolomonBuilder::new()
+            .set_vehicle((2, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 3, 0, 1, 0, 1000, 0))
+            .add_customer((2, 6, 0, 1, 0, 1000, 0))
+            .build()
+            .read_solomon()
+            .unwrap(),
+    );
+    let solution = read_solution(problem.clone(), "Route 1: 1\nRoute 2: 2\n");
+    let best_known = read_solution(problem.clone(), "Route 1: 1 2\n");
+
+    let gap = BestKnownGap::new(problem.as_ref(), &solution, &best_known);
+
+    assert_eq!(gap.tours, (2, 1));
+    assert_eq!(gap.distance, (18., 12.));
+    assert_eq!(gap.tours_gap(), 1);
+    assert_eq!(gap.distance_gap(), 50.);
+    assert_eq!(gap.to_string(), "tours: 2 (best known: 1, gap: 1), distance: 18.00 (best known: 12.00, gap: 50.00%)");
+}
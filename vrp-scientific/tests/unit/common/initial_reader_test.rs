@@ -0,0 +1,60 @@
+use super::*;
+use crate::helpers::*;
+use crate::solomon::SolomonProblem;
+use vrp_core::utils::DefaultRandom;
+
+fn create_problem() -> Arc<Problem> {
+    Arc::new(
+        SolomonBuilder::new()
+            .set_vehicle((2, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 1))
+            .add_customer((1, 1, 0, 1, 0, 1000, 0))
+            .add_customer((2, 2, 0, 1, 0, 1000, 0))
+            .add_customer((3, 3, 0, 1, 0, 1000, 0))
+            .build()
+            .read_solomon()
+            .unwrap(),
+    )
+}
+
+fn read_solution(problem: Arc<Problem>, content: &str) -> Result<Solution, String> {
+    read_text_init_solution(BufReader::new(content.as_bytes()), problem, Arc::new(DefaultRandom::default()), "")
+}
+
+#[test]
+fn can_read_solution_with_header_and_unassigned_jobs() {
+    let problem = create_problem();
+    let content = "Instance name : test\nAuthors : unknown\nSolution\nRoute 1 : 2 1\nRoute #2:\n";
+
+    let solution = read_solution(problem, content).unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert_eq!(
+        solution.routes[0]
+            .tour
+            .all_activities()
+            .filter_map(|activity| activity.retrieve_job())
+            .map(|job| get_customer_id(&job))
+            .collect::<Vec<_>>(),
+        vec!["2", "1"]
+    );
+    assert_eq!(solution.unassigned.iter().map(|(job, _)| get_customer_id(job)).collect::<Vec<_>>(), vec!["3"]);
+}
+
+#[test]
+fn can_return_error_for_unknown_customer() {
+    let problem = create_problem();
+
+    let result = read_solution(problem, "Route 1: 1 4\n");
+
+    assert_eq!(result.err(), Some("cannot find customer with id: '4'".to_string()));
+}
+
+#[test]
+fn can_return_error_when_vehicles_are_not_enough() {
+    let problem = create_problem();
+
+    let result = read_solution(problem, "Route 1: 1\nRoute 2: 2\nRoute 3: 3\n");
+
+    assert_eq!(result.err(), Some("not enough vehicles".to_string()));
+}
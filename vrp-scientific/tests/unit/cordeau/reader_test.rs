@@ -0,0 +1,119 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_multi_depot_problem_content() -> String {
+    r#"2 2 4 2
+0 50
+100 40
+1 10 0 1 10 1 2 1 2
+2 20 0 1 15 1 2 1 2
+3 -10 0 1 5 1 2 1 2
+4 -20 0 1 20 1 2 1 2
+5 0 0 0 0 0 0
+6 30 40 0 0 0 0
+"#
+    .to_string()
+}
+
+fn create_periodic_problem_content() -> String {
+    r#"5 1 2 3
+0 100
+0 100
+0 100
+0 0 0 0 0 0 0 0 500
+1 10 0 5 10 2 2 3 6 10 100
+2 -10 0 5 15 1 3 1 2 4 0 500
+"#
+    .to_string()
+}
+
+fn get_vehicle_details(problem: &Problem) -> Vec<Vec<(Location, f64, f64)>> {
+    problem
+        .fleet
+        .vehicles
+        .iter()
+        .map(|vehicle| {
+            vehicle
+                .details
+                .iter()
+                .map(|detail| {
+                    let start = detail.start.as_ref().unwrap();
+                    let end = detail.end.as_ref().unwrap();
+                    (start.location, start.time.earliest.unwrap(), end.time.latest.unwrap())
+                })
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn can_read_multi_depot_problem() {
+    let problem = create_multi_depot_problem_content().read_cordeau().unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["1", "2", "3", "4"]);
+    assert_eq!(get_job_demands(&problem), vec![10, 15, 5, 20]);
+    assert_eq!(get_job_durations(&problem), vec![1., 1., 1., 1.]);
+    assert_eq!(
+        problem
+            .fleet
+            .vehicles
+            .iter()
+            .map(|vehicle| (
+                *vehicle.dimens.get_value::<usize>("depot").unwrap(),
+                vehicle.dimens.get_capacity().map(|capacity: &SingleDimLoad| capacity.value).unwrap()
+            ))
+            .collect::<Vec<_>>(),
+        vec![(0, 50), (0, 50), (1, 40), (1, 40)]
+    );
+    assert_eq!(
+        get_vehicle_details(&problem),
+        vec![vec![(4, 0., std::f64::MAX)], vec![(4, 0., std::f64::MAX)], vec![(5, 0., 100.)], vec![(5, 0., 100.)]]
+    );
+    assert_eq!(problem.transport.distance(0, 4, 5, 0.), 50.);
+    assert_eq!(problem.transport.distance(0, 0, 3, 0.), 30.);
+}
+
+#[test]
+fn can_read_periodic_problem() {
+    let problem = create_periodic_problem_content().read_cordeau().unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["1", "1", "2"]);
+    assert_eq!(get_job_demands(&problem), vec![10, 10, 15]);
+    assert_eq!(get_job_time_windows(&problem), vec![(10., 100.), (10., 100.), (0., 500.)]);
+    assert_eq!(problem.jobs.all().map(|job| job.to_single().places[0].times.len()).collect::<Vec<_>>(), vec![3, 3, 3]);
+    assert_eq!(
+        problem
+            .jobs
+            .all()
+            .map(|job| job.dimens().get_value::<VisitPattern>(VISIT_PATTERN_KEY).unwrap().combinations.clone())
+            .collect::<Vec<_>>(),
+        vec![vec![vec![1, 2], vec![0, 1]], vec![vec![1, 2], vec![0, 1]], vec![vec![2], vec![1], vec![0]]]
+    );
+    assert_eq!(get_vehicle_details(&problem), vec![vec![(2, 0., 500.), (2, 500., 1000.), (2, 1000., 1500.)]]);
+}
+
+parameterized_test! {can_decode_combination, (combination, days, expected), {
+    assert_eq!(decode_combination(combination, days), expected);
+}}
+
+can_decode_combination! {
+    case01: (1, 2, vec![1]),
+    case02: (2, 2, vec![0]),
+    case03: (3, 2, vec![0, 1]),
+    case04: (5, 3, vec![0, 2]),
+    case05: (10, 5, vec![1, 3]),
+}
+
+#[test]
+fn can_return_error_for_unsupported_type() {
+    let result = create_multi_depot_problem_content().replacen("2 2 4 2", "3 2 4 2", 1).read_cordeau();
+
+    assert_eq!(result.err(), Some("unsupported problem type: 3".to_string()));
+}
+
+#[test]
+fn can_return_error_for_missing_depot() {
+    let result = create_multi_depot_problem_content().replace("6 30 40 0 0 0 0\n", "").read_cordeau();
+
+    assert_eq!(result.err(), Some("unexpected amount of customers and depots: 5".to_string()));
+}
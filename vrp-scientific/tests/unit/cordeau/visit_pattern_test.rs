@@ -0,0 +1,107 @@
+use super::*;
+use crate::cordeau::CordeauProblem;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::population::Elitism;
+use vrp_core::solver::RefinementContext;
+use vrp_core::utils::DefaultRandom;
+
+parameterized_test! {can_check_visit_days, (days, expected), {
+    let pattern = VisitPattern { customer: 1, combinations: vec![vec![0, 2], vec![1, 3]] };
+
+    assert_eq!(pattern.is_allowed(days.as_slice()), expected);
+}}
+
+can_check_visit_days! {
+    case01: (vec![0], true),
+    case02: (vec![2, 0], true),
+    case03: (vec![3], true),
+    case04: (vec![0, 1], false),
+    case05: (vec![0, 0], false),
+    case06: (vec![0, 2, 1], false),
+}
+
+#[test]
+fn can_assign_visits_using_one_pattern() {
+    let random = Arc::new(DefaultRandom::default());
+    let problem = Arc::new(
+        r#"1 2 3 4
+0 100
+0 100
+0 100
+0 100
+0 0 0 0 0 0 0
+1 10 0 0 1 2 2 10 5
+2 -10 0 0 1 2 1 12
+3 0 10 0 1 1 4 8 4 2 1
+"#
+        .to_string()
+        .read_cordeau()
+        .unwrap(),
+    );
+    let mut refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(Elitism::new(problem.clone(), random.clone(), 1, 1)), None);
+
+    let insertion_ctx =
+        RecreateWithCheapest::default().run(&mut refinement_ctx, InsertionContext::new(problem, random));
+
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+    let mut visits = insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .flat_map(|route_ctx| {
+            let day = get_day(&route_ctx.route.actor);
+            route_ctx
+                .route
+                .tour
+                .jobs()
+                .map(move |job| (job.dimens().get_value::<VisitPattern>(VISIT_PATTERN_KEY).unwrap().customer, day))
+        })
+        .collect::<Vec<_>>();
+    visits.sort();
+
+    let days_of = |customer: usize| {
+        visits.iter().filter(|(other, _)| *other == customer).map(|(_, day)| *day).collect::<Vec<_>>()
+    };
+    assert!(days_of(1) == vec![0, 2] || days_of(1) == vec![1, 3]);
+    assert_eq!(days_of(2), vec![0, 1]);
+    assert_eq!(days_of(3).len(), 1);
+}
+
+#[test]
+fn can_unassign_all_customer_visits_when_one_visit_is_unassignable() {
+    let random = Arc::new(DefaultRandom::default());
+    let problem = Arc::new(
+        r#"1 1 2 2
+100 100
+5 100
+0 0 0 0 0 0 0
+1 10 0 0 10 2 1 3
+2 -10 0 0 10 1 1 2
+"#
+        .to_string()
+        .read_cordeau()
+        .unwrap(),
+    );
+    let mut refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(Elitism::new(problem.clone(), random.clone(), 1, 1)), None);
+
+    let insertion_ctx =
+        RecreateWithCheapest::default().run(&mut refinement_ctx, InsertionContext::new(problem, random));
+
+    let get_customers = |jobs: Vec<Job>| {
+        let mut customers = jobs
+            .iter()
+            .map(|job| job.dimens().get_value::<VisitPattern>(VISIT_PATTERN_KEY).unwrap().customer)
+            .collect::<Vec<_>>();
+        customers.sort();
+        customers
+    };
+    let solution = &insertion_ctx.solution;
+    assert_eq!(get_customers(solution.unassigned.keys().cloned().collect()), vec![1, 1]);
+    assert_eq!(
+        get_customers(solution.routes.iter().flat_map(|route_ctx| route_ctx.route.tour.jobs()).collect()),
+        vec![2]
+    );
+}
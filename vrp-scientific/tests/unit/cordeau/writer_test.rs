@@ -0,0 +1,40 @@
+use super::*;
+use crate::cordeau::CordeauProblem;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::population::Elitism;
+use vrp_core::solver::RefinementContext;
+use vrp_core::utils::DefaultRandom;
+
+#[test]
+fn can_write_cordeau_solution() {
+    let random = Arc::new(DefaultRandom::default());
+    let problem = Arc::new(
+        r#"2 1 2 2
+0 50
+0 50
+1 10 0 0 10 1 2 1 2
+2 90 0 0 5 1 2 1 2
+3 0 0 0 0 0 0
+4 100 0 0 0 0 0
+"#
+        .to_string()
+        .read_cordeau()
+        .unwrap(),
+    );
+
+    let mut refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(Elitism::new(problem.clone(), random.clone(), 1, 1)), None);
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    RecreateWithCheapest::default()
+        .run(&mut refinement_ctx, InsertionContext::new(problem.clone(), random))
+        .solution
+        .to_solution(problem.extras.clone())
+        .write_cordeau(problem.as_ref(), writer)
+        .unwrap();
+
+    assert_eq!(buffer, "40.00\n1 1 20.00 10 0 1 0\n2 1 20.00 5 0 2 0\n");
+}
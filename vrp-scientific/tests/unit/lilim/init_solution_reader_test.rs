@@ -0,0 +1,29 @@
+use super::*;
+use crate::helpers::*;
+use crate::lilim::LilimProblem;
+use vrp_core::models::problem::Job;
+use vrp_core::utils::DefaultRandom;
+
+#[test]
+fn can_read_init_solution_with_customer_ids() {
+    let random = Arc::new(DefaultRandom::default());
+    let problem = Arc::new(
+        LilimBuilder::new()
+            .set_vehicle((1, 10))
+            .add_customer((0, 0, 0, 0, 0, 1000, 0, 0, 0))
+            .add_customer((1, 1, 0, 2, 0, 1000, 0, 0, 2))
+            .add_customer((2, 2, 0, -2, 0, 1000, 0, 1, 0))
+            .build()
+            .read_lilim()
+            .unwrap(),
+    );
+
+    let solution = read_init_solution(BufReader::new("Route 1 : 1 2\n".as_bytes()), problem, random).unwrap();
+
+    assert_eq!(solution.routes.len(), 1);
+    assert!(solution.unassigned.is_empty());
+    let jobs = solution.routes[0].tour.jobs().collect::<Vec<_>>();
+    assert_eq!(jobs.len(), 1);
+    assert!(matches!(jobs.first(), Some(Job::Multi(_))));
+    assert_eq!(solution.routes[0].tour.activity_count(), 2);
+}
@@ -1,4 +1,5 @@
 use crate::helpers::{create_lc101_problem, get_job_ids, get_vehicle_capacity};
+use vrp_core::models::common::{Demand, DemandDimension, IdDimension, SingleDimLoad};
 
 #[test]
 fn can_read_lilim_format_from_test_file() {
@@ -12,3 +13,22 @@ fn can_read_lilim_format_from_test_file() {
     assert_eq!(problem.fleet.vehicles.len(), 25);
     assert_eq!(get_vehicle_capacity(&problem), 200);
 }
+
+#[test]
+fn can_read_pickup_and_delivery_demands() {
+    let problem = create_lc101_problem();
+
+    problem.jobs.all().for_each(|job| {
+        let multi = job.to_multi();
+        let (pickup, delivery) = (multi.jobs.first().unwrap(), multi.jobs.last().unwrap());
+        let pickup_demand: &Demand<SingleDimLoad> = pickup.dimens.get_demand().unwrap();
+        let delivery_demand: &Demand<SingleDimLoad> = delivery.dimens.get_demand().unwrap();
+
+        assert!(pickup.dimens.get_id().unwrap().starts_with('c'));
+        assert!(delivery.dimens.get_id().unwrap().starts_with('c'));
+        assert!(pickup_demand.pickup.1.value > 0);
+        assert_eq!(pickup_demand.delivery.1.value, 0);
+        assert_eq!(delivery_demand.pickup.1.value, 0);
+        assert_eq!(delivery_demand.delivery.1.value, pickup_demand.pickup.1.value);
+    });
+}
@@ -2,6 +2,7 @@ use super::*;
 use crate::helpers::{create_c101_100_problem, get_test_resource};
 use vrp_core::algorithms::nsga2::Objective;
 use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::problem::ObjectiveCost;
 use vrp_core::utils::DefaultRandom;
 
 #[test]
@@ -0,0 +1,102 @@
+use super::*;
+use crate::helpers::*;
+
+fn create_euc_2d_problem_content() -> String {
+    r#"NAME : test-n4-k2
+COMMENT : (test instance, No of trucks: 2, Optimal value: 16)
+TYPE : CVRP
+DIMENSION : 4
+EDGE_WEIGHT_TYPE : EUC_2D
+CAPACITY : 10
+NODE_COORD_SECTION
+ 1 0 0
+ 2 3 4
+ 3 0 5
+ 4 6 8
+DEMAND_SECTION
+1 0
+2 3
+3 5
+4 7
+DEPOT_SECTION
+ 1
+ -1
+EOF
+"#
+    .to_string()
+}
+
+#[test]
+fn can_read_tsplib_euc_2d_problem() {
+    let problem = create_euc_2d_problem_content().read_tsplib().unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["1", "2", "3"]);
+    assert_eq!(get_job_demands(&problem), vec![3, 5, 7]);
+    assert_eq!(get_vehicle_capacity(&problem), 10);
+    assert_eq!(problem.fleet.vehicles.len(), 3);
+    assert_eq!(problem.transport.distance(0, 0, 1, 0.), 5.);
+    assert_eq!(problem.transport.distance(0, 1, 3, 0.), 5.);
+    assert_eq!(problem.transport.distance(0, 2, 3, 0.), 7.);
+}
+
+#[test]
+fn can_read_tsplib_explicit_lower_row_problem() {
+    let problem = r#"NAME : explicit
+TYPE : CVRP
+DIMENSION : 3
+VEHICLES : 2
+CAPACITY : 5
+EDGE_WEIGHT_TYPE : EXPLICIT
+EDGE_WEIGHT_FORMAT : LOWER_ROW
+EDGE_WEIGHT_SECTION
+ 10
+ 20 30
+DEMAND_SECTION
+1 0
+2 2
+3 4
+DEPOT_SECTION
+1
+-1
+EOF
+"#
+    .to_string()
+    .read_tsplib()
+    .unwrap();
+
+    assert_eq!(get_job_ids(&problem), vec!["1", "2"]);
+    assert_eq!(get_job_demands(&problem), vec![2, 4]);
+    assert_eq!(problem.fleet.vehicles.len(), 2);
+    assert_eq!(problem.transport.distance(0, 0, 1, 0.), 10.);
+    assert_eq!(problem.transport.distance(0, 2, 0, 0.), 20.);
+    assert_eq!(problem.transport.distance(0, 1, 2, 0.), 30.);
+    assert_eq!(problem.transport.distance(0, 2, 1, 0.), 30.);
+}
+
+parameterized_test! {can_create_explicit_matrix, (format, weights, expected), {
+    can_create_explicit_matrix_impl(format, weights, expected);
+}}
+
+can_create_explicit_matrix! {
+    case01: ("FULL_MATRIX", vec![0., 1., 2., 0.], Ok(vec![0., 1., 2., 0.])),
+    case02: ("UPPER_ROW", vec![1.], Ok(vec![0., 1., 1., 0.])),
+    case03: ("UPPER_DIAG_ROW", vec![0., 1., 0.], Ok(vec![0., 1., 1., 0.])),
+    case04: ("LOWER_DIAG_ROW", vec![0., 1., 0.], Ok(vec![0., 1., 1., 0.])),
+    case05: ("LOWER_ROW", vec![1., 2.], Err("expected 1 edge weights, got 2".to_string())),
+    case06: ("FUNCTION", vec![], Err("unsupported edge weight format: 'FUNCTION'".to_string())),
+}
+
+fn can_create_explicit_matrix_impl(format: &str, weights: Vec<f64>, expected: Result<Vec<f64>, String>) {
+    let result = create_explicit_matrix(format, weights.as_slice(), 2);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_return_error_for_multiple_depots() {
+    let content = create_euc_2d_problem_content().replace(" 1\n -1", " 1\n 2\n -1");
+
+    let result = content.read_tsplib();
+
+    assert_eq!(result.err(), Some("multiple depots are not supported".to_string()));
+}
@@ -0,0 +1,51 @@
+use super::*;
+use crate::tsplib::TsplibProblem;
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::solver::mutation::{Recreate, RecreateWithCheapest};
+use vrp_core::solver::population::Elitism;
+use vrp_core::solver::RefinementContext;
+use vrp_core::utils::DefaultRandom;
+
+#[test]
+fn can_write_tsplib_solution() {
+    let random = Arc::new(DefaultRandom::default());
+    let problem = Arc::new(
+        r#"NAME : trivial
+TYPE : CVRP
+DIMENSION : 3
+VEHICLES : 1
+CAPACITY : 10
+EDGE_WEIGHT_TYPE : EUC_2D
+NODE_COORD_SECTION
+1 0 0
+2 3 4
+3 0 4
+DEMAND_SECTION
+1 0
+2 1
+3 1
+DEPOT_SECTION
+1
+-1
+EOF
+"#
+        .to_string()
+        .read_tsplib()
+        .unwrap(),
+    );
+
+    let mut refinement_ctx =
+        RefinementContext::new(problem.clone(), Box::new(Elitism::new(problem.clone(), random.clone(), 1, 1)), None);
+
+    let mut buffer = String::new();
+    let writer = unsafe { BufWriter::new(buffer.as_mut_vec()) };
+    RecreateWithCheapest::default()
+        .run(&mut refinement_ctx, InsertionContext::new(problem.clone(), random))
+        .solution
+        .to_solution(problem.extras.clone())
+        .write_tsplib(problem.as_ref(), writer)
+        .unwrap();
+
+    assert!(buffer == "Route #1: 1 2\nCost 12\n" || buffer == "Route #1: 2 1\nCost 12\n");
+}